@@ -0,0 +1,733 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+    },
+};
+
+use crate::{
+    f256,
+    math::{Float256, Float512},
+    BigUInt, HiLo,
+};
+
+/// Error returned when trying to convert a NaN or an infinite `f256` into
+/// a [`BigFloat256`] or [`BigFloat512`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NonFiniteError;
+
+impl Display for NonFiniteError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        "Given value is not finite.".fmt(f)
+    }
+}
+
+// Writes "[-]0x1.<fraction>p<exp>" for a non-zero value, where the trailing
+// zeroes of the fraction are omitted, or "0x0p+0" for zero.
+#[allow(clippy::integer_division)]
+fn fmt_hex(
+    signum: i32,
+    exp: i32,
+    chunks: &[u128],
+    form: &mut Formatter<'_>,
+) -> fmt::Result {
+    if signum == 0 {
+        return form.write_str("0x0p+0");
+    }
+    if signum < 0 {
+        form.write_str("-")?;
+    }
+    form.write_str("0x1")?;
+    // The two most significant bits are the reserved bit and the leading
+    // bit, so the fraction is left-aligned after shifting them out.
+    let fraction = |i: usize| -> u128 {
+        let next = chunks.get(i + 1).map_or(0, |c| c >> 126);
+        (chunks[i] << 2) | next
+    };
+    let mut n_chunks = chunks.len();
+    while n_chunks > 0 && fraction(n_chunks - 1) == 0 {
+        n_chunks -= 1;
+    }
+    if n_chunks > 0 {
+        form.write_str(".")?;
+        for i in 0..n_chunks - 1 {
+            write!(form, "{:032x}", fraction(i))?;
+        }
+        let last = fraction(n_chunks - 1);
+        let n_digits = 32 - last.trailing_zeros() as usize / 4;
+        write!(form, "{:0w$x}", last >> (128 - 4 * n_digits), w = n_digits)?;
+    }
+    write!(form, "p{exp:+}")
+}
+
+macro_rules! define_big_float {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $inner:ty, $n_chunks:literal
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Default, PartialEq, Eq)]
+        #[repr(transparent)]
+        pub struct $name($inner);
+
+        impl $name {
+            /// Number of bits of the significand, incl. the leading bit.
+            pub const SIGNIFICAND_BITS: u32 = <$inner>::FRACTION_BITS + 1;
+
+            /// Value 0.
+            pub const ZERO: Self = Self(<$inner>::ZERO);
+
+            /// Value 1.
+            pub const ONE: Self = Self(<$inner>::ONE);
+
+            /// Value -1.
+            pub const NEG_ONE: Self = Self(<$inner>::NEG_ONE);
+
+            /// Value 2.
+            pub const TWO: Self = Self(<$inner>::TWO);
+
+            /// Difference between 1 and the next larger representable
+            /// number.
+            pub const EPSILON: Self = Self(<$inner>::EPSILON);
+
+            /// Minimum exponent of a non-zero value.
+            pub const MIN_EXP: i32 = -(1 << 29);
+
+            /// Maximum exponent of a non-zero value.
+            pub const MAX_EXP: i32 = 1 << 29;
+
+            // Wraps the result of an operation, checking its exponent.
+            // As long as the exponents of the operands are within the
+            // limits, the exponent of the result can not overflow an i32.
+            #[inline(always)]
+            const fn checked(x: $inner) -> Self {
+                assert!(
+                    x.exp() >= Self::MIN_EXP && x.exp() <= Self::MAX_EXP,
+                    "Exponent out of range."
+                );
+                Self(x)
+            }
+
+            /// Construct a value from sign s, quantum exponent t and
+            /// integral significand c (given as big-endian array of
+            /// `u128`), so that the result is (-1)ˢ × 2ᵗ × c.
+            ///
+            /// c must not have more than `SIGNIFICAND_BITS` significant
+            /// bits.
+            ///
+            /// # Panics
+            ///
+            /// Panics if s is not 0 or 1, if c is too large or if the
+            /// exponent of the result is out of range.
+            #[must_use]
+            pub fn from_sign_exp_signif(
+                s: u32,
+                t: i32,
+                c: &[u128; $n_chunks],
+            ) -> Self {
+                assert!(s <= 1, "Sign must be 0 or 1.");
+                assert!(
+                    c[0].leading_zeros() >= 1,
+                    "Significand must not have more than {} bits.",
+                    Self::SIGNIFICAND_BITS
+                );
+                // Exponent of c × 2⁰ can't overflow, so check the sum.
+                let x = <$inner>::from_sign_exp_signif(s, 0, c);
+                let e = i64::from(t) + i64::from(x.exp());
+                assert!(
+                    x.is_zero()
+                        || (e >= i64::from(Self::MIN_EXP)
+                            && e <= i64::from(Self::MAX_EXP)),
+                    "Exponent out of range."
+                );
+                Self(<$inner>::from_sign_exp_signif(s, t, c))
+            }
+
+            /// Returns the sign s, the quantum exponent t and the integral
+            /// significand c (as big-endian array of `u128`) of `self`, so
+            /// that `self` = (-1)ˢ × 2ᵗ × c. For a non-zero value, c has
+            /// exactly `SIGNIFICAND_BITS` significant bits.
+            #[must_use]
+            #[allow(clippy::cast_possible_wrap)]
+            pub fn as_sign_exp_signif(&self) -> (u32, i32, [u128; $n_chunks]) {
+                if self.is_zero() {
+                    return (0, 0, [0; $n_chunks]);
+                }
+                let mut c = [0_u128; $n_chunks];
+                c.copy_from_slice(&self.0.signif().as_u128_chunks());
+                (
+                    self.is_sign_negative() as u32,
+                    self.exponent() - <$inner>::FRACTION_BITS as i32,
+                    c,
+                )
+            }
+
+            /// Returns `true` if `self` is equal to 0.
+            #[must_use]
+            #[inline(always)]
+            pub const fn is_zero(&self) -> bool {
+                self.0.is_zero()
+            }
+
+            /// Returns `true` if `self` is less than 0.
+            #[must_use]
+            #[inline(always)]
+            pub const fn is_sign_negative(&self) -> bool {
+                self.0.signum() < 0
+            }
+
+            /// Returns -1, 0 or 1, depending on the sign of `self`.
+            #[must_use]
+            #[inline(always)]
+            pub const fn signum(&self) -> i32 {
+                self.0.signum()
+            }
+
+            /// Returns the exponent e of `self`, so that
+            /// 2ᵉ <= |`self`| < 2ᵉ⁺¹, or 0 if `self` is 0.
+            #[must_use]
+            #[inline(always)]
+            pub const fn exponent(&self) -> i32 {
+                self.0.exp()
+            }
+
+            /// Returns the absolute value of `self`.
+            #[must_use]
+            #[inline(always)]
+            pub const fn abs(&self) -> Self {
+                Self(self.0.abs())
+            }
+
+            /// Returns the integer part of `self`, i.e. `self` rounded
+            /// towards zero.
+            #[must_use]
+            pub fn trunc(&self) -> Self {
+                Self(self.0.trunc())
+            }
+
+            /// Returns `self` × 2ⁿ. This is exact.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the exponent of the result is out of range.
+            #[must_use]
+            #[inline(always)]
+            pub const fn mul_pow2(&self, n: i32) -> Self {
+                if self.is_zero() {
+                    return *self;
+                }
+                let e = self.0.exp() as i64 + n as i64;
+                assert!(
+                    e >= Self::MIN_EXP as i64 && e <= Self::MAX_EXP as i64,
+                    "Exponent out of range."
+                );
+                Self(self.0.mul_pow2(n))
+            }
+
+            /// Returns `self` × `self`.
+            #[must_use]
+            pub fn square(&self) -> Self {
+                Self::checked(self.0.square())
+            }
+
+            /// Returns 1 / `self`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `self` is 0.
+            #[must_use]
+            pub fn recip(&self) -> Self {
+                Self::checked(self.0.recip())
+            }
+
+            /// Returns the square root of `self`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `self` is less than 0.
+            #[must_use]
+            pub fn sqrt(&self) -> Self {
+                assert!(
+                    !self.is_sign_negative(),
+                    "Square root of negative value."
+                );
+                Self::checked(self.0.sqrt())
+            }
+
+            /// Returns `self` raised to the power of `n`, computed by
+            /// repeated squaring, so that the result is not correctly
+            /// rounded.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `self` is 0 and `n` is negative or if the exponent
+            /// of the result is out of range.
+            #[must_use]
+            pub fn powi(&self, n: i32) -> Self {
+                let mut base = if n < 0 { self.recip() } else { *self };
+                let mut n = n.unsigned_abs();
+                let mut res = Self::ONE;
+                while n > 0 {
+                    if n & 1 == 1 {
+                        res *= base;
+                    }
+                    n >>= 1;
+                    if n > 0 {
+                        base = base.square();
+                    }
+                }
+                res
+            }
+
+            /// Returns `self` × `f` + `a`, rounded only once.
+            #[must_use]
+            pub fn mul_add(&self, f: &Self, a: &Self) -> Self {
+                Self::checked(self.0.mul_add(&f.0, &a.0))
+            }
+
+            /// Returns the sum s = `self` + `rhs` together with the
+            /// rounding error r = `self` + `rhs` - s, so that s + r is
+            /// exactly equal to `self` + `rhs`.
+            #[must_use]
+            pub fn sum_exact(&self, rhs: &Self) -> (Self, Self) {
+                let (s, r) = self.0.sum_exact(&rhs.0);
+                (Self::checked(s), Self::checked(r))
+            }
+
+            /// Returns the product p = `self` × `rhs` together with the
+            /// rounding error r = `self` × `rhs` - p, so that p + r is
+            /// exactly equal to `self` × `rhs`.
+            #[must_use]
+            pub fn mul_exact(&self, rhs: &Self) -> (Self, Self) {
+                let (p, r) = self.0.mul_exact(&rhs.0);
+                (Self::checked(p), Self::checked(r))
+            }
+
+            /// Returns `self` rounded to the nearest `f256`, ties to even.
+            ///
+            /// Values beyond the range of `f256` are rounded to ±0,
+            /// subnormal values or ±∞ respectively. Because `self` has no
+            /// signed zero, 0 is converted to +0.
+            #[must_use]
+            pub fn to_f256(&self) -> f256 {
+                f256::from(&self.0)
+            }
+        }
+
+        impl TryFrom<&f256> for $name {
+            type Error = NonFiniteError;
+
+            #[doc = concat!(
+                "Converts a finite `f256` into a `",
+                stringify!($name),
+                "`. This is exact."
+            )]
+            fn try_from(f: &f256) -> Result<Self, Self::Error> {
+                if f.is_finite() {
+                    Ok(Self(<$inner>::from(f)))
+                } else {
+                    Err(NonFiniteError)
+                }
+            }
+        }
+
+        impl TryFrom<f256> for $name {
+            type Error = NonFiniteError;
+
+            #[inline(always)]
+            fn try_from(f: f256) -> Result<Self, Self::Error> {
+                Self::try_from(&f)
+            }
+        }
+
+        impl From<i32> for $name {
+            #[inline(always)]
+            fn from(i: i32) -> Self {
+                Self(<$inner>::from(i))
+            }
+        }
+
+        impl From<&$name> for f256 {
+            #[inline(always)]
+            fn from(f: &$name) -> Self {
+                f.to_f256()
+            }
+        }
+
+        impl From<$name> for f256 {
+            #[inline(always)]
+            fn from(f: $name) -> Self {
+                f.to_f256()
+            }
+        }
+
+        impl PartialOrd for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> Ordering {
+                match self.signum().cmp(&other.signum()) {
+                    Ordering::Equal => {
+                        let ord = (self.0.exp(), self.0.signif())
+                            .cmp(&(other.0.exp(), other.0.signif()));
+                        if self.is_sign_negative() {
+                            ord.reverse()
+                        } else {
+                            ord
+                        }
+                    }
+                    ord => ord,
+                }
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, form: &mut Formatter<'_>) -> fmt::Result {
+                write!(form, "{}(", stringify!($name))?;
+                fmt::LowerHex::fmt(self, form)?;
+                form.write_str(")")
+            }
+        }
+
+        impl fmt::LowerHex for $name {
+            /// Formats the value as exact hexadecimal floating point
+            /// literal, like `0x1.8p+3` for 12.
+            fn fmt(&self, form: &mut Formatter<'_>) -> fmt::Result {
+                fmt_hex(
+                    self.signum(),
+                    self.exponent(),
+                    &self.0.signif().as_u128_chunks(),
+                    form,
+                )
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn neg(self) -> Self::Output {
+                Self(-self.0)
+            }
+        }
+
+        impl Neg for &$name {
+            type Output = $name;
+
+            #[inline(always)]
+            fn neg(self) -> Self::Output {
+                $name(-self.0)
+            }
+        }
+
+        define_big_float!(@binop $name, Add, add, AddAssign, add_assign);
+        define_big_float!(@binop $name, Sub, sub, SubAssign, sub_assign);
+        define_big_float!(@binop $name, Mul, mul, MulAssign, mul_assign);
+        define_big_float!(@binop $name, Div, div, DivAssign, div_assign);
+    };
+    (@binop $name:ident, $imp:ident, $method:ident,
+     $imp_assign:ident, $method_assign:ident) => {
+        impl $imp for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $method(self, rhs: Self) -> Self::Output {
+                Self::checked($imp::$method(self.0, rhs.0))
+            }
+        }
+
+        impl $imp<&$name> for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $method(self, rhs: &Self) -> Self::Output {
+                Self::checked($imp::$method(self.0, rhs.0))
+            }
+        }
+
+        impl $imp<$name> for &$name {
+            type Output = $name;
+
+            #[inline(always)]
+            fn $method(self, rhs: $name) -> Self::Output {
+                $name::checked($imp::$method(self.0, rhs.0))
+            }
+        }
+
+        impl $imp for &$name {
+            type Output = $name;
+
+            #[inline(always)]
+            fn $method(self, rhs: Self) -> Self::Output {
+                $name::checked($imp::$method(self.0, rhs.0))
+            }
+        }
+
+        impl $imp_assign for $name {
+            #[inline(always)]
+            fn $method_assign(&mut self, rhs: Self) {
+                *self = $imp::$method(*self, rhs);
+            }
+        }
+
+        impl $imp_assign<&$name> for $name {
+            #[inline(always)]
+            fn $method_assign(&mut self, rhs: &Self) {
+                *self = $imp::$method(*self, rhs);
+            }
+        }
+    };
+}
+
+define_big_float!(
+    /// Binary floating point number with a significand of 255 bits, i.e.
+    /// 18 bits more than an `f256`, for intermediate results which need
+    /// some guard bits.
+    ///
+    /// See [`BigFloat512`] for the semantics of this type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, BigFloat256};
+    /// let third = BigFloat256::ONE / BigFloat256::from(3);
+    /// let x = BigFloat256::try_from(f256::TEN).unwrap();
+    /// assert_eq!((third * x).to_f256(), f256::TEN / f256::from(3));
+    /// ```
+    BigFloat256, Float256, 2
+);
+
+define_big_float!(
+    /// Binary floating point number with a significand of 511 bits, for
+    /// intermediate results which need far more precision than an `f256`.
+    ///
+    /// Unlike `f256`, this type has no subnormal values, no signed zero,
+    /// no infinities and no NaN. Values can be created from finite `f256`
+    /// values and from `i32` values without loss of precision; they are
+    /// rounded back to `f256` on demand by [`BigFloat512::to_f256`].
+    ///
+    /// The exponent e of a non-zero value (2ᵉ <= |x| < 2ᵉ⁺¹) is limited to
+    /// the range [`MIN_EXP`](Self::MIN_EXP)..=[`MAX_EXP`](Self::MAX_EXP)
+    /// (±2²⁹), which exceeds the range of `f256` by far. All operations
+    /// panic if the exponent of their result would be out of that range;
+    /// there is no overflow to infinity and no underflow to zero.
+    ///
+    /// Addition, subtraction, multiplication, division and square root are
+    /// correctly rounded (to nearest, ties to even).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, BigFloat512};
+    /// let x = BigFloat512::try_from(f256::TWO).unwrap();
+    /// let s = x.sqrt();
+    /// assert!((s.square() - x).exponent() < -500);
+    /// assert_eq!(s.to_f256(), f256::TWO.sqrt());
+    /// assert!(BigFloat512::try_from(f256::NAN).is_err());
+    /// ```
+    BigFloat512, Float512, 4
+);
+
+#[cfg(test)]
+mod big_float_tests {
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn test_conversion() {
+        for f in [
+            f256::ZERO,
+            f256::ONE,
+            f256::from(-7.25),
+            f256::MAX,
+            f256::MIN,
+            f256::MIN_POSITIVE,
+            f256::MIN_GT_ZERO,
+            -f256::MIN_GT_ZERO,
+        ] {
+            let x = BigFloat256::try_from(f).unwrap();
+            assert_eq!(x.to_f256(), f);
+            let x = BigFloat512::try_from(&f).unwrap();
+            assert_eq!(f256::from(x), f);
+        }
+        assert_eq!(
+            BigFloat512::try_from(f256::NEG_ZERO).unwrap(),
+            BigFloat512::ZERO
+        );
+        assert_eq!(BigFloat256::from(-1), BigFloat256::NEG_ONE);
+        assert_eq!(BigFloat512::from(2), BigFloat512::TWO);
+    }
+
+    #[test]
+    fn test_non_finite() {
+        for f in [f256::NAN, f256::INFINITY, f256::NEG_INFINITY] {
+            assert_eq!(BigFloat256::try_from(f), Err(NonFiniteError));
+            assert_eq!(BigFloat512::try_from(&f), Err(NonFiniteError));
+        }
+        assert_eq!(format!("{NonFiniteError}"), "Given value is not finite.");
+    }
+
+    #[test]
+    fn test_to_f256_rounding() {
+        // 1 + 2⁻²³⁷ is a tie between 1 and 1 + 2⁻²³⁶.
+        let eps = BigFloat512::ONE.mul_pow2(-237);
+        assert_eq!((BigFloat512::ONE + eps).to_f256(), f256::ONE);
+        let eps = eps + BigFloat512::ONE.mul_pow2(-400);
+        assert_eq!(
+            (BigFloat512::ONE + eps).to_f256(),
+            f256::ONE + f256::EPSILON
+        );
+        let big = BigFloat512::ONE.mul_pow2(300_000);
+        assert_eq!(big.to_f256(), f256::INFINITY);
+        assert_eq!((-big).recip().to_f256(), f256::NEG_ZERO);
+    }
+
+    #[test]
+    fn test_sign_exp_signif() {
+        let x = BigFloat256::from_sign_exp_signif(1, -3, &[0, 21]);
+        assert_eq!(x.to_f256(), f256::from(-2.625));
+        assert_eq!(x.exponent(), 1);
+        let (s, t, c) = x.as_sign_exp_signif();
+        assert_eq!(s, 1);
+        assert_eq!(t, 1 - 254);
+        assert_eq!(c, [21_u128 << 122, 0]);
+        assert_eq!(BigFloat256::from_sign_exp_signif(s, t, &c), x);
+        assert_eq!(BigFloat512::ZERO.as_sign_exp_signif(), (0, 0, [0; 4]));
+        assert_eq!(BigFloat512::SIGNIFICAND_BITS, 511);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_signif_too_large() {
+        let _ = BigFloat256::from_sign_exp_signif(0, 0, &[u128::MAX, 0]);
+    }
+
+    #[test]
+    fn test_exp_limits() {
+        let max = BigFloat512::ONE.mul_pow2(BigFloat512::MAX_EXP);
+        let min = BigFloat512::ONE.mul_pow2(BigFloat512::MIN_EXP);
+        assert_eq!(max.exponent(), BigFloat512::MAX_EXP);
+        assert_eq!(min.recip(), max);
+        assert_eq!((max * min).powi(1000), BigFloat512::ONE);
+        assert_eq!(max.powi(-1), min);
+        let x = BigFloat256::from_sign_exp_signif(
+            0,
+            BigFloat256::MAX_EXP,
+            &[0, 1],
+        );
+        assert_eq!(x.exponent(), BigFloat256::MAX_EXP);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_exp_overflow() {
+        let x = BigFloat512::ONE.mul_pow2(BigFloat512::MAX_EXP / 2 + 1);
+        let _ = x.square();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_exp_underflow() {
+        let x = BigFloat512::ONE.mul_pow2(BigFloat512::MIN_EXP);
+        let _ = x / BigFloat512::TWO;
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_powi_exp_overflow() {
+        let _ = BigFloat256::TWO.powi(i32::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sign_exp_signif_exp_overflow() {
+        let _ = BigFloat256::from_sign_exp_signif(0, i32::MAX, &[0, 1]);
+    }
+
+    #[test]
+    fn test_ord() {
+        let a = BigFloat512::from(-3);
+        let b = BigFloat512::NEG_ONE;
+        let c = BigFloat512::ZERO;
+        let d = BigFloat512::ONE.mul_pow2(-10);
+        let e = BigFloat512::from(5);
+        assert!(a < b && b < c && c < d && d < e);
+        assert!(a.abs() > b.abs());
+        assert_eq!(a.max(b), b);
+        assert_eq!(e.min(d), d);
+    }
+
+    #[test]
+    fn test_arith() {
+        let x = BigFloat512::from(7);
+        let y = BigFloat512::from(-2);
+        let (rx, ry) = (&x, &y);
+        assert_eq!(x + y, BigFloat512::from(5));
+        assert_eq!(rx - ry, BigFloat512::from(9));
+        assert_eq!(x * ry, BigFloat512::from(-14));
+        assert_eq!((rx / y).to_f256(), f256::from(-3.5));
+        let mut z = x;
+        z -= x;
+        assert!(z.is_zero());
+        z += &y;
+        z *= y;
+        z /= BigFloat512::TWO;
+        assert_eq!(z, BigFloat512::TWO);
+        assert_eq!(-z, BigFloat512::from(-2));
+        assert_eq!(BigFloat512::from(9).sqrt(), BigFloat512::from(3));
+        assert_eq!(y.powi(-2), BigFloat512::ONE.mul_pow2(-2));
+        assert_eq!(x.mul_add(&y, &BigFloat512::ONE), BigFloat512::from(-13));
+        let t = BigFloat256::try_from(f256::from(-2.75)).unwrap();
+        assert_eq!(t.trunc(), BigFloat256::from(-2));
+        assert_eq!(t.signum(), -1);
+    }
+
+    #[test]
+    fn test_exact_ops() {
+        let x = BigFloat512::ONE;
+        let y = BigFloat512::EPSILON.mul_pow2(-3);
+        let (s, r) = x.sum_exact(&y);
+        assert_eq!(s, x);
+        assert_eq!(r, y);
+        let x = BigFloat256::ONE + BigFloat256::EPSILON;
+        let (p, r) = x.mul_exact(&x);
+        assert_eq!(p, BigFloat256::ONE + BigFloat256::EPSILON.mul_pow2(1));
+        assert_eq!(r, BigFloat256::EPSILON.square());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sqrt_neg() {
+        let _ = BigFloat512::NEG_ONE.sqrt();
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(format!("{:x}", BigFloat512::from(12)), "0x1.8p+3");
+        assert_eq!(format!("{:x}", BigFloat256::ZERO), "0x0p+0");
+        assert_eq!(
+            format!("{:?}", BigFloat256::from(-1).mul_pow2(-5)),
+            "BigFloat256(-0x1p-5)"
+        );
+        let x = BigFloat512::ONE + BigFloat512::EPSILON;
+        // 510 fractional bits => 128 hex digits, the last one being 4
+        let s = format!("{x:x}");
+        assert_eq!(s.len(), "0x1.p+0".len() + 128);
+        assert!(s.ends_with("0004p+0"));
+    }
+}
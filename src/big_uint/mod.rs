@@ -10,20 +10,65 @@
 mod uint128;
 mod uint_generic;
 
-use alloc::vec::Vec;
 use core::{
     fmt::{Debug, Display},
     mem::size_of,
     ops::{
-        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, Div,
-        DivAssign, Mul, MulAssign, Rem, RemAssign, Shl, ShlAssign, Shr,
-        ShrAssign, Sub, SubAssign,
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, Deref,
+        DerefMut, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Shl,
+        ShlAssign, Shr, ShrAssign, Sub, SubAssign,
     },
 };
 pub(crate) use uint128::U128;
 use uint128::{u128_hi, u128_lo};
 pub(crate) use uint_generic::{UInt, U1024, U256, U512};
 
+/// Maximum number of u128 chunks of the supported big unsigned integers.
+const MAX_N_CHUNKS: usize = 8;
+
+/// The u128 chunks of a big unsigned integer, most significant first.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct U128Chunks {
+    chunks: [u128; MAX_N_CHUNKS],
+    len: usize,
+}
+
+impl U128Chunks {
+    /// Returns a new instance holding the single chunk `c`.
+    pub(crate) const fn new(c: u128) -> Self {
+        let mut chunks = [0_u128; MAX_N_CHUNKS];
+        chunks[0] = c;
+        Self { chunks, len: 1 }
+    }
+
+    /// Returns a new instance holding the chunks of `hi` followed by those
+    /// of `lo`.
+    pub(crate) fn concat(hi: &[u128], lo: &[u128]) -> Self {
+        let len = hi.len() + lo.len();
+        debug_assert!(len <= MAX_N_CHUNKS);
+        let mut chunks = [0_u128; MAX_N_CHUNKS];
+        chunks[..hi.len()].copy_from_slice(hi);
+        chunks[hi.len()..len].copy_from_slice(lo);
+        Self { chunks, len }
+    }
+}
+
+impl Deref for U128Chunks {
+    type Target = [u128];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.chunks[..self.len]
+    }
+}
+
+impl DerefMut for U128Chunks {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.chunks[..self.len]
+    }
+}
+
 pub(crate) trait HiLo
 where
     Self: Copy + Clone + Sized,
@@ -37,7 +82,7 @@ where
     fn lo_t(&self) -> Self::T;
     fn hi(&self) -> Self;
     fn lo(&self) -> Self;
-    fn as_vec_u128(&self) -> Vec<u128>;
+    fn as_u128_chunks(&self) -> U128Chunks;
     fn first_chunk(&self) -> u128;
     fn last_chunk(&self) -> u128;
 }
@@ -77,7 +122,6 @@ where
         + DivRem<Output = (Self, Self)>
         + for<'a> From<&'a u128>
         + for<'a> From<&'a [u128]>
-        + for<'a> From<&'a Vec<u128>>
         + Mul<Output = Self>
         + for<'a> MulAssign<&'a Self>
         + PartialEq
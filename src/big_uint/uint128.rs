@@ -7,7 +7,6 @@
 // $Source$
 // $Revision$
 
-use alloc::{vec, vec::Vec};
 use core::{
     fmt,
     ops::{
@@ -17,7 +16,7 @@ use core::{
     },
 };
 
-use super::{BigUInt, DivRem, HiLo};
+use super::{BigUInt, DivRem, HiLo, U128Chunks};
 
 #[inline(always)]
 pub(crate) const fn u128_hi(u: u128) -> u128 {
@@ -73,8 +72,8 @@ impl HiLo for U128 {
     }
 
     #[inline(always)]
-    fn as_vec_u128(&self) -> Vec<u128> {
-        vec![self.0]
+    fn as_u128_chunks(&self) -> U128Chunks {
+        U128Chunks::new(self.0)
     }
 
     #[inline(always)]
@@ -408,14 +407,6 @@ impl<'a> From<&'a [u128]> for U128 {
     }
 }
 
-impl<'a> From<&'a Vec<u128>> for U128 {
-    #[inline(always)]
-    fn from(value: &'a Vec<u128>) -> Self {
-        debug_assert!(value.len() == 1);
-        Self::new(value[0])
-    }
-}
-
 impl fmt::Debug for U128 {
     #[inline(always)]
     fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -448,7 +439,7 @@ mod convert_tests {
         let x = [u128::MAX];
         let y = U128::from(&x[..]);
         assert_eq!(y, U128::MAX);
-        let z = y.as_vec_u128();
+        let z = y.as_u128_chunks();
         assert_eq!(x, z[..]);
     }
 
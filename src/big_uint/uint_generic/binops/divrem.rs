@@ -35,7 +35,7 @@ where
 
     #[inline(always)]
     fn div_rem(self, rhs: &u128) -> Self::Output {
-        let mut chunks = self.as_vec_u128();
+        let mut chunks = self.as_u128_chunks();
         let mut rem = U128::new(chunks[0] % *rhs);
         chunks[0] /= *rhs;
         let rhs = U128::new(*rhs);
@@ -121,7 +121,7 @@ where
             (quot, UInt::<SubUInt>::from_hi_lo(SubUInt::ZERO, rem))
         } else if rhs.hi > self.hi {
             // self < rhs
-            return (UInt::<SubUInt>::ZERO, *self);
+            (UInt::<SubUInt>::ZERO, *self)
         } else {
             // estimate the quotient
             let nlz = self.hi.leading_zeros();
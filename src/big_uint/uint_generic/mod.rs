@@ -9,10 +9,9 @@
 
 mod binops;
 
-use alloc::vec::Vec;
 use core::{fmt, ops::Rem};
 
-use super::{BigUInt, DivRem, HiLo, U128};
+use super::{BigUInt, DivRem, HiLo, U128Chunks, U128};
 use crate::big_uint::uint128::{u128_hi, u128_lo};
 
 #[derive(Clone, Copy, Default, Eq, Ord, PartialOrd, PartialEq)]
@@ -59,8 +58,11 @@ where
         Self::from_hi_lo(SubUInt::ZERO, self.lo)
     }
 
-    fn as_vec_u128(&self) -> Vec<u128> {
-        [self.hi.as_vec_u128(), self.lo.as_vec_u128()].concat()
+    fn as_u128_chunks(&self) -> U128Chunks {
+        U128Chunks::concat(
+            &self.hi.as_u128_chunks(),
+            &self.lo.as_u128_chunks(),
+        )
     }
 
     fn first_chunk(&self) -> u128 {
@@ -365,27 +367,12 @@ where
     }
 }
 
-impl<'a, SubUInt> From<&'a Vec<u128>> for UInt<SubUInt>
-where
-    SubUInt: BigUInt + HiLo,
-{
-    #[allow(clippy::integer_division)]
-    #[inline(always)]
-    fn from(value: &'a Vec<u128>) -> Self {
-        debug_assert!(value.len() == (Self::BITS / 128) as usize);
-        Self::from_hi_lo(
-            SubUInt::from(&value[0..value.len() / 2]),
-            SubUInt::from(&value[value.len() / 2..]),
-        )
-    }
-}
-
 impl<SubUInt> fmt::Debug for UInt<SubUInt>
 where
     SubUInt: BigUInt + HiLo,
 {
     fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let chunks = self.as_vec_u128();
+        let chunks = self.as_u128_chunks();
         write!(form, "(0x{:032x}", chunks[0])?;
         for c in chunks.iter().skip(1) {
             write!(form, ", 0x{:032x}", c)?;
@@ -402,17 +389,19 @@ where
         const SEGMENT_SIZE: usize = 38;
         #[allow(clippy::cast_possible_truncation)]
         const SEGMENT_BASE: u128 = 10_u128.pow(SEGMENT_SIZE as u32);
+        // Enough for 608 decimal digits, i.e. up to U1024.
+        const MAX_N_SEGMENTS: usize = 16;
         if self.hi.is_zero() {
             return fmt::Display::fmt(&self.lo, form);
         }
-        let mut segments = Vec::<u128>::new();
+        let mut segments = [0_u128; MAX_N_SEGMENTS];
+        let mut n_segments = 0_usize;
         let mut t = *self;
-        let mut r: u128;
         while !t.is_zero() {
-            (t, r) = t.div_rem(SEGMENT_BASE);
-            segments.push(r);
+            (t, segments[n_segments]) = t.div_rem(SEGMENT_BASE);
+            n_segments += 1;
         }
-        let mut idx = segments.len() - 1;
+        let mut idx = n_segments - 1;
         write!(form, "{}", segments[idx])?;
         while idx > 0 {
             idx -= 1;
@@ -653,7 +642,7 @@ mod into_vec_tests {
     fn test_into_vec() {
         let a = [12_u128, 34_u128, 56_u128, 78_u128];
         let x = U512::from(&a[..]);
-        let z = x.as_vec_u128();
+        let z = x.as_u128_chunks();
         assert_eq!(z.len(), U512::N_CHUNKS);
         assert_eq!(z[..], a);
     }
@@ -19,8 +19,24 @@ use crate::{
     HI_ABS_MASK, HI_EXP_MASK, HI_FRACTION_BIAS, HI_FRACTION_BITS,
     HI_FRACTION_MASK, HI_SIGN_MASK, INF_HI, MAX_HI, SIGNIFICAND_BITS, U256,
 };
+use crate::{ExceptionFlags, RoundingMode};
 
+#[inline(always)]
 pub(crate) fn add(x: f256, y: f256) -> f256 {
+    add_rounded(
+        x,
+        y,
+        RoundingMode::NearestTiesEven,
+        &mut ExceptionFlags::default(),
+    )
+}
+
+pub(crate) fn add_rounded(
+    x: f256,
+    y: f256,
+    rnd: RoundingMode,
+    flags: &mut ExceptionFlags,
+) -> f256 {
     let mut abs_bits_x = abs_bits(&x);
     let mut abs_bits_y = abs_bits(&y);
     let mut sign_bits_hi_x = sign_bits_hi(&x);
@@ -34,9 +50,15 @@ pub(crate) fn add(x: f256, y: f256) -> f256 {
     if (abs_bits_sticky_x, abs_bits_sticky_y).any_special() {
         let max_abs_bits_sticky = max(abs_bits_sticky_x, abs_bits_sticky_y);
         if max_abs_bits_sticky == 0 {
-            // Both operands are zero.
+            // Both operands are zero. If their signs differ, the sign of the
+            // result depends on the rounding mode.
+            let sign_bits_hi_z = if sign_bits_hi_x == sign_bits_hi_y {
+                sign_bits_hi_x
+            } else {
+                rnd.zero_sum_sign_bits_hi()
+            };
             return f256 {
-                bits: U256::new(sign_bits_hi_x & sign_bits_hi_y, 0),
+                bits: U256::new(sign_bits_hi_z, 0),
             };
         }
         if max_abs_bits_sticky > HI_EXP_MASK
@@ -46,6 +68,9 @@ pub(crate) fn add(x: f256, y: f256) -> f256 {
         {
             // Atleast one operand is NAN, or both operands are infinite and
             // their signs differ.
+            if max_abs_bits_sticky == INF_HI {
+                *flags |= ExceptionFlags::INVALID;
+            }
             return f256::NAN;
         }
         // For all other special cases return the operand with the greater
@@ -59,9 +84,12 @@ pub(crate) fn add(x: f256, y: f256) -> f256 {
 
     // Both operands are finite and non-zero.
 
-    // In case |x| = |y| and the sign(x) != sign(y), the result is +0.
+    // In case |x| = |y| and the sign(x) != sign(y), the result is +0, or -0
+    // when rounding toward -∞.
     if abs_bits_x == abs_bits_y && sign_bits_hi_x != sign_bits_hi_y {
-        return f256::ZERO;
+        return f256 {
+            bits: U256::new(rnd.zero_sum_sign_bits_hi(), 0),
+        };
     }
 
     // Compare the absolute values of the operands and swap them in case
@@ -93,7 +121,15 @@ pub(crate) fn add(x: f256, y: f256) -> f256 {
         add_or_sub_subnormals(&signif_x, &signif_y, op)
     } else if exp_bits_x == exp_bits_y {
         // Exponents are equal, so there's no need for shifting and rounding.
-        add_or_sub_normals_exact(exp_bits_x, &signif_x, &signif_y, op)
+        add_or_sub_normals_exact(
+            exp_bits_x,
+            &signif_x,
+            &signif_y,
+            op,
+            sign_bits_hi_x,
+            rnd,
+            flags,
+        )
     } else {
         // Exponents and significands have to be adjusted and the result has
         // to be rounded.
@@ -103,9 +139,16 @@ pub(crate) fn add(x: f256, y: f256) -> f256 {
             &mut signif_x,
             &mut signif_y,
             op,
+            sign_bits_hi_x,
+            rnd,
+            flags,
         )
     };
 
+    // Rounding may have overflowed the range of finite values.
+    if abs_bits_z.hi.0 == INF_HI {
+        *flags |= ExceptionFlags::OVERFLOW;
+    }
     // The sign of the result is the sign of the operand with the greater
     // absolute value.
     abs_bits_z.hi.0 |= sign_bits_hi_x;
@@ -131,6 +174,9 @@ fn add_or_sub_normals_exact<'a>(
     signif_x: &'a U256,
     signif_y: &'a U256,
     op: fn(&'a U256, &'a U256) -> U256,
+    sign_bits_hi_z: u128,
+    rnd: RoundingMode,
+    flags: &mut ExceptionFlags,
 ) -> U256 {
     debug_assert!(exp_bits_z > 0);
     debug_assert!(signif_x >= signif_y);
@@ -140,13 +186,20 @@ fn add_or_sub_normals_exact<'a>(
     if abs_bits_z.hi.0 >= HI_FRACTION_BIAS << 1 {
         exp_bits_z += 1;
         // If the result overflows the range of values representable as
-        // `f256`, return +Inf.
+        // `f256`, return ±Inf or ±MAX, depending on the rounding mode.
         if exp_bits_z >= EXP_MAX {
-            return U256::new(INF_HI, 0);
+            *flags |= ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT;
+            return rnd.overflow(sign_bits_hi_z).bits;
         }
         let l2bits = (abs_bits_z.lo.0 & 3) as u32;
+        flags.set_inexact(l2bits & 1, false);
         abs_bits_z >>= 1;
-        abs_bits_z.incr_if(l2bits == 3);
+        abs_bits_z.incr_if(rnd.round_up(
+            sign_bits_hi_z,
+            l2bits & 2 != 0,
+            l2bits & 1,
+            1,
+        ));
     }
     // If subtraction cancelled the hidden bit, left-shift the significand
     // and decrement the exponent, unless exp is already zero.
@@ -162,6 +215,7 @@ fn add_or_sub_normals_exact<'a>(
     abs_bits_z
 }
 
+#[allow(clippy::too_many_arguments)]
 #[inline]
 fn add_or_sub_rounded<'a>(
     exp_bits_x: u32,
@@ -169,6 +223,9 @@ fn add_or_sub_rounded<'a>(
     signif_x: &'a mut U256,
     signif_y: &'a mut U256,
     op: fn(&'a U256, &'a U256) -> U256,
+    sign_bits_hi_z: u128,
+    rnd: RoundingMode,
+    flags: &mut ExceptionFlags,
 ) -> U256 {
     debug_assert!(exp_bits_x > 0); // x is normal!
     debug_assert!(
@@ -209,9 +266,10 @@ fn add_or_sub_rounded<'a>(
     if abs_bits_z.hi.0 >= HI_FRACTION_BIAS << 4 {
         exp_bits_z += 1;
         // If the result overflows the range of values representable as
-        // `f256`, return +Inf.
+        // `f256`, return ±Inf or ±MAX, depending on the rounding mode.
         if exp_bits_z >= EXP_MAX {
-            return U256::new(INF_HI, 0);
+            *flags |= ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT;
+            return rnd.overflow(sign_bits_hi_z).bits;
         }
         sticky_bit |= abs_bits_z.lo.0 & 1;
         abs_bits_z >>= 1;
@@ -227,13 +285,16 @@ fn add_or_sub_rounded<'a>(
     }
 
     // Get round, guard and sticky bit.
+    // A tiny result is always exact, so there's no need to check for
+    // underflow.
     let l3bits = (abs_bits_z.lo.0 & 0x7_u128) as u32;
+    flags.set_inexact(l3bits, false);
     // Shift significand back, erase hidden bit and set exponent.
     abs_bits_z >>= 3;
     abs_bits_z.hi.0 &= HI_FRACTION_MASK;
     abs_bits_z.hi.0 |= (exp_bits_z as u128) << HI_FRACTION_BITS;
     // Final rounding. Possibly overflowing into the exponent, but that is ok.
-    if l3bits > 0x4 || l3bits == 0x4 && abs_bits_z.lo.is_odd() {
+    if rnd.round_up(sign_bits_hi_z, abs_bits_z.lo.is_odd(), l3bits, 0x4) {
         abs_bits_z.incr();
     }
     abs_bits_z
@@ -264,3 +325,101 @@ impl Sub for f256 {
 forward_ref_binop!(impl Sub, sub);
 
 forward_op_assign!(impl SubAssign, sub_assign, Sub, sub);
+
+impl f256 {
+    /// Returns `self + rhs`, rounded according to the rounding mode `rnd`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, RoundingMode};
+    /// let x = f256::ONE;
+    /// let y = f256::EPSILON.div2();
+    /// assert_eq!(x.add_rounded(y, RoundingMode::NearestTiesEven), x);
+    /// assert_eq!(
+    ///     x.add_rounded(y, RoundingMode::TowardPositive),
+    ///     x + f256::EPSILON
+    /// );
+    /// assert_eq!(x.add_rounded(y, RoundingMode::TowardZero), x);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn add_rounded(self, rhs: Self, rnd: RoundingMode) -> Self {
+        add_rounded(self, rhs, rnd, &mut ExceptionFlags::default())
+    }
+
+    /// Returns `self - rhs`, rounded according to the rounding mode `rnd`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, RoundingMode};
+    /// let x = f256::ONE;
+    /// let y = f256::EPSILON.div_pow2(2);
+    /// assert_eq!(
+    ///     x.sub_rounded(y, RoundingMode::TowardNegative),
+    ///     x - f256::EPSILON.div2()
+    /// );
+    /// assert_eq!(x.sub_rounded(y, RoundingMode::TowardPositive), x);
+    /// let z = x.sub_rounded(x, RoundingMode::TowardNegative);
+    /// assert!(z.eq_zero() && z.is_sign_negative());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn sub_rounded(self, rhs: Self, rnd: RoundingMode) -> Self {
+        add_rounded(self, -rhs, rnd, &mut ExceptionFlags::default())
+    }
+
+    /// Returns `self + rhs`, rounded according to the rounding mode `rnd`,
+    /// together with the exception flags raised by the operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, ExceptionFlags, RoundingMode};
+    /// let rnd = RoundingMode::NearestTiesEven;
+    /// let x = f256::ONE;
+    /// let (z, flags) = x.add_with_flags(x, rnd);
+    /// assert_eq!(z, f256::TWO);
+    /// assert!(flags.is_empty());
+    /// let (z, flags) = x.add_with_flags(f256::EPSILON.div2(), rnd);
+    /// assert_eq!(z, x);
+    /// assert_eq!(flags, ExceptionFlags::INEXACT);
+    /// let (z, flags) = f256::INFINITY.add_with_flags(f256::NEG_INFINITY, rnd);
+    /// assert!(z.is_nan());
+    /// assert_eq!(flags, ExceptionFlags::INVALID);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn add_with_flags(
+        self,
+        rhs: Self,
+        rnd: RoundingMode,
+    ) -> (Self, ExceptionFlags) {
+        let mut flags = ExceptionFlags::default();
+        (add_rounded(self, rhs, rnd, &mut flags), flags)
+    }
+
+    /// Returns `self - rhs`, rounded according to the rounding mode `rnd`,
+    /// together with the exception flags raised by the operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, ExceptionFlags, RoundingMode};
+    /// let rnd = RoundingMode::TowardZero;
+    /// let (z, flags) = f256::MIN.sub_with_flags(f256::MAX, rnd);
+    /// assert_eq!(z, f256::MIN);
+    /// assert_eq!(flags, ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn sub_with_flags(
+        self,
+        rhs: Self,
+        rnd: RoundingMode,
+    ) -> (Self, ExceptionFlags) {
+        let mut flags = ExceptionFlags::default();
+        (add_rounded(self, -rhs, rnd, &mut flags), flags)
+    }
+}
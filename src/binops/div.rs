@@ -19,6 +19,7 @@ use crate::{
     HI_FRACTION_BITS, HI_FRACTION_MASK, HI_SIGN_MASK, INF_HI, MAX_HI,
     SIGNIFICAND_BITS, U256, U512,
 };
+use crate::{ExceptionFlags, RoundingMode};
 
 #[inline]
 fn div_signifs(x: &U256, y: &U256) -> (U256, u32) {
@@ -34,11 +35,27 @@ fn div_signifs(x: &U256, y: &U256) -> (U256, u32) {
 }
 
 // Compute z = x / y, rounded tie to even.
+#[inline(always)]
+pub(crate) fn div(x: f256, y: f256) -> f256 {
+    div_rounded(
+        x,
+        y,
+        RoundingMode::NearestTiesEven,
+        &mut ExceptionFlags::default(),
+    )
+}
+
+// Compute z = x / y, rounded according to `rnd`.
 #[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::cast_possible_wrap)]
 #[allow(clippy::cast_sign_loss)]
 #[inline]
-pub(crate) fn div(x: f256, y: f256) -> f256 {
+pub(crate) fn div_rounded(
+    x: f256,
+    y: f256,
+    rnd: RoundingMode,
+    flags: &mut ExceptionFlags,
+) -> f256 {
     // The quotients sign is the XOR of the signs of the operands.
     let sign_bits_hi_z = (x.bits.hi.0 ^ y.bits.hi.0) & HI_SIGN_MASK;
     let mut abs_bits_x = abs_bits(&x);
@@ -55,6 +72,9 @@ pub(crate) fn div(x: f256, y: f256) -> f256 {
             || abs_bits_sticky_x == abs_bits_sticky_y
         {
             // Atleast one operand is NAN or we have ±0 / ±0 or ±Inf / ±Inf.
+            if max_abs_bits_sticky <= HI_EXP_MASK {
+                *flags |= ExceptionFlags::INVALID;
+            }
             return f256::NAN;
         }
         if abs_bits_sticky_x < abs_bits_sticky_y {
@@ -64,6 +84,9 @@ pub(crate) fn div(x: f256, y: f256) -> f256 {
             };
         }
         // ±Inf / ±0 or ±finite / ±0 or ±Inf / ±finite.
+        if abs_bits_sticky_x < INF_HI {
+            *flags |= ExceptionFlags::DIV_BY_ZERO;
+        }
         return f256 {
             bits: U256::new(sign_bits_hi_z | INF_HI, 0),
         };
@@ -86,11 +109,10 @@ pub(crate) fn div(x: f256, y: f256) -> f256 {
         + (-EMIN - 1)
         + (norm_signif_x >= norm_signif_y) as i32;
     // If the result overflows the range of values representable as `f256`,
-    // return +/- Infinity.
+    // return ±Infinity or ±MAX, depending on the rounding mode.
     if exp_bits_z_minus_1 >= EXP_MAX as i32 - 1 {
-        return f256 {
-            bits: U256::new(sign_bits_hi_z | INF_HI, 0),
-        };
+        *flags |= ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT;
+        return rnd.overflow(sign_bits_hi_z);
     }
     let (mut signif_z, mut rnd_bits) =
         div_signifs(&norm_signif_x, &norm_signif_y);
@@ -100,10 +122,11 @@ pub(crate) fn div(x: f256, y: f256) -> f256 {
     if exp_bits_z_minus_1 < 0 {
         let shift = exp_bits_z_minus_1.unsigned_abs();
         if shift > SIGNIFICAND_BITS + 1 {
-            // Result underflows to zero.
-            return f256 {
-                bits: U256::new(sign_bits_hi_z, 0),
-            };
+            // Result underflows to zero, but is inexact.
+            *flags |= ExceptionFlags::UNDERFLOW | ExceptionFlags::INEXACT;
+            let mut bits_z = U256::new(sign_bits_hi_z, 0);
+            bits_z.incr_if(rnd.round_up(sign_bits_hi_z, false, 1, 0b10));
+            return f256 { bits: bits_z };
         }
         if shift > 0 {
             // Adjust the rounding bits for correct final rounding.
@@ -118,15 +141,17 @@ pub(crate) fn div(x: f256, y: f256) -> f256 {
                 }
                 3..=127 => {
                     let rem = signif_z.rem_pow2(shift).lo.0;
-                    rnd_bits = (rem >> (shift - 2)) as u32
-                        | (rem > (1_u128 << (shift - 1))) as u32
-                        | (rnd_bits != 0) as u32;
+                    rnd_bits = ((rem >> (shift - 1)) as u32) << 1
+                        | (rem & ((1_u128 << (shift - 1)) - 1) != 0
+                            || rnd_bits != 0)
+                            as u32;
                 }
                 _ => {
                     let rem = signif_z.rem_pow2(shift);
-                    rnd_bits = (rem >> (shift - 2)).lo.0 as u32
-                        | (rem > (U256::ONE << (shift - 1))) as u32
-                        | (rnd_bits != 0) as u32;
+                    rnd_bits = ((rem >> (shift - 1)).lo.0 as u32) << 1
+                        | (!rem.rem_pow2(shift - 1).is_zero()
+                            || rnd_bits != 0)
+                            as u32;
                 }
             }
             signif_z >>= shift;
@@ -139,11 +164,16 @@ pub(crate) fn div(x: f256, y: f256) -> f256 {
         signif_z.hi.0 + ((exp_bits_z_minus_1 as u128) << HI_FRACTION_BITS),
         signif_z.lo.0,
     );
+    // The result is tiny if its biased exponent is 0 before rounding.
+    flags.set_inexact(rnd_bits, bits_z.hi.0 < HI_FRACTION_BIAS);
     bits_z.hi.0 |= sign_bits_hi_z;
 
     // Final rounding. Possibly overflowing into the exponent, but that is ok.
-    if rnd_bits > 0b10 || (rnd_bits == 0b10 && bits_z.lo.is_odd()) {
+    if rnd.round_up(sign_bits_hi_z, bits_z.lo.is_odd(), rnd_bits, 0b10) {
         bits_z.incr();
+        if bits_z.hi.0 & HI_ABS_MASK == INF_HI {
+            *flags |= ExceptionFlags::OVERFLOW;
+        }
     }
     f256 { bits: bits_z }
 }
@@ -159,3 +189,57 @@ impl Div for f256 {
 forward_ref_binop!(impl Div, div);
 
 forward_op_assign!(impl DivAssign, div_assign, Div, div);
+
+impl f256 {
+    /// Returns `self / rhs`, rounded according to the rounding mode `rnd`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, RoundingMode};
+    /// let x = f256::ONE;
+    /// let y = f256::from(3);
+    /// let lo = x.div_rounded(y, RoundingMode::TowardNegative);
+    /// let hi = x.div_rounded(y, RoundingMode::TowardPositive);
+    /// assert_eq!(hi, lo.next_up());
+    /// assert_eq!(x.div_rounded(y, RoundingMode::TowardZero), lo);
+    /// assert_eq!(x.div_rounded(y, RoundingMode::NearestTiesEven), x / y);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn div_rounded(self, rhs: Self, rnd: RoundingMode) -> Self {
+        div_rounded(self, rhs, rnd, &mut ExceptionFlags::default())
+    }
+
+    /// Returns `self / rhs`, rounded according to the rounding mode `rnd`,
+    /// together with the exception flags raised by the operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, ExceptionFlags, RoundingMode};
+    /// let rnd = RoundingMode::NearestTiesEven;
+    /// let x = f256::ONE;
+    /// let (z, flags) = x.div_with_flags(f256::from(4), rnd);
+    /// assert_eq!(z, f256::from(0.25));
+    /// assert!(flags.is_empty());
+    /// let (z, flags) = x.div_with_flags(f256::from(3), rnd);
+    /// assert_eq!(flags, ExceptionFlags::INEXACT);
+    /// let (z, flags) = x.div_with_flags(f256::NEG_ZERO, rnd);
+    /// assert_eq!(z, f256::NEG_INFINITY);
+    /// assert_eq!(flags, ExceptionFlags::DIV_BY_ZERO);
+    /// let (z, flags) = f256::ZERO.div_with_flags(f256::ZERO, rnd);
+    /// assert!(z.is_nan());
+    /// assert_eq!(flags, ExceptionFlags::INVALID);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn div_with_flags(
+        self,
+        rhs: Self,
+        rnd: RoundingMode,
+    ) -> (Self, ExceptionFlags) {
+        let mut flags = ExceptionFlags::default();
+        (div_rounded(self, rhs, rnd, &mut flags), flags)
+    }
+}
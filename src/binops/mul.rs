@@ -9,6 +9,7 @@
 
 use core::{
     cmp::{max, min},
+    iter::Product,
     ops::{Mul, MulAssign},
 };
 
@@ -19,6 +20,7 @@ use crate::{
     HI_FRACTION_MASK, HI_SIGN_MASK, INF_HI, MAX_HI, SIGNIFICAND_BITS,
     TOTAL_BITS, U256, U512,
 };
+use crate::{math::Float512, ExceptionFlags, RoundingMode};
 
 #[inline]
 #[allow(clippy::cast_possible_truncation)]
@@ -29,9 +31,9 @@ fn mul_signifs(x: &U256, y: &U256) -> (U256, u32, u32) {
     let carry = (hi.hi.0 >= HI_SIGN_MASK) as u32;
     let shift = EXP_BITS - 1 + carry;
     let rem = hi.rem_pow2(shift).lo;
-    let rnd_bits = (rem.0 >> (shift - 2)) as u32
-        | (rem.0 > (1 << (shift - 1))) as u32
-        | !lo.is_zero() as u32;
+    // rnd_bits = round bit << 1 | sticky bit
+    let rnd_bits = ((rem.0 >> (shift - 1)) as u32) << 1
+        | (rem.0 & ((1 << (shift - 1)) - 1) != 0 || !lo.is_zero()) as u32;
     hi >>= shift;
     (hi, carry, rnd_bits)
 }
@@ -72,8 +74,8 @@ pub(crate) fn mul_abs_finite(
     if exp_bits_z_minus_1 < 0 {
         let shift = exp_bits_z_minus_1.unsigned_abs();
         if shift > SIGNIFICAND_BITS + 1 {
-            // Result underflows to zero.
-            return (U256::ZERO, 0_u32);
+            // Result underflows to zero, but is inexact.
+            return (U256::ZERO, 1_u32);
         }
         if shift > 0 {
             // Adjust the rounding bits for correct final rounding.
@@ -88,15 +90,17 @@ pub(crate) fn mul_abs_finite(
                 }
                 3..=127 => {
                     let rem = signif_z.rem_pow2(shift).lo.0;
-                    rnd_bits = (rem >> (shift - 2)) as u32
-                        | (rem > (1_u128 << (shift - 1))) as u32
-                        | (rnd_bits != 0) as u32;
+                    rnd_bits = ((rem >> (shift - 1)) as u32) << 1
+                        | (rem & ((1_u128 << (shift - 1)) - 1) != 0
+                            || rnd_bits != 0)
+                            as u32;
                 }
                 _ => {
                     let rem = signif_z.rem_pow2(shift);
-                    rnd_bits = (rem >> (shift - 2)).lo.0 as u32
-                        | (rem > (U256::ONE << (shift - 1))) as u32
-                        | (rnd_bits != 0) as u32;
+                    rnd_bits = ((rem >> (shift - 1)).lo.0 as u32) << 1
+                        | (!rem.rem_pow2(shift - 1).is_zero()
+                            || rnd_bits != 0)
+                            as u32;
                 }
             }
             signif_z >>= shift;
@@ -113,11 +117,27 @@ pub(crate) fn mul_abs_finite(
 }
 
 /// Compute z = x * y, rounded tie to even.
+#[inline(always)]
+pub(crate) fn mul(x: f256, y: f256) -> f256 {
+    mul_rounded(
+        x,
+        y,
+        RoundingMode::NearestTiesEven,
+        &mut ExceptionFlags::default(),
+    )
+}
+
+/// Compute z = x * y, rounded according to `rnd`.
 #[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::cast_possible_wrap)]
 #[allow(clippy::cast_sign_loss)]
 #[inline]
-pub(crate) fn mul(x: f256, y: f256) -> f256 {
+pub(crate) fn mul_rounded(
+    x: f256,
+    y: f256,
+    rnd: RoundingMode,
+    flags: &mut ExceptionFlags,
+) -> f256 {
     // The products sign is the XOR of the signs of the operands.
     let sign_bits_hi_z = (x.bits.hi.0 ^ y.bits.hi.0) & HI_SIGN_MASK;
     let mut abs_bits_x = abs_bits(&x);
@@ -141,6 +161,7 @@ pub(crate) fn mul(x: f256, y: f256) -> f256 {
             };
             if max_abs_bits_sticky == INF_HI {
                 // ±0 × ±Inf or ±Inf × ±0
+                *flags |= ExceptionFlags::INVALID;
                 return f256::NAN;
             }
         }
@@ -156,11 +177,21 @@ pub(crate) fn mul(x: f256, y: f256) -> f256 {
 
     // Both operands are finite and non-zero.
     let (mut bits_z, rnd_bits) = mul_abs_finite(&abs_bits_x, &abs_bits_y);
+    if bits_z.hi.0 == INF_HI {
+        // Result overflows.
+        *flags |= ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT;
+        return rnd.overflow(sign_bits_hi_z);
+    }
+    // The result is tiny if its biased exponent is 0 before rounding.
+    flags.set_inexact(rnd_bits, bits_z.hi.0 < HI_FRACTION_BIAS);
     bits_z.hi.0 |= sign_bits_hi_z;
 
     // Final rounding. Possibly overflowing into the exponent, but that is ok.
-    if rnd_bits > 0b10 || (rnd_bits == 0b10 && bits_z.lo.is_odd()) {
+    if rnd.round_up(sign_bits_hi_z, bits_z.lo.is_odd(), rnd_bits, 0b10) {
         bits_z.incr();
+        if bits_z.hi.0 & HI_ABS_MASK == INF_HI {
+            *flags |= ExceptionFlags::OVERFLOW;
+        }
     }
     f256 { bits: bits_z }
 }
@@ -176,3 +207,119 @@ impl Mul for f256 {
 forward_ref_binop!(impl Mul, mul);
 
 forward_op_assign!(impl MulAssign, mul_assign, Mul, mul);
+
+// The finite factors are multiplied using a 512-bit significand, keeping the
+// exponent of the intermediate product separately, so that intermediate
+// results can neither overflow nor underflow. Products of up to two factors
+// are exact before the final rounding, for more factors the relative error
+// of the intermediate product is less than n × 2⁻⁵¹¹.
+#[allow(clippy::cast_possible_truncation)]
+fn product<I: Iterator<Item = f256>>(iter: I) -> f256 {
+    // Any exponent beyond this limit leads to ±0 or ±Inf in the final
+    // conversion.
+    const EXP_LIMIT: i64 = 1 << 20;
+    let mut sign = 0_u32;
+    let mut has_zero = false;
+    let mut has_inf = false;
+    let mut acc = Float512::ONE;
+    let mut exp = 0_i64;
+    for x in iter {
+        if x.is_nan() {
+            return f256::NAN;
+        }
+        sign ^= x.sign();
+        if x.eq_zero() {
+            has_zero = true;
+        } else if x.is_infinite() {
+            has_inf = true;
+        } else if !has_zero && !has_inf {
+            acc *= Float512::from(&x.abs());
+            // Keep the intermediate product in [1..2).
+            let e = acc.exp();
+            acc = acc.mul_pow2(-e);
+            exp += i64::from(e);
+        }
+    }
+    let abs = match (has_zero, has_inf) {
+        (true, true) => return f256::NAN,
+        (true, false) => f256::ZERO,
+        (false, true) => f256::INFINITY,
+        (false, false) => {
+            f256::from(&acc.mul_pow2(exp.clamp(-EXP_LIMIT, EXP_LIMIT) as i32))
+        }
+    };
+    [abs, -abs][sign as usize]
+}
+
+/// The factors are multiplied with a 512-bit significand and an unbounded
+/// exponent, so intermediate products neither overflow nor underflow, and
+/// the result is rounded to `f256` once at the end.
+///
+/// The product of upto two factors is correctly rounded. For more than two
+/// factors the product is **not** guaranteed to be correctly rounded: the
+/// intermediate product has a relative error less than n × 2⁻⁵¹¹ before
+/// the final rounding, which may lead to a wrong result when the exact
+/// product is extremely close to the midpoint between two `f256` values.
+impl Product for f256 {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        product(iter)
+    }
+}
+
+/// See the implementation of `Product<f256>` for the accuracy of the
+/// result.
+impl<'a> Product<&'a Self> for f256 {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        product(iter.copied())
+    }
+}
+
+impl f256 {
+    /// Returns `self * rhs`, rounded according to the rounding mode `rnd`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, RoundingMode};
+    /// let x = f256::ONE / f256::from(3);
+    /// let lo = x.mul_rounded(x, RoundingMode::TowardNegative);
+    /// let hi = x.mul_rounded(x, RoundingMode::TowardPositive);
+    /// assert_eq!(hi, lo.next_up());
+    /// assert!(lo <= x * x && x * x <= hi);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn mul_rounded(self, rhs: Self, rnd: RoundingMode) -> Self {
+        mul_rounded(self, rhs, rnd, &mut ExceptionFlags::default())
+    }
+
+    /// Returns `self * rhs`, rounded according to the rounding mode `rnd`,
+    /// together with the exception flags raised by the operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, ExceptionFlags, RoundingMode};
+    /// let rnd = RoundingMode::NearestTiesEven;
+    /// let x = f256::from(3);
+    /// let (z, flags) = x.mul_with_flags(x, rnd);
+    /// assert_eq!(z, f256::from(9));
+    /// assert!(flags.is_empty());
+    /// let (z, flags) = f256::MIN_POSITIVE.mul_with_flags(f256::EPSILON, rnd);
+    /// assert_eq!(z, f256::MIN_GT_ZERO);
+    /// assert!(flags.is_empty());
+    /// let (z, flags) = z.mul_with_flags(f256::ONE.div2(), rnd);
+    /// assert_eq!(z, f256::ZERO);
+    /// assert_eq!(flags, ExceptionFlags::UNDERFLOW | ExceptionFlags::INEXACT);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn mul_with_flags(
+        self,
+        rhs: Self,
+        rnd: RoundingMode,
+    ) -> (Self, ExceptionFlags) {
+        let mut flags = ExceptionFlags::default();
+        (mul_rounded(self, rhs, rnd, &mut flags), flags)
+    }
+}
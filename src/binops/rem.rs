@@ -14,15 +14,16 @@ use core::{
 
 use crate::{
     abs_bits, abs_bits_sticky, exp_bits, f256, norm_bit, sign_bits_hi,
-    signif, BigUInt, BinEncAnySpecial, DivRem, HiLo, FRACTION_BITS,
-    HI_EXP_MASK, HI_FRACTION_BITS, MAX_HI, SIGNIFICAND_BITS, U256, U512,
+    signif, BigUInt, BinEncAnySpecial, DivRem, ExceptionFlags, HiLo, EMAX,
+    FRACTION_BITS, HI_EXP_MASK, HI_FRACTION_BITS, MAX_HI, SIGNIFICAND_BITS,
+    U256, U512,
 };
 
 // Compute z = x % y.
 #[inline]
 pub(crate) fn rem(x: f256, y: f256) -> f256 {
-    let mut abs_bits_x = abs_bits(&x);
-    let mut abs_bits_y = abs_bits(&y);
+    let abs_bits_x = abs_bits(&x);
+    let abs_bits_y = abs_bits(&y);
     // Check whether one or both operands are NaN, infinite or zero.
     // We mask off the sign bit and mark subnormals having a significand less
     // than 2¹²⁸ in least bit of the representations high u128. This allows to
@@ -54,33 +55,158 @@ pub(crate) fn rem(x: f256, y: f256) -> f256 {
         return x;
     }
 
-    // Extract biased exponents and significands.
-    let mut exp_bits_x = exp_bits(&abs_bits_x);
-    let norm_bit_x = norm_bit(&abs_bits_x);
-    let mut signif_x = signif(&abs_bits_x);
-    let mut exp_bits_y = exp_bits(&abs_bits_y);
-    let norm_bit_y = norm_bit(&abs_bits_y);
-    let mut signif_y = signif(&abs_bits_y);
-
-    // If the significands are equal, |x| is an integral multiple of |y|.
-    if signif_x == signif_y {
+    let (abs_bits_z, _) = long_div(&abs_bits_x, &abs_bits_y);
+    if abs_bits_z.is_zero() {
         return f256::ZERO;
     }
+    f256 {
+        bits: U256::new(sign_bits_hi(&x) | abs_bits_z.hi.0, abs_bits_z.lo.0),
+    }
+}
+
+// Computes |x| % |y| and ⌊|x| / |y|⌋ for finite, non-zero x and y.
+fn abs_rem_quot(x: &f256, y: &f256) -> (f256, Quotient) {
+    let abs_bits_x = abs_bits(x);
+    let abs_bits_y = abs_bits(y);
+    if abs_bits_x < abs_bits_y {
+        return (x.abs(), Quotient::ZERO);
+    }
+    let (abs_bits_z, quot) = long_div(&abs_bits_x, &abs_bits_y);
+    (f256 { bits: abs_bits_z }, quot)
+}
+
+// Truncated quotient q = ⌊|x| / |y|⌋ of a long division, condensed to the
+// information needed to round q or q + 1 and to get its lowest bits:
+// q = lead × 2²⁵⁶ⁿ + tail, where n = n_tail_chunks and tail < 2²⁵⁶ⁿ.
+#[derive(Clone, Copy, Debug)]
+struct Quotient {
+    lead: U512,
+    n_tail_chunks: u32,
+    tail_is_zero: bool,
+    tail_is_max: bool,
+    low_bits: u64,
+}
+
+impl Quotient {
+    const ZERO: Self = Self {
+        lead: U512::ZERO,
+        n_tail_chunks: 0,
+        tail_is_zero: true,
+        tail_is_max: true,
+        low_bits: 0,
+    };
+
+    // Appends the next chunk of quotient bits.
+    #[allow(clippy::cast_possible_truncation)]
+    fn push(&mut self, chunk: &U256) {
+        if self.lead.hi.is_zero() {
+            self.lead = U512::from_hi_lo(self.lead.lo, *chunk);
+        } else {
+            self.n_tail_chunks += 1;
+            self.tail_is_zero &= chunk.is_zero();
+            self.tail_is_max &= *chunk == U256::MAX;
+        }
+        self.low_bits = chunk.lo.0 as u64;
+    }
+
+    /// Returns the lowest 64 bits of q (or q + 1, if `incr` is true).
+    #[inline]
+    const fn low_bits(&self, incr: bool) -> u64 {
+        self.low_bits.wrapping_add(incr as u64)
+    }
+
+    /// Returns q (or q + 1, if `incr` is true), rounded to the nearest
+    /// `f256`, ties to even.
+    #[allow(clippy::cast_possible_wrap)]
+    fn rounded(&self, incr: bool) -> f256 {
+        let mut lead = self.lead;
+        // Indicates whether any bit of the tail is set.
+        let mut sticky = !self.tail_is_zero;
+        if incr {
+            if self.tail_is_max {
+                // The tail overflows into lead and becomes zero.
+                lead.incr();
+                sticky = false;
+            } else {
+                sticky = true;
+            }
+        }
+        if lead.is_zero() {
+            return f256::ZERO;
+        }
+        // Reduce lead to atmost 255 bits, keeping track of the bits shifted
+        // out. If there is a tail, lead has more than 256 bits, so sticky
+        // will not touch the rounding bit.
+        let shr = lead.msb().saturating_sub(U256::BITS - 2);
+        let (lead, rem) = lead.widening_shr(shr);
+        sticky |= !rem.is_zero();
+        let mut signif = lead.lo;
+        signif.lo.0 |= sticky as u128;
+        let mut t = (shr + U256::BITS * self.n_tail_chunks) as i32;
+        // Round to SIGNIFICAND_BITS bits.
+        let msb = signif.msb();
+        if msb >= SIGNIFICAND_BITS {
+            let sh = msb + 1 - SIGNIFICAND_BITS;
+            signif = signif.rounding_div_pow2(sh);
+            t += sh as i32;
+            if signif.msb() >= SIGNIFICAND_BITS {
+                signif >>= 1;
+                t += 1;
+            }
+        }
+        if t + signif.msb() as i32 > EMAX {
+            return f256::INFINITY;
+        }
+        f256::encode(0, t, signif)
+    }
+}
+
+// Computes |x| % |y| and ⌊|x| / |y|⌋ for finite, non-zero x and y with
+// |x| >= |y|, given the absolute bits of x and y. The remainder is returned
+// as absolute bits.
+#[allow(clippy::cast_possible_truncation)]
+fn long_div(abs_bits_x: &U256, abs_bits_y: &U256) -> (U256, Quotient) {
+    debug_assert!(abs_bits_x >= abs_bits_y);
+    // Extract biased exponents and significands.
+    let exp_bits_x = exp_bits(abs_bits_x);
+    let norm_bit_x = norm_bit(abs_bits_x);
+    let signif_x = signif(abs_bits_x);
+    let exp_bits_y = exp_bits(abs_bits_y);
+    let norm_bit_y = norm_bit(abs_bits_y);
+    let signif_y = signif(abs_bits_y);
 
     let n_bits = exp_bits_x + norm_bit_y - exp_bits_y - norm_bit_x;
     let sh = n_bits % U256::BITS;
+    let n_chunks = n_bits >> 8;
     let mut t = U512::from_hi_lo(U256::ZERO, signif_x);
     t <<= sh;
-    let mut abs_bits_z = t % signif_y;
-    for _ in 0..n_bits >> 8 {
-        t = U512::from_hi_lo(abs_bits_z, U256::ZERO);
-        abs_bits_z = t % signif_y;
+    let (lead, mut abs_bits_z) = t.div_rem(signif_y);
+    let mut quot = Quotient {
+        lead,
+        ..Quotient::ZERO
+    };
+    quot.low_bits = lead.lo.lo.0 as u64;
+    for i in 0..n_chunks {
         if abs_bits_z.is_zero() {
+            // All remaining chunks of the quotient are zero. As the
+            // quotient is not zero, pushing one zero chunk is enough to
+            // fill lead.
+            quot.push(&U256::ZERO);
+            let n_zero_chunks = n_chunks - i - 1;
+            if n_zero_chunks > 0 {
+                quot.n_tail_chunks += n_zero_chunks;
+                quot.tail_is_max = false;
+                quot.low_bits = 0;
+            }
             break;
         }
+        t = U512::from_hi_lo(abs_bits_z, U256::ZERO);
+        let chunk;
+        (chunk, abs_bits_z) = t.div_rem(signif_y);
+        quot.push(&chunk.lo);
     }
     if abs_bits_z.is_zero() {
-        return f256::ZERO;
+        return (abs_bits_z, quot);
     }
     let shift_z = min(
         FRACTION_BITS - abs_bits_z.msb(),
@@ -89,9 +215,7 @@ pub(crate) fn rem(x: f256, y: f256) -> f256 {
     abs_bits_z <<= shift_z;
     let exp_bits_z_m1 = (exp_bits_y - shift_z).saturating_sub(1);
     abs_bits_z.hi.0 += (exp_bits_z_m1 as u128) << HI_FRACTION_BITS;
-    f256 {
-        bits: U256::new(sign_bits_hi(&x) | abs_bits_z.hi.0, abs_bits_z.lo.0),
-    }
+    (abs_bits_z, quot)
 }
 
 impl Rem for f256 {
@@ -105,3 +229,160 @@ impl Rem for f256 {
 forward_ref_binop!(impl Rem, rem);
 
 forward_op_assign!(impl RemAssign, rem_assign, Rem, rem);
+
+impl f256 {
+    /// Returns `self % rhs`, together with the exception flags raised by the
+    /// operation.
+    ///
+    /// The remainder is always exact, so the only exception to be signaled
+    /// is an invalid operation for `x % ±0` and `±Inf % y`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, ExceptionFlags};
+    /// let (z, flags) = f256::from(7.5).rem_with_flags(f256::TWO);
+    /// assert_eq!(z, f256::from(1.5));
+    /// assert!(flags.is_empty());
+    /// let (z, flags) = f256::ONE.rem_with_flags(f256::ZERO);
+    /// assert!(z.is_nan());
+    /// assert_eq!(flags, ExceptionFlags::INVALID);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn rem_with_flags(self, rhs: Self) -> (Self, ExceptionFlags) {
+        let z = rem(self, rhs);
+        if z.is_nan() && !self.is_nan() && !rhs.is_nan() {
+            (z, ExceptionFlags::INVALID)
+        } else {
+            (z, ExceptionFlags::NONE)
+        }
+    }
+
+    /// Computes the remainder of `self` / `rhs` as defined by IEEE 754:
+    /// `self - n * rhs`, where `n` is the integer nearest to the exact value
+    /// of `self` / `rhs`, ties to even.
+    ///
+    /// In contrast to `self % rhs`, the result r satisfies |r| <= |rhs| / 2.
+    /// The result is always exact. If it is zero, it has the sign of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f256::f256;
+    /// let y = f256::from(3);
+    /// assert_eq!(f256::from(5).remainder(y), f256::from(-1));
+    /// assert_eq!(f256::from(4).remainder(y), f256::from(1));
+    /// // Ties are resolved to an even quotient.
+    /// assert_eq!(f256::from(4.5).remainder(y), f256::from(-1.5));
+    /// assert_eq!(f256::from(7.5).remainder(y), f256::from(1.5));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn remainder(self, rhs: Self) -> Self {
+        self.remquo(rhs).0
+    }
+
+    /// Computes the remainder of `self` / `rhs` like [`f256::remainder`],
+    /// together with the lowest bits of the quotient `n` used.
+    ///
+    /// The returned integer has the sign of `self` / `rhs` and its magnitude
+    /// is congruent to |n| modulo 2⁶³. It is zero if the remainder is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f256::f256;
+    /// let y = f256::from(0.25);
+    /// let (r, q) = f256::from(-5.125).remquo(y);
+    /// assert_eq!((r, q), (f256::from(-0.125), -20));
+    /// let (r, q) = f256::from(1e100).remquo(y);
+    /// assert_eq!((r, q & 7), (f256::ZERO, 0));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn remquo(self, rhs: Self) -> (Self, i64) {
+        if !self.is_finite()
+            || !rhs.is_finite()
+            || self.eq_zero()
+            || rhs.eq_zero()
+        {
+            return (rem(self, rhs), 0);
+        }
+        let (mut r, quot) = abs_rem_quot(&self, &rhs);
+        let abs_y = rhs.abs();
+        // Round the quotient to nearest, ties to even. 2 × |r| is exact or
+        // overflows, but then it's greater than |y| anyway.
+        let twice_r = r + r;
+        let incr = twice_r > abs_y
+            || (twice_r == abs_y && quot.low_bits(false) & 1 == 1);
+        if incr {
+            // |y| / 2 <= |r| < |y| => |y| - |r| is exact
+            r = -(abs_y - r);
+        }
+        let quo = (quot.low_bits(incr) & (u64::MAX >> 1)) as i64;
+        let sign_x = self.sign();
+        let r = if r.eq_zero() {
+            [Self::ZERO, Self::NEG_ZERO][sign_x as usize]
+        } else if sign_x == 1 {
+            -r
+        } else {
+            r
+        };
+        if sign_x == rhs.sign() {
+            (r, quo)
+        } else {
+            (r, -quo)
+        }
+    }
+
+    /// Calculates Euclidean division, the matching method for rem_euclid.
+    ///
+    /// This computes the integer n such that
+    /// self = n * rhs + self.rem_euclid(rhs).
+    /// In other words, the result is self / rhs rounded to the integer n
+    /// such that self >= n * rhs.
+    ///
+    /// The integer n is determined exactly and then rounded to the nearest
+    /// representable value, ties to even.
+    #[must_use]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        if !self.is_finite()
+            || !rhs.is_finite()
+            || self.eq_zero()
+            || rhs.eq_zero()
+        {
+            return (self / rhs).floor();
+        }
+        let (r, quot) = abs_rem_quot(&self, &rhs);
+        if self.sign() == rhs.sign() {
+            quot.rounded(false)
+        } else {
+            -quot.rounded(!r.eq_zero())
+        }
+    }
+
+    /// Calculates the least nonnegative remainder of self (mod rhs).
+    ///
+    /// In particular, the return value r satisfies 0.0 <= r < rhs.abs() in
+    /// most cases. However, due to a floating point round-off error it can
+    /// result in r == rhs.abs(), violating the mathematical definition, if
+    /// self is much smaller than rhs.abs() in magnitude and self < 0.0.
+    /// This result is not an element of the function's codomain, but it is
+    /// the closest floating point number in the real numbers and thus
+    /// fulfills the property
+    /// self == self.div_euclid(rhs) * rhs + self.rem_euclid(rhs)
+    /// approximately.
+    #[must_use]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        if !rhs.is_finite() {
+            return Self::NAN;
+        }
+        let r = rem(self, rhs);
+        if !r.eq_zero() && r.sign() != rhs.sign() {
+            r + rhs
+        } else {
+            r
+        }
+    }
+}
@@ -14,7 +14,7 @@ const fn min(x: u32, y: u32) -> u32 {
     x - x.saturating_sub(y)
 }
 
-trait Float: Copy + Clone {
+pub(super) trait Float: Copy + Clone {
     /// Precision level in relation to single precision float (f32)
     const PREC_LEVEL: u32;
     /// Total number of bits
@@ -25,9 +25,10 @@ trait Float: Copy + Clone {
     const FRACTION_BITS: u32 = Self::TOTAL_BITS - Self::EXP_BITS - 1;
     /// Maximum value of biased base 2 exponent
     const BIASED_EXP_MAX: u32 = (1_u32 << Self::EXP_BITS) - 1;
+    /// Base 2 exponent bias
+    const EXP_BIAS: u32 = Self::BIASED_EXP_MAX >> 1;
     /// Base 2 exponent bias (incl. radix adjustment)
-    const EXP_BIAS_ADJ: u32 =
-        (Self::BIASED_EXP_MAX >> 1) + Self::FRACTION_BITS;
+    const EXP_BIAS_ADJ: u32 = Self::EXP_BIAS + Self::FRACTION_BITS;
     /// Fraction mask
     const FRACTION_MASK: u64 = (1_u64 << Self::FRACTION_BITS) - 1;
     /// Fraction bias
@@ -42,6 +43,8 @@ trait Float: Copy + Clone {
     const INF: u64 = (Self::BIASED_EXP_MAX as u64) << Self::FRACTION_BITS;
     /// Raw transmutation to u64.
     fn to_bits(self) -> u64;
+    /// Raw transmutation from u64.
+    fn from_bits(bits: u64) -> Self;
 }
 
 impl Float for f32 {
@@ -51,6 +54,12 @@ impl Float for f32 {
     fn to_bits(self) -> u64 {
         self.to_bits() as u64
     }
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_bits(bits: u64) -> Self {
+        Self::from_bits(bits as u32)
+    }
 }
 
 impl Float for f64 {
@@ -60,6 +69,11 @@ impl Float for f64 {
     fn to_bits(self) -> u64 {
         self.to_bits()
     }
+
+    #[inline]
+    fn from_bits(bits: u64) -> Self {
+        Self::from_bits(bits)
+    }
 }
 
 impl<F: Float> From<F> for f256 {
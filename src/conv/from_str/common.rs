@@ -9,6 +9,8 @@
 
 use core::ptr;
 
+use super::options::ParseOptions;
+
 /// Check whether an u64 is holding 8 decimal digits.
 #[inline]
 pub const fn chunk_contains_8_digits(chunk: u64) -> bool {
@@ -75,20 +77,24 @@ pub(super) struct ParsingState {
     pub(super) end_pos_signif: usize,
     pub(super) signif_truncated: bool,
     pub(super) pos_radix_point: Option<usize>,
-    pub(super) invalid: bool,
+    pub(super) pos_double_radix_point: Option<usize>,
 }
 
 // Bytes wrapper specialized for parsing number literals
 pub(super) struct AsciiNumLit<'a> {
     bytes: &'a [u8],
+    opts: &'a ParseOptions,
+    n_skipped_separators: usize,
     pub(super) state: ParsingState,
 }
 
 impl<'a> AsciiNumLit<'a> {
     #[inline]
-    pub fn new(bytes: &'a [u8]) -> Self {
+    pub fn new(bytes: &'a [u8], opts: &'a ParseOptions) -> Self {
         Self {
             bytes,
+            opts,
+            n_skipped_separators: 0,
             state: ParsingState::default(),
         }
     }
@@ -98,8 +104,17 @@ impl<'a> AsciiNumLit<'a> {
         self.bytes.is_empty()
     }
 
+    // Number of remaining bytes plus number of skipped digit separators.
+    // I.e. digit separators do not count when calculating the number of
+    // digits between two positions.
     #[inline]
     pub(super) const fn len(&self) -> usize {
+        self.bytes.len() + self.n_skipped_separators
+    }
+
+    // Number of remaining bytes.
+    #[inline]
+    pub(super) const fn n_bytes(&self) -> usize {
         self.bytes.len()
     }
 
@@ -133,11 +148,47 @@ impl<'a> AsciiNumLit<'a> {
         matches!(self.first(), Some(c) if c.wrapping_sub(b'0') < 10)
     }
 
+    // If the first byte is a digit separator, skip it and return true.
+    #[inline]
+    #[allow(unsafe_code)]
+    pub(super) fn skip_separator(&mut self) -> bool {
+        if self.opts.separator().is_some_and(|sep| self.first_eq(sep)) {
+            // SAFETY: safe because of condition above
+            unsafe { self.skip_1() };
+            self.n_skipped_separators += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    pub(super) const fn opts(&self) -> &ParseOptions {
+        self.opts
+    }
+
     #[inline]
     pub(super) fn eq_ignore_ascii_case(&self, other: &[u8]) -> bool {
         self.bytes.eq_ignore_ascii_case(other)
     }
 
+    /// Returns the number of leading bytes of self matching those of
+    /// `other` (ignoring ASCII case) and whether self is a proper prefix of
+    /// `other`.
+    #[inline]
+    pub(super) fn n_matching_ignore_ascii_case(
+        &self,
+        other: &[u8],
+    ) -> (usize, bool) {
+        let n = self
+            .bytes
+            .iter()
+            .zip(other)
+            .take_while(|(a, b)| a.eq_ignore_ascii_case(b))
+            .count();
+        (n, n == self.n_bytes() && n < other.len())
+    }
+
     #[inline]
     #[allow(unsafe_code)]
     pub(super) fn get_sign(&mut self) -> u32 {
@@ -169,7 +220,7 @@ impl<'a> AsciiNumLit<'a> {
     #[inline]
     #[allow(unsafe_code)]
     pub(super) fn read_u64(&self) -> Option<u64> {
-        (self.len() >= 8).then(|| {
+        (self.n_bytes() >= 8).then(|| {
             // SAFETY: safe because of condition above!
             unsafe { self.read_u64_unchecked() }
         })
@@ -181,32 +232,39 @@ impl<'a> AsciiNumLit<'a> {
     // position is recorded in the internal state and it is skipped also.
     #[allow(unsafe_code)]
     pub(super) fn skip_leading_zeroes(&mut self, skip_radix_point: bool) {
-        // First, try chunks of 8 digits
-        while let Some(mut k) = self.read_u64() {
-            if chunk_contains_8_digits(k) {
-                if chunk_to_u64(k) == 0 {
-                    // SAFETY: safe because of call to self.read_u64 above
-                    unsafe {
-                        self.skip_n(8);
+        loop {
+            // First, try chunks of 8 digits
+            while let Some(k) = self.read_u64() {
+                if chunk_contains_8_digits(k) {
+                    if chunk_to_u64(k) == 0 {
+                        // SAFETY: safe because of call to self.read_u64
+                        // above
+                        unsafe {
+                            self.skip_n(8);
+                        }
+                    } else {
+                        break;
                     }
                 } else {
                     break;
                 }
-            } else {
+            }
+            // Handle remaining zeroes
+            while self.first_eq(b'0') {
+                // SAFETY: safe because of condition above!
+                unsafe {
+                    self.skip_1();
+                };
+            }
+            // Continue after a digit separator
+            if !self.skip_separator() {
                 break;
             }
         }
-        // Handle remaining zeroes
-        while self.first_eq(b'0') {
-            // SAFETY: safe because of condition above!
-            unsafe {
-                self.skip_1();
-            };
-        }
         if self.first_eq(b'.') {
             if self.state.pos_radix_point.is_some() {
                 // Double radix point
-                self.state.invalid = true;
+                self.state.pos_double_radix_point = Some(self.n_bytes());
                 return;
             }
             if skip_radix_point {
@@ -220,11 +278,104 @@ impl<'a> AsciiNumLit<'a> {
         }
     }
 
+    // self <- self[x..] where x is the position of the first byte which is
+    // neither a digit in the given radix nor a digit separator. Returns the
+    // number of digits skipped.
+    #[allow(unsafe_code)]
+    pub(super) fn skip_digits(&mut self, radix: u32) -> usize {
+        let mut n_digits = 0_usize;
+        loop {
+            // First, try chunks of 8 digits
+            while let Some(k) = self.read_u64() {
+                if radix != 10 || !chunk_contains_8_digits(k) {
+                    break;
+                }
+                // SAFETY: safe because of call to self.read_u64 above
+                unsafe {
+                    self.skip_n(8);
+                }
+                n_digits += 8;
+            }
+            while self.first().is_some_and(|c| (*c as char).is_digit(radix)) {
+                // SAFETY: safe because of condition above!
+                unsafe {
+                    self.skip_1();
+                }
+                n_digits += 1;
+            }
+            // Continue after a digit separator
+            if !self.skip_separator() {
+                break;
+            }
+        }
+        n_digits
+    }
+
+    // self <- self[x..] where x is the end of the longest prefix of self
+    // which is an unsigned number, i.e. a sequence of digits in the given
+    // radix with an optional radix point, followed by an optional complete
+    // exponent. The exponent is marked by 'p' or 'P' for radix 16 and by
+    // the exponent marks of the options otherwise. Returns false and leaves
+    // self untouched if there is no such prefix.
+    #[allow(unsafe_code)]
+    pub(super) fn skip_number(&mut self, radix: u32) -> bool {
+        let (bytes, n_skipped_separators) =
+            (self.bytes, self.n_skipped_separators);
+        let mut n_digits = self.skip_digits(radix);
+        if self.first_eq(b'.') {
+            // SAFETY: safe because of condition above!
+            unsafe {
+                self.skip_1();
+            }
+            n_digits += self.skip_digits(radix);
+        }
+        if n_digits == 0 {
+            self.bytes = bytes;
+            self.n_skipped_separators = n_skipped_separators;
+            return false;
+        }
+        let (bytes, n_skipped_separators) =
+            (self.bytes, self.n_skipped_separators);
+        let has_exp_mark = self.first().is_some_and(|c| {
+            if radix == 16 {
+                matches!(c, b'p' | b'P')
+            } else {
+                self.opts.is_exp_mark(*c)
+            }
+        });
+        if has_exp_mark {
+            // SAFETY: safe because of condition above!
+            unsafe {
+                self.skip_1();
+            }
+            if matches!(self.first(), Some(b'+' | b'-')) {
+                // SAFETY: safe because of condition above!
+                unsafe {
+                    self.skip_1();
+                }
+            }
+            // Need atleast one digit, otherwise the exponent is not part
+            // of the number.
+            if !self.first_is_digit() {
+                self.bytes = bytes;
+                self.n_skipped_separators = n_skipped_separators;
+                return true;
+            }
+            while self.first_is_digit() {
+                // SAFETY: safe because of condition above!
+                unsafe {
+                    self.skip_1();
+                }
+            }
+        }
+        true
+    }
+
     #[allow(unsafe_code)]
     pub(super) fn parse_exponent(&mut self) -> Option<i32> {
         let mut exponent = 0_i32;
         if let Some(c) = self.first() {
-            if *c == b'e' || *c == b'E' {
+            if self.opts.is_exp_mark(*c) {
                 // SAFETY: safe because of condition above
                 unsafe { self.skip_1() };
                 let exp_is_negative = match self.first() {
@@ -244,17 +395,8 @@ impl<'a> AsciiNumLit<'a> {
                     _ => false,
                 };
                 // Need atleast one digit.
-                if let Some(c) = self.first() {
-                    let d = c.wrapping_sub(b'0');
-                    if d < 10 {
-                        exponent = d as i32;
-                        // SAFETY: safe because of call to self.first above
-                        unsafe {
-                            self.skip_1();
-                        }
-                    } else {
-                        return None;
-                    }
+                if !self.first_is_digit() {
+                    return None;
                 }
                 while let Some(c) = self.first() {
                     let d = c.wrapping_sub(b'0');
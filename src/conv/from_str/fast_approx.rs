@@ -14,6 +14,7 @@ use core::cmp::Ordering;
 
 use super::{
     fast_exact::fast_exact,
+    options::ParseOptions,
     powers_of_five::{get_power_of_five, MAX_ABS_EXP},
     slow_exact::f256_exact,
 };
@@ -25,6 +26,7 @@ use crate::{
 #[allow(clippy::cast_sign_loss)]
 pub(super) fn fast_approx(
     lit: &str,
+    opts: &ParseOptions,
     sign: u32,
     exp10: i32,
     mut signif10: U256,
@@ -108,7 +110,14 @@ pub(super) fn fast_approx(
                 // If f = f" then f = f'.
                 let mut signif10_incr = signif10;
                 signif10_incr.incr();
-                if f == fast_approx(lit, sign, exp10, signif10_incr, false) {
+                if f == fast_approx(
+                    lit,
+                    opts,
+                    sign,
+                    exp10,
+                    signif10_incr,
+                    false,
+                ) {
                     return f;
                 }
             } else {
@@ -118,5 +127,5 @@ pub(super) fn fast_approx(
     }
 
     // The last resort must always succeed!
-    f256_exact(lit)
+    f256_exact(lit, opts)
 }
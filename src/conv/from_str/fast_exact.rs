@@ -7,7 +7,9 @@
 // $Source$
 // $Revision$
 
-use super::{fast_approx::fast_approx, slow_exact::f256_exact};
+use super::{
+    fast_approx::fast_approx, options::ParseOptions, slow_exact::f256_exact,
+};
 use crate::{
     f256, BigUInt, FIVE, HI_FRACTION_BIAS, HI_FRACTION_BITS,
     HI_FRACTION_MASK, HI_SIGN_SHIFT, U256,
@@ -19,6 +21,7 @@ const MAX_EXP10: u32 = 102;
 /// Create a correctly rounded `f256` from a canonical decimal representation.
 pub(super) fn fast_exact(
     lit: &str,
+    opts: &ParseOptions,
     sign: u32,
     exp10: i32,
     mut signif10: U256,
@@ -62,17 +65,17 @@ pub(super) fn fast_exact(
             // If f = f" then f = f'.
             let mut signif10_incr = signif10;
             signif10_incr.incr();
-            if f == fast_exact(lit, sign, exp10, signif10_incr, false) {
+            if f == fast_exact(lit, opts, sign, exp10, signif10_incr, false) {
                 return f;
             }
             // The approx algorithm will not give a different result here, so
             // we fall back directly.
-            return f256_exact(lit);
+            return f256_exact(lit, opts);
         } else {
             return f;
         }
     }
-    fast_approx(lit, sign, exp10, signif10, signif_truncated)
+    fast_approx(lit, opts, sign, exp10, signif10, signif_truncated)
 }
 
 #[rustfmt::skip]
@@ -12,9 +12,13 @@ use core::{
     fmt::Display,
 };
 
-use super::common::{
-    chunk_contains_7_digits_and_a_dot_at, chunk_contains_8_digits,
-    chunk_to_u64, AsciiNumLit,
+use super::{
+    common::{
+        chunk_contains_7_digits_and_a_dot_at, chunk_contains_8_digits,
+        chunk_to_u64, AsciiNumLit,
+    },
+    options::ParseOptions,
+    parse_error::{ParseErrorKind, ParseF256Error},
 };
 use crate::U256;
 
@@ -44,11 +48,9 @@ pub(super) struct DecNumRepr {
     pub(super) signif_truncated: bool,
 }
 
-// Records the final parsing result.
+// Records the final parsing result of a valid literal.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub(super) enum FloatRepr {
-    Empty,
-    Invalid,
     Nan,
     Inf(u32),
     Number(DecNumRepr),
@@ -77,6 +79,18 @@ impl PartialSignif {
         self.n_chunks += 1;
     }
 
+    // More than 7 single digits in a row can only occur in literals with
+    // digit separators. Completed chunks are moved out of `rem` then.
+    const fn add_digit(&mut self, d: u64) {
+        self.rem = self.rem * 10 + d;
+        self.n_rem_digits += 1;
+        if self.n_rem_digits == 8 {
+            self.add_chunk(self.rem);
+            self.rem = 0;
+            self.n_rem_digits = 0;
+        }
+    }
+
     const fn n_digits(&self) -> usize {
         self.n_chunks * 8 + self.n_rem_digits
     }
@@ -125,89 +139,96 @@ impl FloatRepr {
         lit.state.start_pos_signif = lit.len();
         let max_n_digits = PartialSignif::MAX_N_DIGITS;
         let mut n_digits = 0_usize;
-        // First, try chunks of 8 digits
-        let limit = max_n_digits.saturating_sub(8);
-        while n_digits <= limit {
-            if let Some(mut k) = lit.read_u64() {
-                if chunk_contains_8_digits(k) {
-                    partial_signif.add_chunk(chunk_to_u64(k));
-                    n_digits += 8;
-                    // SAFETY: safe because of call to lit.read_u64 above
-                    unsafe {
-                        lit.skip_n(8);
-                    }
-                } else if let Some(p) =
-                    chunk_contains_7_digits_and_a_dot_at(k)
-                {
-                    if lit.state.pos_radix_point.is_some() {
-                        // Double radix point
-                        lit.state.invalid = true;
-                        return partial_signif;
-                    }
-                    // The index p points to the b'.' in chunk k in
-                    // little-endian order while lit.len() is based on the
-                    // big-endian order of the byte array. Therefore we have
-                    // to subtract (7 -p) here.
-                    lit.state.pos_radix_point =
-                        Some(lit.len() - (7 - p) as usize);
-                    // SAFETY: safe because of call to lit.read_u64 above
-                    unsafe {
-                        lit.skip_n(8);
-                    }
-                    if lit.first_is_digit() {
-                        // Assemble 8-digit chunk
-                        // SAFETY: unwrap is safe here because of call to
-                        // lit.first above.
-                        let d = *lit.first().unwrap();
-                        // SAFETY: dito.
-                        unsafe {
-                            lit.skip_1();
-                        }
-                        // The bytes in chunk k are in little endian order!
-                        // k <- d << 56 | k[..p] >> 8 | k[p+1..]
-                        k = eliminate_byte_from_chunk(k, p);
-                        k |= (d as u64) << 56;
+        loop {
+            // First, try chunks of 8 digits (as long as there are no single
+            // digits pending)
+            let limit = max_n_digits.saturating_sub(8);
+            while n_digits <= limit && partial_signif.n_rem_digits == 0 {
+                if let Some(mut k) = lit.read_u64() {
+                    if chunk_contains_8_digits(k) {
                         partial_signif.add_chunk(chunk_to_u64(k));
                         n_digits += 8;
+                        // SAFETY: safe because of call to lit.read_u64 above
+                        unsafe {
+                            lit.skip_n(8);
+                        }
+                    } else if let Some(p) =
+                        chunk_contains_7_digits_and_a_dot_at(k)
+                    {
+                        if lit.state.pos_radix_point.is_some() {
+                            // Double radix point
+                            lit.state.pos_double_radix_point =
+                                Some(lit.n_bytes() - (7 - p) as usize);
+                            return partial_signif;
+                        }
+                        // The index p points to the b'.' in chunk k in
+                        // little-endian order while lit.len() is based on the
+                        // big-endian order of the byte array. Therefore we have
+                        // to subtract (7 -p) here.
+                        lit.state.pos_radix_point =
+                            Some(lit.len() - (7 - p) as usize);
+                        // SAFETY: safe because of call to lit.read_u64 above
+                        unsafe {
+                            lit.skip_n(8);
+                        }
+                        if lit.first_is_digit() {
+                            // Assemble 8-digit chunk
+                            // SAFETY: unwrap is safe here because of call to
+                            // lit.first above.
+                            let d = *lit.first().unwrap();
+                            // SAFETY: dito.
+                            unsafe {
+                                lit.skip_1();
+                            }
+                            // The bytes in chunk k are in little endian order!
+                            // k <- d << 56 | k[..p] >> 8 | k[p+1..]
+                            k = eliminate_byte_from_chunk(k, p);
+                            k |= (d as u64) << 56;
+                            partial_signif.add_chunk(chunk_to_u64(k));
+                            n_digits += 8;
+                        } else {
+                            // No more digits
+                            // k <- k[..p] | k[p+1..] << 8
+                            k = eliminate_byte_from_chunk(k, p) << 8;
+                            partial_signif.rem = chunk_to_u64(k);
+                            partial_signif.n_rem_digits = 7;
+                            n_digits += 7;
+                        }
                     } else {
-                        // No more digits
-                        // k <- k[..p] | k[p+1..] << 8
-                        k = eliminate_byte_from_chunk(k, p) << 8;
-                        partial_signif.rem = chunk_to_u64(k);
-                        partial_signif.n_rem_digits = 7;
-                        n_digits += 7;
+                        break;
                     }
                 } else {
                     break;
                 }
-            } else {
-                break;
             }
-        }
-        // Read single digits
-        while let Some(c) = lit.first() {
-            if n_digits < max_n_digits {
-                let d = c.wrapping_sub(b'0');
-                if d < 10 {
-                    partial_signif.rem *= 10;
-                    partial_signif.rem += d as u64;
-                    partial_signif.n_rem_digits += 1;
-                    n_digits += 1;
-                } else if *c == b'.' {
-                    if lit.state.pos_radix_point.is_some() {
-                        // Double radix point
-                        lit.state.invalid = true;
-                        return partial_signif;
+            // Read single digits
+            while let Some(c) = lit.first() {
+                if n_digits < max_n_digits {
+                    let d = c.wrapping_sub(b'0');
+                    if d < 10 {
+                        partial_signif.add_digit(d as u64);
+                        n_digits += 1;
+                    } else if *c == b'.' {
+                        if lit.state.pos_radix_point.is_some() {
+                            // Double radix point
+                            lit.state.pos_double_radix_point =
+                                Some(lit.n_bytes());
+                            return partial_signif;
+                        }
+                        lit.state.pos_radix_point = Some(lit.len());
+                    } else {
+                        break;
+                    }
+                    // SAFETY: safe because of call to lit.first above
+                    unsafe {
+                        lit.skip_1();
                     }
-                    lit.state.pos_radix_point = Some(lit.len());
                 } else {
                     break;
                 }
-                // SAFETY: safe because of call to lit.first above
-                unsafe {
-                    lit.skip_1();
-                }
-            } else {
+            }
+            // Continue after a digit separator
+            if !lit.skip_separator() {
                 break;
             }
         }
@@ -220,11 +241,14 @@ impl FloatRepr {
             } else if *c == b'.' {
                 if lit.state.pos_radix_point.is_some() {
                     // Double radix point
-                    lit.state.invalid = true;
+                    lit.state.pos_double_radix_point = Some(lit.n_bytes());
                     return partial_signif;
                 }
                 lit.state.pos_radix_point = Some(lit.len());
             } else if *c != b'0' {
+                if lit.skip_separator() {
+                    continue;
+                }
                 break;
             }
             // SAFETY: safe because of call to lit.first above
@@ -238,53 +262,95 @@ impl FloatRepr {
         partial_signif
     }
 
-    #[allow(unsafe_code)]
-    fn parse_special(lit: &mut AsciiNumLit, sign: u32) -> Self {
-        if lit.eq_ignore_ascii_case(b"nan") {
-            Self::Nan
-        } else if lit.eq_ignore_ascii_case(b"inf")
-            || lit.eq_ignore_ascii_case(b"infinity")
+    fn parse_special(
+        lit: &AsciiNumLit,
+        sign: u32,
+        start: usize,
+    ) -> Result<Self, ParseF256Error> {
+        const SPECIAL_VALUES: [&[u8]; 3] = [b"nan", b"inf", b"infinity"];
+        let alt_infinity = lit.opts().alt_infinity_spelling();
+        let value = if lit.eq_ignore_ascii_case(SPECIAL_VALUES[0]) {
+            Some(Self::Nan)
+        } else if lit.eq_ignore_ascii_case(SPECIAL_VALUES[1])
+            || lit.eq_ignore_ascii_case(SPECIAL_VALUES[2])
+            || alt_infinity
+                .is_some_and(|val| lit.eq_ignore_ascii_case(val.as_bytes()))
         {
-            Self::Inf(sign)
+            Some(Self::Inf(sign))
         } else {
-            Self::Invalid
+            None
+        };
+        match value {
+            Some(value) if lit.opts().allows_special_values() => Ok(value),
+            Some(_) => {
+                Err(ParseF256Error::new(ParseErrorKind::InvalidChar, start))
+            }
+            None => {
+                // Report the position of the first char not matching any of
+                // the special values.
+                let (n_matching, is_prefix) = SPECIAL_VALUES
+                    .iter()
+                    .copied()
+                    .chain(alt_infinity.map(str::as_bytes))
+                    .map(|val| lit.n_matching_ignore_ascii_case(val))
+                    .max()
+                    .unwrap_or_default();
+                let kind = if is_prefix {
+                    ParseErrorKind::UnexpectedEnd
+                } else {
+                    ParseErrorKind::InvalidChar
+                };
+                Err(ParseF256Error::new(kind, start + n_matching))
+            }
         }
     }
 
+    /// Parses the given decimal literal according to the given options.
     #[allow(unsafe_code)]
     #[allow(clippy::cast_possible_truncation)]
     #[allow(clippy::cast_possible_wrap)]
-    pub(crate) fn from_str(s: &str) -> Self {
-        let mut lit = AsciiNumLit::new(s.as_ref());
+    pub(crate) fn from_str(
+        s: &str,
+        opts: &ParseOptions,
+    ) -> Result<Self, ParseF256Error> {
+        let mut lit = AsciiNumLit::new(s.as_ref(), opts);
+        // Byte offset of the current position of `lit` in `s`.
+        let pos = |lit: &AsciiNumLit| s.len() - lit.n_bytes();
 
         if lit.is_empty() {
-            return Self::Empty;
+            return Err(ParseF256Error::new(ParseErrorKind::Empty, 0));
         }
 
         let sign = lit.get_sign();
 
         if lit.is_empty() {
-            return Self::Invalid;
+            return Err(ParseF256Error::new(
+                ParseErrorKind::UnexpectedEnd,
+                pos(&lit),
+            ));
         }
         let start_pos = lit.len();
 
         lit.skip_leading_zeroes(true);
         if lit.is_empty() {
             // There must have been atleast one zero.
-            return Self::Number(DecNumRepr::default());
+            return Ok(Self::Number(DecNumRepr::default()));
         }
 
         // Parse significant digits.
         let mut partial_signif = Self::read_significand(&mut lit);
 
         // Check state.
-        if lit.state.invalid {
-            return Self::Invalid;
+        if let Some(p) = lit.state.pos_double_radix_point {
+            return Err(ParseF256Error::new(
+                ParseErrorKind::DoubleRadixPoint,
+                s.len() - p,
+            ));
         }
 
         // If there are no digits, check for special values.
         if start_pos == lit.len() {
-            return Self::parse_special(&mut lit, sign);
+            return Self::parse_special(&lit, sign, pos(&lit));
         }
 
         // Set implicit radix point if no one was detected.
@@ -296,15 +362,20 @@ impl FloatRepr {
         let mut exponent = match lit.parse_exponent() {
             Some(exp) => exp,
             None => {
-                return Self::Invalid;
+                return Err(ParseF256Error::new(
+                    ParseErrorKind::InvalidExponent,
+                    pos(&lit),
+                ));
             }
         };
 
         // Check bounds.
         if !lit.is_empty() {
-            return Self::Invalid;
+            return Err(ParseF256Error::new(
+                ParseErrorKind::InvalidChar,
+                pos(&lit),
+            ));
         }
-
         // Adjust exponent by truncated integer digits and fractional digits.
         let pos_radix_point =
             // Can't be None here.
@@ -322,12 +393,12 @@ impl FloatRepr {
         let n_trailing_zeroes = partial_signif.normalize();
         exponent += n_trailing_zeroes as i32;
         let significand = partial_signif.significand();
-        Self::Number(DecNumRepr {
+        Ok(Self::Number(DecNumRepr {
             sign,
             exponent,
             significand,
             signif_truncated: lit.state.signif_truncated,
-        })
+        }))
     }
 }
 
@@ -338,23 +409,23 @@ mod tests {
     #[test]
     fn parse_zero_digits_with_dot() {
         let s = "000.0000000000000000";
-        let r = FloatRepr::from_str(s);
-        assert_eq!(r, FloatRepr::Number(DecNumRepr::default()));
+        let r = FloatRepr::from_str(s, &ParseOptions::new());
+        assert_eq!(r, Ok(FloatRepr::Number(DecNumRepr::default())));
     }
 
     #[test]
     fn parse_digits_with_trailing_zeroes_before_dot() {
         let s = "180000.0000000000000000000000000000000000000000000000000\
             000000000000000000000000000000000000000000000000000000000000000000";
-        let r = FloatRepr::from_str(s);
+        let r = FloatRepr::from_str(s, &ParseOptions::new());
         assert_eq!(
             r,
-            FloatRepr::Number(DecNumRepr {
+            Ok(FloatRepr::Number(DecNumRepr {
                 sign: 0,
                 exponent: 4,
                 significand: U256::new(0, 18),
                 signif_truncated: false
-            })
+            }))
         );
     }
 
@@ -362,15 +433,15 @@ mod tests {
     fn parse_frac_only_with_trailing_zeroes() {
         let s = "-.000000000000000010000000000000000000000000000000000000\
             00000000000000000000000000000000000000000000000000000000000000000";
-        let r = FloatRepr::from_str(s);
+        let r = FloatRepr::from_str(s, &ParseOptions::new());
         assert_eq!(
             r,
-            FloatRepr::Number(DecNumRepr {
+            Ok(FloatRepr::Number(DecNumRepr {
                 sign: 1,
                 exponent: -17,
                 significand: U256::new(0, 1),
                 signif_truncated: false
-            })
+            }))
         );
     }
 
@@ -379,15 +450,15 @@ mod tests {
         let s = "-7.62939453125000000000000000000000000000000000000000000\
             0000000000000000000000000000000000000000000000000000000000000000000\
             00e-06";
-        let r = FloatRepr::from_str(s);
+        let r = FloatRepr::from_str(s, &ParseOptions::new());
         assert_eq!(
             r,
-            FloatRepr::Number(DecNumRepr {
+            Ok(FloatRepr::Number(DecNumRepr {
                 sign: 1,
                 significand: U256::new(0, 762939453125),
                 exponent: -17,
                 signif_truncated: false
-            })
+            }))
         );
     }
 
@@ -395,15 +466,15 @@ mod tests {
     fn parse_digits_with_more_than_77_int_digits() {
         let s = "-1000000000000000000000000000000000000000000000000000000\
             000000000000000000000000000000000000000000000000000000000000.0e-06";
-        let r = FloatRepr::from_str(s);
+        let r = FloatRepr::from_str(s, &ParseOptions::new());
         assert_eq!(
             r,
-            FloatRepr::Number(DecNumRepr {
+            Ok(FloatRepr::Number(DecNumRepr {
                 sign: 1,
                 significand: U256::new(0, 1),
                 exponent: 108,
                 signif_truncated: false
-            })
+            }))
         );
     }
 
@@ -411,10 +482,10 @@ mod tests {
     fn test_large_signif_large_neg_exp() {
         let s = "-441.249009748590979791323783709646682894752724672748600\
                 542581589000e-78928";
-        let r = FloatRepr::from_str(s);
+        let r = FloatRepr::from_str(s, &ParseOptions::new());
         assert_eq!(
             r,
-            FloatRepr::Number(DecNumRepr {
+            Ok(FloatRepr::Number(DecNumRepr {
                 sign: 1,
                 significand: U256::new(
                     1296714295663492914563767,
@@ -422,7 +493,7 @@ mod tests {
                 ),
                 exponent: -78988,
                 signif_truncated: false
-            })
+            }))
         );
     }
 }
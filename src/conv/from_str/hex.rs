@@ -0,0 +1,347 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use super::parse_error::{ParseErrorKind, ParseF256Error};
+use crate::{
+    f256, BigUInt, ExceptionFlags, EMAX, EMIN, FRACTION_BITS,
+    HI_FRACTION_BITS, U256,
+};
+
+/// Max number of hex digits fitting into an U256.
+const MAX_N_DIGITS: u32 = 64;
+
+// Exponents beyond this limit are saturated. This does not change the
+// result because the number of digits is limited by the length of the
+// literal.
+const MAX_ABS_EXP: i64 = 1 << 40;
+
+#[inline]
+const fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+// Parse the decimal exponent following the 'p' or 'P'. In case of an
+// invalid exponent, the position of the offending byte is returned.
+fn parse_bin_exponent(bytes: &[u8]) -> Result<i64, usize> {
+    let (exp_is_negative, start) = match bytes.first() {
+        Some(b'-') => (true, 1),
+        Some(b'+') => (false, 1),
+        _ => (false, 0),
+    };
+    // Need atleast one digit.
+    if start == bytes.len() {
+        return Err(start);
+    }
+    let mut exponent = 0_i64;
+    for (i, c) in bytes.iter().enumerate().skip(start) {
+        let d = c.wrapping_sub(b'0');
+        if d >= 10 {
+            return Err(i);
+        }
+        exponent = (exponent * 10 + d as i64).min(MAX_ABS_EXP);
+    }
+    Ok(if exp_is_negative { -exponent } else { exponent })
+}
+
+/// Round (-1)ˢ × (c + δ) × 2ᵗ, where 0 <= δ < 1 and δ > 0 if `sticky` is
+/// true, to the nearest f256, ties to even, adding the exception flags
+/// raised to `flags`.
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_possible_wrap)]
+#[allow(clippy::cast_sign_loss)]
+fn round_to_f256(
+    s: u32,
+    t: i64,
+    mut c: U256,
+    sticky: bool,
+    flags: &mut ExceptionFlags,
+) -> f256 {
+    if c.is_zero() {
+        return [f256::ZERO, f256::NEG_ZERO][s as usize];
+    }
+    let inf = [f256::INFINITY, f256::NEG_INFINITY][s as usize];
+    // Exponent of the most significant bit.
+    let e = t + c.msb() as i64;
+    if e > EMAX as i64 {
+        *flags |= ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT;
+        return inf;
+    }
+    // Quantum exponent of the result.
+    let mut q = e.max(EMIN as i64) - FRACTION_BITS as i64;
+    let shift = q - t;
+    if shift <= 0 {
+        // The value is exact, because sticky can only be true if there are
+        // more than SIGNIFICAND_BITS significant bits.
+        debug_assert!(!sticky);
+        return f256::encode(s, t as i32, c);
+    }
+    let (round_bit, sticky) = if shift > U256::BITS as i64 {
+        c = U256::ZERO;
+        (false, true)
+    } else {
+        let shift = shift as u32;
+        let rem = c.rem_pow2(shift);
+        c = if shift == U256::BITS {
+            U256::ZERO
+        } else {
+            c >> shift
+        };
+        (
+            !(rem >> (shift - 1)).is_zero(),
+            sticky || !rem.rem_pow2(shift - 1).is_zero(),
+        )
+    };
+    // The value is tiny if it is less than 2^Eₘᵢₙ before rounding.
+    flags.set_inexact(
+        u32::from(round_bit) << 1 | u32::from(sticky),
+        e < EMIN as i64,
+    );
+    if round_bit && (sticky || c.is_odd()) {
+        c.incr();
+        // Rounding may have caused the significand to overflow.
+        if (c.hi.0 >> (HI_FRACTION_BITS + 1)) != 0 {
+            c >>= 1;
+            q += 1;
+            if q + FRACTION_BITS as i64 > EMAX as i64 {
+                *flags |= ExceptionFlags::OVERFLOW;
+                return inf;
+            }
+        }
+    }
+    if c.is_zero() {
+        return [f256::ZERO, f256::NEG_ZERO][s as usize];
+    }
+    f256::encode(s, q as i32, c)
+}
+
+// Parse the hexadecimal significand and the optional binary exponent.
+// `offset` is the position of `bytes` in the literal, used for reporting
+// errors.
+#[allow(clippy::cast_possible_wrap)]
+fn parse_hex_number(
+    s: u32,
+    bytes: &[u8],
+    offset: usize,
+    flags: &mut ExceptionFlags,
+) -> Result<f256, ParseF256Error> {
+    let mut signif = U256::ZERO;
+    let mut n_digits = 0_u32;
+    let mut has_digits = false;
+    let mut sticky = false;
+    let mut exponent = 0_i64;
+    let mut after_radix_point = false;
+    let mut pos = 0_usize;
+    while let Some(c) = bytes.get(pos) {
+        if let Some(d) = hex_digit(*c) {
+            has_digits = true;
+            if n_digits < MAX_N_DIGITS {
+                if n_digits > 0 || d != 0 {
+                    signif <<= 4;
+                    signif += &(d as u128);
+                    n_digits += 1;
+                }
+                exponent -= 4 * after_radix_point as i64;
+            } else {
+                sticky |= d != 0;
+                exponent += 4 * !after_radix_point as i64;
+            }
+        } else if *c == b'.' && !after_radix_point {
+            after_radix_point = true;
+        } else {
+            break;
+        }
+        pos += 1;
+    }
+    let err = |kind, pos| ParseF256Error::new(kind, offset + pos);
+    match bytes.get(pos) {
+        None if !has_digits => {
+            return Err(err(ParseErrorKind::UnexpectedEnd, pos));
+        }
+        None => {}
+        Some(b'p' | b'P') if has_digits => {
+            exponent +=
+                parse_bin_exponent(&bytes[pos + 1..]).map_err(|i| {
+                    err(ParseErrorKind::InvalidExponent, pos + 1 + i)
+                })?;
+        }
+        Some(b'.') => {
+            return Err(err(ParseErrorKind::DoubleRadixPoint, pos));
+        }
+        _ => {
+            return Err(err(ParseErrorKind::InvalidChar, pos));
+        }
+    }
+    Ok(round_to_f256(s, exponent, signif, sticky, flags))
+}
+
+impl f256 {
+    /// Parse a hexadecimal floating point literal of the form
+    /// `[+-][0x]h[.h][p[+-]d]` (or one of the special values 'inf' /
+    /// 'infinity' and 'nan'), rounding to the nearest representable value,
+    /// ties to even. The prefix `0x` (or `0X`) is mandatory if `prefixed`
+    /// is true and not allowed otherwise.
+    #[inline]
+    pub(crate) fn from_hex_str(
+        lit: &str,
+        prefixed: bool,
+    ) -> Result<Self, ParseF256Error> {
+        Self::from_hex_str_with_flags(
+            lit,
+            prefixed,
+            &mut ExceptionFlags::default(),
+        )
+    }
+
+    /// Same as `from_hex_str`, but adds the exception flags raised by the
+    /// conversion to `flags`.
+    pub(crate) fn from_hex_str_with_flags(
+        lit: &str,
+        prefixed: bool,
+        flags: &mut ExceptionFlags,
+    ) -> Result<Self, ParseF256Error> {
+        let mut bytes = lit.as_bytes();
+        if bytes.is_empty() {
+            return Err(ParseF256Error::new(ParseErrorKind::Empty, 0));
+        }
+        let s = match bytes[0] {
+            b'-' => 1,
+            _ => 0,
+        };
+        if matches!(bytes[0], b'-' | b'+') {
+            bytes = &bytes[1..];
+        }
+        if bytes.eq_ignore_ascii_case(b"nan") {
+            return Ok(Self::NAN);
+        }
+        if bytes.eq_ignore_ascii_case(b"inf")
+            || bytes.eq_ignore_ascii_case(b"infinity")
+        {
+            return Ok([Self::INFINITY, Self::NEG_INFINITY][s as usize]);
+        }
+        if prefixed {
+            match bytes {
+                [b'0', b'x' | b'X', rest @ ..] => bytes = rest,
+                _ => {
+                    return Err(ParseF256Error::new(
+                        ParseErrorKind::InvalidChar,
+                        lit.len() - bytes.len(),
+                    ))
+                }
+            }
+        }
+        parse_hex_number(s, bytes, lit.len() - bytes.len(), flags)
+    }
+}
+
+#[cfg(test)]
+mod from_hex_str_tests {
+    use alloc::{format, string::ToString};
+    use core::str::FromStr;
+
+    use super::*;
+
+    fn parse(lit: &str) -> f256 {
+        f256::from_hex_str(lit, true).unwrap()
+    }
+
+    #[test]
+    fn test_simple() {
+        assert_eq!(parse("0x1.8p+3"), f256::from(12));
+        assert_eq!(parse("-0x1.8p3"), f256::from(-12));
+        assert_eq!(parse("0X18"), f256::from(24));
+        assert_eq!(parse("+0xa.Bp-4"), f256::from(0.66796875));
+        assert_eq!(parse("0x.8"), f256::from(0.5));
+        assert_eq!(parse("0x1."), f256::ONE);
+        assert_eq!(parse("0x0001000p-12"), f256::ONE);
+        assert_eq!(
+            f256::from_hex_str("1.8p1", false).unwrap(),
+            f256::from(3)
+        );
+    }
+
+    #[test]
+    fn test_special() {
+        assert_eq!(parse("0x0p+0").to_bits(), f256::ZERO.to_bits());
+        assert_eq!(parse("-0x0.000p7").to_bits(), f256::NEG_ZERO.to_bits());
+        assert_eq!(parse("-inf"), f256::NEG_INFINITY);
+        assert_eq!(parse("Infinity"), f256::INFINITY);
+        assert!(parse("NaN").is_nan());
+    }
+
+    #[test]
+    fn test_limits() {
+        let s = format!("0x1.{}p+262143", "f".repeat(59));
+        assert_eq!(parse(&s), f256::MAX);
+        assert_eq!(parse("0x1p-262378"), f256::MIN_GT_ZERO);
+        assert_eq!(parse("-0x1p-262142"), -f256::MIN_POSITIVE);
+        assert_eq!(parse("0x1p+262144"), f256::INFINITY);
+        assert_eq!(parse("-0x1p+99999999999999"), f256::NEG_INFINITY);
+        assert_eq!(parse("0x1p-262380"), f256::ZERO);
+        assert_eq!(parse("-0x1p-99999999999999").to_bits(), (1 << 127, 0));
+    }
+
+    #[test]
+    fn test_rounding() {
+        // 2⁻²³⁷ is exactly half an ulp of 1.
+        let zeroes = "0".repeat(58);
+        let s = format!("0x1.{zeroes}08");
+        assert_eq!(parse(&s), f256::ONE);
+        let s = format!("0x1.{zeroes}08{zeroes}1");
+        assert_eq!(parse(&s), f256::ONE + f256::EPSILON);
+        let s = format!("0x1.{zeroes}18");
+        assert_eq!(parse(&s), f256::ONE + f256::EPSILON * f256::TWO);
+        // Rounding up to the next power of two.
+        let s = format!("0x1.{}cp+0", "f".repeat(59));
+        assert_eq!(parse(&s), f256::TWO);
+        let s = format!("0x1.{}8p+262143", "f".repeat(59));
+        assert_eq!(parse(&s), f256::INFINITY);
+        // Subnormal results.
+        assert_eq!(parse("0x1.8p-262378"), f256::MIN_GT_ZERO * f256::TWO);
+        assert_eq!(parse("0x1p-262379"), f256::ZERO);
+        assert_eq!(parse("0x1.000001p-262379"), f256::MIN_GT_ZERO);
+        let s = format!("0x0.{}8p-262142", "f".repeat(59));
+        assert_eq!(parse(&s), f256::MIN_POSITIVE);
+    }
+
+    #[test]
+    fn test_many_digits() {
+        let s = "0x123456789abcdef0123456789abcdef0123456789abcdef0123456789\
+                 abcdef0123456789abcdef.0p-8";
+        let f = parse(s);
+        assert_eq!(
+            f.as_sign_exp_signif(),
+            (
+                0,
+                68,
+                (
+                    0x123456789abcdef0123456789abc,
+                    0xdef0123456789abcdef0123456789abd
+                )
+            )
+        );
+        assert_eq!(f256::from_str(s).unwrap(), f);
+    }
+
+    #[test]
+    fn test_err() {
+        let lits = [
+            "", "0x", "-0x", "0x.", "0xp3", "0x1p", "0x1p+", "0x1.2.3",
+            "0x1g", "0x1p3.5", "1.8p3", "0x 1", "0x1 ", "+", "0x1e3p",
+        ];
+        for lit in lits {
+            assert!(f256::from_hex_str(lit, true).is_err(), "{lit}");
+        }
+        assert!(f256::from_hex_str("0x1p3", false).is_err());
+    }
+}
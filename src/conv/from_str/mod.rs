@@ -11,6 +11,10 @@ mod common;
 mod fast_approx;
 mod fast_exact;
 mod float_repr;
+mod hex;
+mod options;
+mod parse_error;
+mod partial;
 mod powers_of_five;
 mod slow_exact;
 
@@ -18,29 +22,23 @@ use core::{convert::TryFrom, num::ParseFloatError, str::FromStr};
 
 use fast_exact::fast_exact;
 use float_repr::FloatRepr;
-use slow_exact::f256_exact;
+pub use options::ParseOptions;
+pub use parse_error::{ParseErrorKind, ParseF256Error};
+use slow_exact::{f256_exact, f256_exact_with_flags};
 
 use super::big_decimal::{Decimal, MAX_DIGITS};
-use crate::{f256, HI_FRACTION_BIAS, MIN_GT_ZERO_10_EXP, U256};
+use crate::{
+    f256, ExceptionFlags, HI_FRACTION_BIAS, MIN_GT_ZERO_10_EXP, U256,
+};
 
 /// Minimum possible subnormal power of 10 exponent - adjustment of
 /// significand: ⌊(Eₘᵢₙ + 1 - p) × log₁₀(2)⌋ - ⌈p × log₁₀(2)⌉.
 const MIN_10_EXP_CUTOFF: i32 = -79056;
 
-// The internals of ParseFloatError are not public. The following hack is used
-// to return the same errors as f64.
-#[inline]
-fn parse_float_error(empty: bool) -> ParseFloatError {
-    if empty {
-        f64::from_str("").unwrap_err()
-    } else {
-        f64::from_str("_").unwrap_err()
-    }
-}
-
 #[inline]
 fn calc_normal_f256(
     lit: &str,
+    opts: &ParseOptions,
     sign: u32,
     exp10: i32,
     signif10: U256,
@@ -49,16 +47,129 @@ fn calc_normal_f256(
     // The transformation of the decimal representation is implemented as a
     // sequence of faster to slower algorithms, chained together by tail
     // calls.
-    fast_exact(lit, sign, exp10, signif10, signif_truncated)
+    fast_exact(lit, opts, sign, exp10, signif10, signif_truncated)
 }
 
-impl FromStr for f256 {
-    type Err = ParseFloatError;
+impl f256 {
+    /// Parses a decimal literal of the form `[+-]d[.d][(e|E)[+-]d]`, a
+    /// hexadecimal literal of the form `[+-](0x|0X)h[.h][(p|P)[+-]d]` or
+    /// one of the special values `inf`, `infinity` and `nan` (case is
+    /// ignored), rounding to the nearest representable value, ties to even.
+    ///
+    /// This is the same as `f256::from_str`, but returns a detailed error.
+    /// Use [`ParseOptions`] to parse literals with a different syntax.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseF256Error`] giving the kind of the error and the
+    /// byte offset in `lit` where it was detected, if `lit` is not a valid
+    /// literal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, ParseErrorKind};
+    /// assert_eq!(f256::parse("-1.5e3"), Ok(f256::from(-1500)));
+    /// let err = f256::parse("1.5.3").unwrap_err();
+    /// assert_eq!(err.kind(), ParseErrorKind::DoubleRadixPoint);
+    /// assert_eq!(err.position(), 3);
+    /// let err = f256::parse("7.5e+x").unwrap_err();
+    /// assert_eq!(err.kind(), ParseErrorKind::InvalidExponent);
+    /// assert_eq!(err.position(), 5);
+    /// let err = f256::parse("25 ").unwrap_err();
+    /// assert_eq!(err.kind(), ParseErrorKind::InvalidChar);
+    /// assert_eq!(err.position(), 2);
+    /// ```
+    #[inline]
+    pub fn parse(lit: &str) -> Result<Self, ParseF256Error> {
+        Self::parse_with_options(lit, &ParseOptions::new())
+    }
 
-    fn from_str(lit: &str) -> Result<Self, Self::Err> {
-        match FloatRepr::from_str(lit) {
-            FloatRepr::Empty => Err(parse_float_error(true)),
-            FloatRepr::Invalid => Err(parse_float_error(false)),
+    /// Parses a literal like [`f256::parse`], returning the result together
+    /// with the exception flags raised by the conversion.
+    ///
+    /// `INEXACT` is signaled if the value of `lit` is not exactly
+    /// representable as `f256`, `OVERFLOW` if it is rounded to ±∞ and
+    /// `UNDERFLOW` if it is tiny and inexact. Parsing `inf` or `nan` does
+    /// not signal any exception.
+    ///
+    /// Because deciding whether a decimal literal is exactly representable
+    /// requires the exact conversion algorithm, this is slower than
+    /// [`f256::parse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseF256Error`] giving the kind of the error and the
+    /// byte offset in `lit` where it was detected, if `lit` is not a valid
+    /// literal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, ExceptionFlags};
+    /// let (f, flags) = f256::parse_with_flags("-1.5e3").unwrap();
+    /// assert_eq!(f, f256::from(-1500));
+    /// assert!(flags.is_empty());
+    /// let (f, flags) = f256::parse_with_flags("0.1").unwrap();
+    /// assert_eq!(flags, ExceptionFlags::INEXACT);
+    /// let (f, flags) = f256::parse_with_flags("1e80000").unwrap();
+    /// assert_eq!(f, f256::INFINITY);
+    /// assert_eq!(flags, ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT);
+    /// let (f, flags) = f256::parse_with_flags("-1e-80000").unwrap();
+    /// assert_eq!(f, f256::NEG_ZERO);
+    /// assert_eq!(flags, ExceptionFlags::UNDERFLOW | ExceptionFlags::INEXACT);
+    /// ```
+    pub fn parse_with_flags(
+        lit: &str,
+    ) -> Result<(Self, ExceptionFlags), ParseF256Error> {
+        let opts = ParseOptions::new();
+        let mut flags = ExceptionFlags::default();
+        let unsigned = lit.strip_prefix(['+', '-']).unwrap_or(lit);
+        if opts.allows_hex_literals()
+            && (unsigned.starts_with("0x") || unsigned.starts_with("0X"))
+        {
+            let f = Self::from_hex_str_with_flags(lit, true, &mut flags)?;
+            return Ok((f, flags));
+        }
+        let f = match FloatRepr::from_str(lit, &opts)? {
+            FloatRepr::Nan => Self::NAN,
+            FloatRepr::Inf(sign) => {
+                [Self::INFINITY, Self::NEG_INFINITY][sign as usize]
+            }
+            FloatRepr::Number(repr) => {
+                let sign = repr.sign as usize;
+                if repr.significand.is_zero() {
+                    [Self::ZERO, Self::NEG_ZERO][sign]
+                } else if repr.exponent < MIN_10_EXP_CUTOFF {
+                    flags |=
+                        ExceptionFlags::UNDERFLOW | ExceptionFlags::INEXACT;
+                    [Self::ZERO, Self::NEG_ZERO][sign]
+                } else if repr.exponent > Self::MAX_10_EXP {
+                    flags |=
+                        ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT;
+                    [Self::INFINITY, Self::NEG_INFINITY][sign]
+                } else {
+                    f256_exact_with_flags(lit, &opts, &mut flags)
+                }
+            }
+        };
+        Ok((f, flags))
+    }
+
+    // Parses `lit` after the checks done in `ParseOptions::parse`.
+    fn parse_with_options(
+        lit: &str,
+        opts: &ParseOptions,
+    ) -> Result<Self, ParseF256Error> {
+        // Hexadecimal literals are identified by the prefix "0x" or "0X"
+        // (after an optional sign).
+        let unsigned = lit.strip_prefix(['+', '-']).unwrap_or(lit);
+        if opts.allows_hex_literals()
+            && (unsigned.starts_with("0x") || unsigned.starts_with("0X"))
+        {
+            return Self::from_hex_str(lit, true);
+        }
+        match FloatRepr::from_str(lit, opts)? {
             FloatRepr::Nan => Ok(Self::NAN),
             FloatRepr::Inf(sign) => {
                 Ok([Self::INFINITY, Self::NEG_INFINITY][sign as usize])
@@ -91,7 +202,7 @@ impl FromStr for f256 {
                 // => e < Eₘᵢₙ
                 if exp10 < MIN_GT_ZERO_10_EXP {
                     // Subnormals are not handled by the fast algorithms.
-                    return Ok(f256_exact(lit));
+                    return Ok(f256_exact(lit, opts));
                 }
                 // k > ⌊(Eₘₐₓ + 1) × log₁₀(2)⌋ => e > Eₘₐₓ
                 if exp10 > Self::MAX_10_EXP {
@@ -101,6 +212,7 @@ impl FromStr for f256 {
                 }
                 Ok(calc_normal_f256(
                     lit,
+                    opts,
                     sign,
                     exp10,
                     signif10,
@@ -111,6 +223,15 @@ impl FromStr for f256 {
     }
 }
 
+impl FromStr for f256 {
+    type Err = ParseFloatError;
+
+    #[inline]
+    fn from_str(lit: &str) -> Result<Self, Self::Err> {
+        Self::parse(lit).map_err(ParseF256Error::to_parse_float_error)
+    }
+}
+
 impl TryFrom<&str> for f256 {
     type Error = ParseFloatError;
 
@@ -127,20 +248,29 @@ mod cmp_algos_tests {
 
     #[allow(clippy::print_stdout)]
     fn cmp_algos(lit: &str) -> bool {
-        if let FloatRepr::Number(repr) = FloatRepr::from_str(lit) {
+        let opts = ParseOptions::new();
+        if let Ok(FloatRepr::Number(repr)) = FloatRepr::from_str(lit, &opts) {
             let sign = repr.sign;
             let exp10 = repr.exponent;
             let signif10 = repr.significand;
             let signif_truncated = repr.signif_truncated;
-            let fe = fast_exact(lit, sign, exp10, signif10, signif_truncated);
+            let fe = fast_exact(
+                lit,
+                &opts,
+                sign,
+                exp10,
+                signif10,
+                signif_truncated,
+            );
             let fa = fast_approx::fast_approx(
                 lit,
+                &opts,
                 sign,
                 exp10,
                 signif10,
                 signif_truncated,
             );
-            let fs = f256_exact(lit);
+            let fs = f256_exact(lit, &opts);
             if fe != fa || fa != fs {
                 println!("> {}", lit);
                 println!("> {:?}", fe.decode());
@@ -470,4 +600,48 @@ mod from_str_tests {
             assert!(res.is_err());
         }
     }
+
+    #[test]
+    fn test_err_kind_and_pos() {
+        let cases = [
+            ("", ParseErrorKind::Empty, 0),
+            ("-", ParseErrorKind::UnexpectedEnd, 1),
+            (" 1", ParseErrorKind::InvalidChar, 0),
+            ("2.87 e3", ParseErrorKind::InvalidChar, 4),
+            ("+e3", ParseErrorKind::InvalidChar, 1),
+            ("-4.33.2", ParseErrorKind::DoubleRadixPoint, 5),
+            ("0.00.1", ParseErrorKind::DoubleRadixPoint, 4),
+            ("12345678.12.5", ParseErrorKind::DoubleRadixPoint, 11),
+            ("1234567.1234567.", ParseErrorKind::DoubleRadixPoint, 15),
+            ("1.5e", ParseErrorKind::InvalidExponent, 4),
+            ("1.5e-", ParseErrorKind::InvalidExponent, 5),
+            ("1.5E+x", ParseErrorKind::InvalidExponent, 5),
+            ("1.5e7.", ParseErrorKind::InvalidChar, 5),
+            ("-in", ParseErrorKind::UnexpectedEnd, 3),
+            ("infinit", ParseErrorKind::UnexpectedEnd, 7),
+            ("-Infinityy", ParseErrorKind::InvalidChar, 9),
+            ("nax", ParseErrorKind::InvalidChar, 2),
+            ("0x1.8p+", ParseErrorKind::InvalidExponent, 7),
+            ("-0x1.8.", ParseErrorKind::DoubleRadixPoint, 6),
+            ("0xg", ParseErrorKind::InvalidChar, 2),
+            ("0x", ParseErrorKind::UnexpectedEnd, 2),
+        ];
+        for (lit, kind, pos) in cases {
+            let err = f256::parse(lit).unwrap_err();
+            assert_eq!((err.kind(), err.position()), (kind, pos), "{lit}");
+            assert_eq!(
+                f256::from_str(lit).unwrap_err(),
+                f64::from_str(if lit.is_empty() { "" } else { "_" })
+                    .unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_err_display() {
+        let err = f256::parse("1.5.3").unwrap_err();
+        assert_eq!(err.to_string(), "second radix point at byte 3");
+        let err = f256::parse("").unwrap_err();
+        assert_eq!(err.to_string(), "cannot parse f256 from empty string");
+    }
 }
@@ -0,0 +1,416 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use super::parse_error::{ParseErrorKind, ParseF256Error};
+use crate::f256;
+
+/// Options selecting the syntax of literals accepted by
+/// [`ParseOptions::parse`].
+///
+/// The default options accept the same literals as [`f256::parse`]:
+///
+/// * exponents are marked by `e` or `E`,
+/// * there are no digit separators,
+/// * the special values `inf`, `infinity` and `nan` are accepted,
+/// * hexadecimal literals like `0x1.8p3` are accepted,
+/// * a leading `+` is accepted,
+/// * leading or trailing whitespace is rejected.
+///
+/// # Examples
+///
+/// ```
+/// # use ::f256::{f256, ParseOptions};
+/// // Fortran
+/// let fortran = ParseOptions::new().exponent_chars("eEdD");
+/// assert_eq!(fortran.parse("1.5D+03"), Ok(f256::from(1500)));
+/// // Spreadsheet
+/// let spreadsheet = ParseOptions::new().digit_separator(b'\'');
+/// assert_eq!(spreadsheet.parse("1'000.25"), Ok(f256::from(1000.25)));
+/// // JSON
+/// let json = ParseOptions::new()
+///     .special_values(false)
+///     .hex_literals(false)
+///     .leading_plus(false);
+/// assert!(json.parse("inf").is_err());
+/// assert!(json.parse("0x10").is_err());
+/// assert!(json.parse("+1").is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseOptions {
+    // Bit i is set if the ASCII char i marks an exponent.
+    exp_marks: u128,
+    separator: Option<u8>,
+    special_values: bool,
+    alt_infinity: Option<&'static str>,
+    hex_literals: bool,
+    leading_plus: bool,
+    whitespace: bool,
+}
+
+impl Default for ParseOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParseOptions {
+    /// Returns the default options.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            exp_marks: (1 << b'e') | (1 << b'E'),
+            separator: None,
+            special_values: true,
+            alt_infinity: None,
+            hex_literals: true,
+            leading_plus: true,
+            whitespace: false,
+        }
+    }
+
+    /// Sets the chars marking an exponent in a decimal literal (default:
+    /// `"eE"`). Only ASCII letters are taken into account; case is **not**
+    /// ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chars` contains the digit separator.
+    #[must_use]
+    pub const fn exponent_chars(mut self, chars: &str) -> Self {
+        let chars = chars.as_bytes();
+        self.exp_marks = 0;
+        let mut i = 0;
+        while i < chars.len() {
+            if chars[i].is_ascii_alphabetic() {
+                self.exp_marks |= 1 << chars[i];
+            }
+            i += 1;
+        }
+        if let Some(sep) = self.separator {
+            assert!(
+                !self.is_exp_mark(sep),
+                "Exponent char must not be the digit separator."
+            );
+        }
+        self
+    }
+
+    /// Sets a char which may be used to group the digits of the significand
+    /// of a decimal literal, e.g. `b'_'` to accept `1_000.25` (default:
+    /// none). A separator must be preceded and followed by a digit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sep` is not an ASCII char, or if it is a digit, the radix
+    /// point, a sign or an exponent char.
+    #[must_use]
+    pub const fn digit_separator(mut self, sep: u8) -> Self {
+        assert!(
+            sep.is_ascii()
+                && !sep.is_ascii_digit()
+                && !matches!(sep, b'.' | b'+' | b'-')
+                && !self.is_exp_mark(sep),
+            "Invalid digit separator."
+        );
+        self.separator = Some(sep);
+        self
+    }
+
+    /// Sets whether the special values `inf`, `infinity` and `nan` (and an
+    /// alternate spelling of infinity, if given) are accepted (default:
+    /// `true`).
+    #[must_use]
+    pub const fn special_values(mut self, allow: bool) -> Self {
+        self.special_values = allow;
+        self
+    }
+
+    /// Sets an alternate spelling of infinity, accepted in addition to `inf`
+    /// and `infinity`, e.g. `"∞"` (default: none). As with the other special
+    /// values, it may be preceded by a sign and ASCII case is ignored.
+    #[must_use]
+    pub const fn alt_infinity(mut self, spelling: &'static str) -> Self {
+        self.alt_infinity = Some(spelling);
+        self
+    }
+
+    /// Sets whether hexadecimal literals of the form
+    /// `[+-](0x|0X)h[.h][(p|P)[+-]d]` are accepted (default: `true`).
+    #[must_use]
+    pub const fn hex_literals(mut self, allow: bool) -> Self {
+        self.hex_literals = allow;
+        self
+    }
+
+    /// Sets whether a leading `+` is accepted (default: `true`).
+    #[must_use]
+    pub const fn leading_plus(mut self, allow: bool) -> Self {
+        self.leading_plus = allow;
+        self
+    }
+
+    /// Sets whether leading and trailing ASCII whitespace is accepted
+    /// (default: `false`).
+    #[must_use]
+    pub const fn whitespace(mut self, allow: bool) -> Self {
+        self.whitespace = allow;
+        self
+    }
+
+    #[inline(always)]
+    pub(super) const fn is_exp_mark(&self, c: u8) -> bool {
+        c < 128 && self.exp_marks & (1 << c) != 0
+    }
+
+    #[inline(always)]
+    pub(super) const fn separator(&self) -> Option<u8> {
+        self.separator
+    }
+
+    #[inline(always)]
+    pub(super) const fn allows_special_values(&self) -> bool {
+        self.special_values
+    }
+
+    #[inline(always)]
+    pub(super) const fn allows_hex_literals(&self) -> bool {
+        self.hex_literals
+    }
+
+    #[inline(always)]
+    pub(super) const fn alt_infinity_spelling(&self) -> Option<&str> {
+        self.alt_infinity
+    }
+
+    /// Parses `lit` according to the options, rounding to the nearest
+    /// representable value, ties to even.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseF256Error`] giving the kind of the error and the
+    /// byte offset in `lit` where it was detected, if `lit` is not a valid
+    /// literal.
+    pub fn parse(&self, lit: &str) -> Result<f256, ParseF256Error> {
+        let (trimmed, offset) = if self.whitespace {
+            let start = lit.trim_ascii_start();
+            (start.trim_ascii_end(), lit.len() - start.len())
+        } else {
+            (lit, 0)
+        };
+        self.check(trimmed)
+            .and_then(|()| f256::parse_with_options(trimmed, self))
+            .map_err(|err| err.shifted(offset))
+    }
+
+    // Checks the leading sign and the placement of digit separators.
+    fn check(&self, lit: &str) -> Result<(), ParseF256Error> {
+        let bytes = lit.as_bytes();
+        if !self.leading_plus && bytes.first() == Some(&b'+') {
+            return Err(ParseF256Error::new(ParseErrorKind::InvalidChar, 0));
+        }
+        if let Some(sep) = self.separator {
+            let is_digit_at =
+                |i: usize| bytes.get(i).is_some_and(u8::is_ascii_digit);
+            for (i, _) in bytes.iter().enumerate().filter(|(_, c)| **c == sep)
+            {
+                if i == 0 || !is_digit_at(i - 1) || !is_digit_at(i + 1) {
+                    return Err(ParseF256Error::new(
+                        ParseErrorKind::InvalidChar,
+                        i,
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod parse_options_tests {
+    use alloc::string::ToString;
+    use core::str::FromStr;
+
+    use super::*;
+
+    fn assert_err(
+        opts: &ParseOptions,
+        lit: &str,
+        kind: ParseErrorKind,
+        pos: usize,
+    ) {
+        let err = opts.parse(lit).unwrap_err();
+        assert_eq!((err.kind(), err.position()), (kind, pos), "{lit}");
+    }
+
+    #[test]
+    fn test_default() {
+        let opts = ParseOptions::default();
+        for lit in ["0", "-1.5e3", "+7E-2", ".5", "inf", "-NaN", "0x1p-3"] {
+            let f = opts.parse(lit).unwrap();
+            let g = f256::from_str(lit).unwrap();
+            assert!(f == g || (f.is_nan() && g.is_nan()), "{lit}");
+        }
+        assert_err(&opts, "1d3", ParseErrorKind::InvalidChar, 1);
+        assert_err(&opts, "1_000", ParseErrorKind::InvalidChar, 1);
+        assert_err(&opts, " 1", ParseErrorKind::InvalidChar, 0);
+    }
+
+    #[test]
+    fn test_exponent_chars() {
+        let opts = ParseOptions::new().exponent_chars("eEdD");
+        assert_eq!(opts.parse("1.5D+03"), Ok(f256::from(1500)));
+        assert_eq!(opts.parse("-25d-1"), Ok(f256::from(-2.5)));
+        assert_eq!(opts.parse("2e2"), Ok(f256::from(200)));
+        let opts = ParseOptions::new().exponent_chars("D");
+        assert_eq!(opts.parse("1.5D3"), Ok(f256::from(1500)));
+        assert_err(&opts, "1.5d3", ParseErrorKind::InvalidChar, 3);
+        assert_err(&opts, "1.5e3", ParseErrorKind::InvalidChar, 3);
+        assert_err(&opts, "1.5D", ParseErrorKind::InvalidExponent, 4);
+        // Long significands take the slow path.
+        let lit = "1.00000000000000000000000000000000000000000000000000000\
+                   00000000000000000000000000000000000000000001D-78950";
+        let f = opts.parse(lit).unwrap();
+        assert!(f.is_subnormal());
+        assert_eq!(f, f256::from_str(&lit.replace('D', "e")).unwrap());
+    }
+
+    #[test]
+    fn test_digit_separator() {
+        for sep in [b'_', b'\''] {
+            let opts = ParseOptions::new().digit_separator(sep);
+            let s = sep as char;
+            for lit in [
+                "1_000.25",
+                "-1_000_000",
+                "0_000.000_1",
+                "12_345_678_901_234_567.890_123_456_789_012_345_6e-3",
+                "1_2345678_9.1234567_8",
+                "1234567.1_2345678",
+                "0.0000000_1",
+                "1_000e-10",
+            ] {
+                let lit = lit.replace('_', &s.to_string());
+                let f = opts.parse(&lit).unwrap();
+                let g = f256::from_str(&lit.replace(s, "")).unwrap();
+                assert_eq!(f, g, "{lit}");
+            }
+        }
+        let opts = ParseOptions::new().digit_separator(b'_');
+        // More than 77 significant digits
+        let lit =
+            "1_000_000_000_000_000_000_000_000_000_000_000_000_000_000_\
+                   000_000_000_000_000_000_000_000_000_000_000_000_000_007.5";
+        let f = opts.parse(lit).unwrap();
+        assert_eq!(f, f256::from_str(&lit.replace('_', "")).unwrap());
+        assert_err(&opts, "_1", ParseErrorKind::InvalidChar, 0);
+        assert_err(&opts, "1_", ParseErrorKind::InvalidChar, 1);
+        assert_err(&opts, "1__0", ParseErrorKind::InvalidChar, 1);
+        assert_err(&opts, "1_.5", ParseErrorKind::InvalidChar, 1);
+        assert_err(&opts, "1._5", ParseErrorKind::InvalidChar, 2);
+        assert_err(&opts, "-_1", ParseErrorKind::InvalidChar, 1);
+        assert_err(&opts, "1e_5", ParseErrorKind::InvalidChar, 2);
+        assert_err(&opts, "1_0.5.5", ParseErrorKind::DoubleRadixPoint, 5);
+        assert_err(&opts, "1_0x", ParseErrorKind::InvalidChar, 3);
+        assert_err(&opts, "1_0e5_0", ParseErrorKind::InvalidChar, 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_digit_separator_radix_point() {
+        let _ = ParseOptions::new().digit_separator(b'.');
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_digit_separator_digit() {
+        let _ = ParseOptions::new().digit_separator(b'0');
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_digit_separator_sign() {
+        let _ = ParseOptions::new().digit_separator(b'-');
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_digit_separator_exponent_char() {
+        let _ = ParseOptions::new().digit_separator(b'e');
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_exponent_char_digit_separator() {
+        let _ = ParseOptions::new()
+            .digit_separator(b'd')
+            .exponent_chars("eEdD");
+    }
+
+    #[test]
+    fn test_special_values() {
+        let opts = ParseOptions::new().special_values(false);
+        assert_err(&opts, "inf", ParseErrorKind::InvalidChar, 0);
+        assert_err(&opts, "-Infinity", ParseErrorKind::InvalidChar, 1);
+        assert_err(&opts, "NaN", ParseErrorKind::InvalidChar, 0);
+        // Overflow is not rejected.
+        assert_eq!(opts.parse("1e100000"), Ok(f256::INFINITY));
+        let opts = ParseOptions::new().alt_infinity("∞");
+        assert_eq!(opts.parse("∞"), Ok(f256::INFINITY));
+        assert_eq!(opts.parse("-∞"), Ok(f256::NEG_INFINITY));
+        assert_eq!(opts.parse("-inf"), Ok(f256::NEG_INFINITY));
+        let opts = ParseOptions::new().alt_infinity("1.#INF");
+        assert_err(&opts, "1.#INF", ParseErrorKind::InvalidChar, 2);
+        let opts = ParseOptions::new().alt_infinity("Unendlich");
+        assert_eq!(opts.parse("+unendlich"), Ok(f256::INFINITY));
+        assert_err(&opts, "Unend", ParseErrorKind::UnexpectedEnd, 5);
+        let opts = opts.special_values(false);
+        assert_err(&opts, "Unendlich", ParseErrorKind::InvalidChar, 0);
+    }
+
+    #[test]
+    fn test_leading_plus() {
+        let opts = ParseOptions::new().leading_plus(false);
+        assert_eq!(opts.parse("-1"), Ok(f256::NEG_ONE));
+        assert_err(&opts, "+1", ParseErrorKind::InvalidChar, 0);
+        assert_err(&opts, "+0x1", ParseErrorKind::InvalidChar, 0);
+        assert_err(&opts, "1e+", ParseErrorKind::InvalidExponent, 3);
+        assert_eq!(opts.parse("1e+1"), Ok(f256::TEN));
+    }
+
+    #[test]
+    fn test_whitespace() {
+        let opts = ParseOptions::new().whitespace(true);
+        assert_eq!(opts.parse(" \t1.5\r\n"), Ok(f256::from(1.5)));
+        assert_eq!(opts.parse("  -inf "), Ok(f256::NEG_INFINITY));
+        assert_eq!(opts.parse(" 0x10 "), Ok(f256::from(16)));
+        let opts = opts.hex_literals(false);
+        assert_err(&opts, " 0x10 ", ParseErrorKind::InvalidChar, 2);
+        assert_err(&opts, "  ", ParseErrorKind::Empty, 2);
+        assert_err(&opts, " 1 2 ", ParseErrorKind::InvalidChar, 2);
+        assert_err(&opts, "  1..2", ParseErrorKind::DoubleRadixPoint, 4);
+        let opts = opts.leading_plus(false).digit_separator(b' ');
+        assert_eq!(opts.parse(" 1 000 "), Ok(f256::from(1000)));
+        assert_err(&opts, "  +1", ParseErrorKind::InvalidChar, 2);
+    }
+
+    #[test]
+    fn test_json() {
+        let json = ParseOptions::new()
+            .special_values(false)
+            .hex_literals(false)
+            .leading_plus(false);
+        assert_eq!(json.parse("-2.5e-1"), Ok(f256::from(-0.25)));
+        assert_eq!(json.parse("0"), Ok(f256::ZERO));
+        assert_err(&json, "nan", ParseErrorKind::InvalidChar, 0);
+        assert_err(&json, "+2", ParseErrorKind::InvalidChar, 0);
+        assert_err(&json, "0x10", ParseErrorKind::InvalidChar, 1);
+        assert_err(&json, "-0X1p3", ParseErrorKind::InvalidChar, 2);
+    }
+}
@@ -0,0 +1,95 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use core::{fmt, num::ParseFloatError, str::FromStr};
+
+/// Kind of the error which occurred when parsing an `f256` literal.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The literal is empty.
+    Empty,
+    /// The literal ended unexpectedly, e.g. after a sign.
+    UnexpectedEnd,
+    /// The literal contains a char which is not allowed at its position.
+    InvalidChar,
+    /// The literal contains more than one radix point.
+    DoubleRadixPoint,
+    /// The exponent has no digits or contains an invalid char.
+    InvalidExponent,
+}
+
+/// Error returned by [`f256::parse`](crate::f256::parse) and
+/// [`ParseOptions::parse`](crate::ParseOptions::parse), giving the kind of
+/// the error and the byte offset in the literal where it was detected.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ParseF256Error {
+    kind: ParseErrorKind,
+    pos: usize,
+}
+
+impl ParseF256Error {
+    #[inline(always)]
+    pub(crate) const fn new(kind: ParseErrorKind, pos: usize) -> Self {
+        Self { kind, pos }
+    }
+
+    // Shifts the position by `n` bytes.
+    #[inline(always)]
+    pub(crate) const fn shifted(self, n: usize) -> Self {
+        Self {
+            kind: self.kind,
+            pos: self.pos + n,
+        }
+    }
+
+    /// Returns the kind of the error.
+    #[must_use]
+    #[inline(always)]
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset in the literal where the error was detected.
+    ///
+    /// For [`ParseErrorKind::UnexpectedEnd`] and an exponent without digits
+    /// this is the length of the literal.
+    #[must_use]
+    #[inline(always)]
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+
+    // The internals of ParseFloatError are not public. The following hack
+    // is used to return the same errors as f64.
+    pub(crate) fn to_parse_float_error(self) -> ParseFloatError {
+        if self.kind == ParseErrorKind::Empty {
+            f64::from_str("").unwrap_err()
+        } else {
+            f64::from_str("_").unwrap_err()
+        }
+    }
+}
+
+impl fmt::Display for ParseF256Error {
+    fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self.kind {
+            ParseErrorKind::Empty => {
+                return form.write_str("cannot parse f256 from empty string");
+            }
+            ParseErrorKind::UnexpectedEnd => "unexpected end of literal",
+            ParseErrorKind::InvalidChar => "invalid char",
+            ParseErrorKind::DoubleRadixPoint => "second radix point",
+            ParseErrorKind::InvalidExponent => "invalid exponent",
+        };
+        write!(form, "{msg} at byte {}", self.pos)
+    }
+}
+
+impl core::error::Error for ParseF256Error {}
@@ -0,0 +1,177 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use core::str;
+
+use super::{
+    common::AsciiNumLit,
+    options::ParseOptions,
+    parse_error::{ParseErrorKind, ParseF256Error},
+};
+use crate::f256;
+
+/// Returns the length of the longest prefix of `bytes` which is a valid
+/// literal.
+fn len_of_longest_valid_prefix(
+    bytes: &[u8],
+) -> Result<usize, ParseF256Error> {
+    const SPECIAL_VALUES: [&[u8]; 3] = [b"infinity", b"inf", b"nan"];
+    let opts = ParseOptions::new();
+    let mut lit = AsciiNumLit::new(bytes, &opts);
+    // Byte offset of the current position of `lit` in `bytes`.
+    let pos = |lit: &AsciiNumLit| bytes.len() - lit.n_bytes();
+    if lit.is_empty() {
+        return Err(ParseF256Error::new(ParseErrorKind::Empty, 0));
+    }
+    lit.get_sign();
+    let start = pos(&lit);
+    if let [b'0', b'x' | b'X', ..] = &bytes[start..] {
+        let mut hex = AsciiNumLit::new(&bytes[start + 2..], &opts);
+        if hex.skip_number(16) {
+            return Ok(pos(&hex));
+        }
+    }
+    if lit.skip_number(10) {
+        return Ok(pos(&lit));
+    }
+    // No number, check for special values.
+    let mut n_matching = 0;
+    for val in SPECIAL_VALUES {
+        let (n, _) = lit.n_matching_ignore_ascii_case(val);
+        if n == val.len() {
+            return Ok(start + n);
+        }
+        n_matching = n_matching.max(n);
+    }
+    // Report the position where a digit or the rest of a special value was
+    // expected.
+    let err_pos = start + n_matching.max(lit.first_eq(b'.') as usize);
+    let kind = if err_pos == bytes.len() {
+        ParseErrorKind::UnexpectedEnd
+    } else {
+        ParseErrorKind::InvalidChar
+    };
+    Err(ParseF256Error::new(kind, err_pos))
+}
+
+impl f256 {
+    /// Parses the longest prefix of `bytes` which is a valid literal (see
+    /// [`f256::parse`]) and returns the value together with the number of
+    /// bytes consumed.
+    ///
+    /// As with `strtod` in C, an incomplete exponent is not consumed, e.g.
+    /// parsing `b"1.5e+x"` gives 1.5 and 3, and `b"0x"` gives 0 and 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseF256Error`] if no prefix of `bytes` is a valid
+    /// literal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, ParseErrorKind};
+    /// let bytes = b"-12.5 7 inf";
+    /// let (f, n) = f256::parse_partial(bytes).unwrap();
+    /// assert_eq!((f, n), (f256::from(-12.5), 5));
+    /// let (f, n) = f256::parse_partial(&bytes[n + 1..]).unwrap();
+    /// assert_eq!((f, n), (f256::from(7), 1));
+    /// let (f, n) = f256::parse_partial(&bytes[8..]).unwrap();
+    /// assert_eq!((f, n), (f256::INFINITY, 3));
+    /// let err = f256::parse_partial(b"+x").unwrap_err();
+    /// assert_eq!(err.kind(), ParseErrorKind::InvalidChar);
+    /// assert_eq!(err.position(), 1);
+    /// ```
+    #[allow(unsafe_code)]
+    pub fn parse_partial(
+        bytes: &[u8],
+    ) -> Result<(Self, usize), ParseF256Error> {
+        let len = len_of_longest_valid_prefix(bytes)?;
+        // SAFETY: the prefix only contains ASCII chars.
+        let lit = unsafe { str::from_utf8_unchecked(&bytes[..len]) };
+        Self::parse(lit).map(|f| (f, len))
+    }
+}
+
+#[cfg(test)]
+mod parse_partial_tests {
+    use core::str::FromStr;
+
+    use super::*;
+
+    fn assert_prefix(bytes: &[u8], len: usize) {
+        let lit = str::from_utf8(&bytes[..len]).unwrap();
+        let (f, n) = f256::parse_partial(bytes).unwrap();
+        assert_eq!(n, len, "{lit}");
+        let g = f256::from_str(lit).unwrap();
+        assert!(f == g || (f.is_nan() && g.is_nan()), "{lit}");
+    }
+
+    #[test]
+    fn test_decimal() {
+        assert_prefix(b"0", 1);
+        assert_prefix(b"-17 ", 3);
+        assert_prefix(b"+3.25,4", 5);
+        assert_prefix(b"5.x", 2);
+        assert_prefix(b".5.5", 2);
+        assert_prefix(b"1234567890123456789.0987654321 x", 30);
+        assert_prefix(b"-000000000000000000007;", 22);
+        assert_prefix(b"12e3 ", 4);
+        assert_prefix(b"12E-3e", 5);
+        assert_prefix(b"12e", 2);
+        assert_prefix(b"12e+", 2);
+        assert_prefix(b"12e-x", 2);
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_prefix(b"0x1.8p3 ", 7);
+        assert_prefix(b"-0X.8p-1p", 8);
+        assert_prefix(b"0x1fg", 4);
+        assert_prefix(b"0x1p", 3);
+        assert_prefix(b"0x", 1);
+        assert_prefix(b"0x.p1", 1);
+        assert_prefix(b"-0xg", 2);
+    }
+
+    #[test]
+    fn test_special() {
+        assert_prefix(b"inf", 3);
+        assert_prefix(b"-infinity ", 9);
+        assert_prefix(b"Infinit", 3);
+        assert_prefix(b"+INFx", 4);
+        assert_prefix(b"nan7", 3);
+        assert_prefix(b"-NaN", 4);
+    }
+
+    #[test]
+    fn test_non_ascii_suffix() {
+        assert_prefix("1.5€".as_bytes(), 3);
+        assert_prefix("inf∞".as_bytes(), 3);
+    }
+
+    #[test]
+    fn test_err() {
+        let cases: [(&[u8], ParseErrorKind, usize); 9] = [
+            (b"", ParseErrorKind::Empty, 0),
+            (b"-", ParseErrorKind::UnexpectedEnd, 1),
+            (b" 1", ParseErrorKind::InvalidChar, 0),
+            (b"+x", ParseErrorKind::InvalidChar, 1),
+            (b".", ParseErrorKind::UnexpectedEnd, 1),
+            (b"-.e5", ParseErrorKind::InvalidChar, 2),
+            (b"e5", ParseErrorKind::InvalidChar, 0),
+            (b"-in", ParseErrorKind::UnexpectedEnd, 3),
+            (b"nax", ParseErrorKind::InvalidChar, 2),
+        ];
+        for (bytes, kind, pos) in cases {
+            let err = f256::parse_partial(bytes).unwrap_err();
+            assert_eq!((err.kind(), err.position()), (kind, pos));
+        }
+    }
+}
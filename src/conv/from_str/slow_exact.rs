@@ -13,18 +13,20 @@
 /// `f256`.
 use core::cmp::min;
 
-use super::{common::AsciiNumLit, Decimal, MAX_DIGITS};
+use super::{
+    common::AsciiNumLit, options::ParseOptions, Decimal, MAX_DIGITS,
+};
 use crate::{
-    f256, EMAX, EMIN, EXP_BIAS, FRACTION_BITS, HI_FRACTION_BIAS,
-    SIGNIFICAND_BITS,
+    f256, ExceptionFlags, EMAX, EMIN, EXP_BIAS, FRACTION_BITS,
+    HI_FRACTION_BIAS, SIGNIFICAND_BITS,
 };
 
 // Parse a valid non-zero decimal number.
 #[allow(unsafe_code)]
 #[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::cast_possible_wrap)]
-fn parse_decimal(s: &str) -> Decimal {
-    let mut lit = AsciiNumLit::new(s.as_ref());
+fn parse_decimal(s: &str, opts: &ParseOptions) -> Decimal {
+    let mut lit = AsciiNumLit::new(s.as_ref(), opts);
     let mut res = Decimal {
         sign: lit.get_sign(),
         ..Decimal::default()
@@ -40,6 +42,8 @@ fn parse_decimal(s: &str) -> Decimal {
             res.add_digit(d);
         } else if *c == b'.' {
             lit.state.pos_radix_point = Some(lit.len());
+        } else if lit.skip_separator() {
+            continue;
         } else {
             break;
         }
@@ -51,7 +55,7 @@ fn parse_decimal(s: &str) -> Decimal {
     lit.state.end_pos_signif = lit.len();
 
     // Check state.
-    debug_assert!(!lit.state.invalid);
+    debug_assert!(lit.state.pos_double_radix_point.is_none());
     let (mut n_digits, mut n_frac_digits) =
         if let Some(pos) = lit.state.pos_radix_point {
             (
@@ -87,10 +91,21 @@ fn parse_decimal(s: &str) -> Decimal {
 }
 
 /// Create a correctly rounded `f256` from a valid decimal number literal.
+#[inline]
+pub(super) fn f256_exact(s: &str, opts: &ParseOptions) -> f256 {
+    f256_exact_with_flags(s, opts, &mut ExceptionFlags::default())
+}
+
+/// Create a correctly rounded `f256` from a valid decimal number literal,
+/// adding the exception flags raised by the conversion to `flags`.
 #[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::cast_possible_wrap)]
 #[allow(clippy::cast_sign_loss)]
-pub(super) fn f256_exact(s: &str) -> f256 {
+pub(super) fn f256_exact_with_flags(
+    s: &str,
+    opts: &ParseOptions,
+    flags: &mut ExceptionFlags,
+) -> f256 {
     // ⌊log₁₀(2⁶⁴-1)⌋
     const MAX_DEC_SHIFT: u8 = 19;
     // [0] + [⌊log₂(10ⁿ⌋] for n in [1..MAX_DEC_SHIFT - 1] + [60]
@@ -118,7 +133,7 @@ pub(super) fn f256_exact(s: &str) -> f256 {
     ];
 
     // Parse the number literal into a high precision decimal.
-    let mut dec = parse_decimal(s);
+    let mut dec = parse_decimal(s, opts);
 
     // Multiply / devide by powers of 2 (using non-rounding shifts) until the
     // number is in the range [½..1].
@@ -146,6 +161,9 @@ pub(super) fn f256_exact(s: &str) -> f256 {
     // Adjust exponent to put the number in range [1..2].
     bin_exp -= 1;
 
+    // The number is tiny if it is less than 2^Eₘᵢₙ before rounding.
+    let is_tiny = bin_exp < EMIN;
+
     // If the exponent is too small, right-shift digits and adjust exponent.
     while bin_exp < EMIN {
         let n = min((EMIN - bin_exp) as usize, Decimal::MAX_SHIFT as usize);
@@ -155,6 +173,7 @@ pub(super) fn f256_exact(s: &str) -> f256 {
 
     // If the exponent is too large, return ±Infinity
     if bin_exp > EMAX {
+        *flags |= ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT;
         return [f256::INFINITY, f256::NEG_INFINITY][dec.sign as usize];
     }
 
@@ -167,12 +186,17 @@ pub(super) fn f256_exact(s: &str) -> f256 {
         sh -= n;
         dec.left_shift(n);
     }
+    // The result is exact if no fractional digits are left.
+    let is_exact =
+        !dec.truncated && dec.n_digits as i64 <= i64::from(dec.decimal_point);
+    flags.set_inexact(u32::from(!is_exact), is_tiny);
     let mut significand = dec.round();
     if significand.hi.0 >= HI_FRACTION_BIAS << 1 {
         // Rounding overflowed, need to shift back.
         dec.right_shift(1);
         bin_exp += 1;
         if bin_exp > EMAX {
+            *flags |= ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT;
             return [f256::INFINITY, f256::NEG_INFINITY][dec.sign as usize];
         }
         significand = dec.round();
@@ -200,7 +224,7 @@ mod tests {
     fn parse_frac_only_with_trailing_zeroes() {
         let s = "-.000000000000000010000000000000000000000000000000000000\
             00000000000000000000000000000000000000000000000000000000000000000";
-        let mut dec = parse_decimal(s);
+        let mut dec = parse_decimal(s, &ParseOptions::new());
         assert_eq!(dec.n_digits, 1);
         assert_eq!(dec.digits[0], 1);
         assert_eq!(dec.decimal_point, -16);
@@ -210,7 +234,7 @@ mod tests {
     #[test]
     fn parse_frac_only_without_trailing_zeroes() {
         let s = ".3000001";
-        let mut dec = parse_decimal(s);
+        let mut dec = parse_decimal(s, &ParseOptions::new());
         assert_eq!(dec.n_digits, 7);
         assert_eq!(dec.digits[0], 3);
         assert_eq!(dec.digits[6], 1);
@@ -221,7 +245,7 @@ mod tests {
     #[test]
     fn parse_nonzero_digits_with_dot_and_trailing_zeroes_without_exp() {
         let s = "-750.629394531250000000000000000000000000000000000000";
-        let mut dec = parse_decimal(s);
+        let mut dec = parse_decimal(s, &ParseOptions::new());
         assert_eq!(dec.n_digits, 14);
         assert_eq!(dec.digits[0], 7);
         assert_eq!(dec.digits[13], 5);
@@ -234,7 +258,7 @@ mod tests {
         let s = "-7.62939453125000000000000000000000000000000000000000000\
             0000000000000000000000000000000000000000000000000000000000000000000\
             00e-06";
-        let mut dec = parse_decimal(s);
+        let mut dec = parse_decimal(s, &ParseOptions::new());
         assert_eq!(dec.n_digits, 12);
         assert_eq!(dec.digits[0], 7);
         assert_eq!(dec.digits[11], 5);
@@ -250,7 +274,7 @@ mod tests {
         s.push('0');
         s.push('0');
         let s = s.as_str();
-        let mut dec = parse_decimal(s);
+        let mut dec = parse_decimal(s, &ParseOptions::new());
         assert_eq!(dec.n_digits, MAX_DIGITS);
         assert_eq!(dec.digits[0], 1);
         assert_eq!(dec.digits[MAX_DIGITS - 1], 9);
@@ -264,7 +288,7 @@ mod tests {
         s.push_str(&"0".repeat(MAX_DIGITS - 1));
         s.push('9');
         let s = s.as_str();
-        let mut dec = parse_decimal(s);
+        let mut dec = parse_decimal(s, &ParseOptions::new());
         assert_eq!(dec.n_digits, 1);
         assert_eq!(dec.digits[0], 1);
         assert_eq!(dec.decimal_point, 1);
@@ -11,5 +11,10 @@ mod big_decimal;
 mod from_float;
 mod from_int;
 mod from_str;
+mod to_float;
 mod to_int;
 mod to_str;
+
+pub use from_str::{ParseErrorKind, ParseF256Error, ParseOptions};
+#[cfg(feature = "alloc")]
+pub use to_str::SigDigits;
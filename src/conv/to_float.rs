@@ -0,0 +1,372 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use super::from_float::Float;
+use crate::{
+    abs_bits, exp, f256, fraction, signif, BigUInt, BinEncSpecial,
+    ExceptionFlags, FRACTION_BITS, SIGNIFICAND_BITS,
+};
+
+/// Returns `f` converted to the binary floating point type `F`, rounded to
+/// nearest, ties to even, and sets the exception flags raised by the
+/// conversion in `flags`.
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_possible_wrap)]
+#[allow(clippy::cast_sign_loss)]
+fn to_float<F: Float>(f: &f256, flags: &mut ExceptionFlags) -> F {
+    let sign_bits = (f.sign() as u64) << F::SIGN_SHIFT;
+    let abs_bits = abs_bits(f);
+    if abs_bits.is_special() {
+        return F::from_bits(
+            sign_bits
+                | if abs_bits.is_zero() {
+                    0_u64
+                } else if f.is_infinite() {
+                    F::INF
+                } else {
+                    // NaN => keep the upper bits of the payload and make
+                    // sure the result is a quiet NaN.
+                    let payload = fraction(&abs_bits)
+                        >> (FRACTION_BITS - F::FRACTION_BITS);
+                    F::INF
+                        | payload.lo.0 as u64
+                        | (1_u64 << (F::FRACTION_BITS - 1))
+                },
+        );
+    }
+    // f is finite and != 0
+    // |f| = m⋅2ᵉ⁻²³⁶ with 2²³⁶ <= m < 2²³⁷ (f normal) or 0 < m < 2²³⁶ (f
+    // subnormal)
+    let emax = F::EXP_BIAS as i32;
+    let emin = 1 - emax;
+    let e = exp(&abs_bits);
+    if e > emax {
+        *flags |= ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT;
+        return F::from_bits(sign_bits | F::INF);
+    }
+    let (shift, biased_exp) = if e >= emin {
+        // normal range of F
+        (FRACTION_BITS - F::FRACTION_BITS, (e + emax - 1) as u64)
+    } else {
+        // subnormal range of F
+        (FRACTION_BITS - F::FRACTION_BITS + (emin - e) as u32, 0_u64)
+    };
+    if shift > SIGNIFICAND_BITS {
+        // |f| < ½⋅2ᵉᵐⁱⁿ⁻ᵖ⁺¹ => result underflows to zero
+        *flags |= ExceptionFlags::UNDERFLOW | ExceptionFlags::INEXACT;
+        return F::from_bits(sign_bits);
+    }
+    let signif = signif(&abs_bits);
+    flags.set_inexact(!signif.rem_pow2(shift).is_zero() as u32, e < emin);
+    let m = signif.rounding_div_pow2(shift);
+    // The significand of a normal result includes the hidden bit, so adding
+    // it increments the biased exponent. If rounding caused the significand
+    // to overflow, this increments the exponent once more, possibly giving
+    // infinity, which is the correct result in this case.
+    let abs_bits_z = (biased_exp << F::FRACTION_BITS) + m.lo.0 as u64;
+    if abs_bits_z == F::INF {
+        *flags |= ExceptionFlags::OVERFLOW;
+    }
+    F::from_bits(sign_bits | abs_bits_z)
+}
+
+impl f256 {
+    /// Returns `self` converted to `f64`, rounded to nearest, ties to even.
+    ///
+    /// Values exceeding the range of `f64` are converted to ±∞, values too
+    /// small to be represented as `f64` are converted to ±0. For a NaN, the
+    /// sign and the upper bits of the payload are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f256::f256;
+    /// let f = f256::from(0.1_f64);
+    /// assert_eq!(f.to_f64(), 0.1_f64);
+    /// let g = f256::ONE / f256::from(3);
+    /// assert_eq!(g.to_f64(), 1_f64 / 3_f64);
+    ///
+    /// assert_eq!(f256::MAX.to_f64(), f64::INFINITY);
+    /// assert_eq!((-f256::MIN_POSITIVE).to_f64(), -0_f64);
+    /// assert!(f256::NAN.to_f64().is_nan());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_f64(&self) -> f64 {
+        to_float::<f64>(self, &mut ExceptionFlags::default())
+    }
+
+    /// Returns `self` converted to `f64`, rounded to nearest, ties to even,
+    /// together with the exception flags raised by the conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, ExceptionFlags};
+    /// let (f, flags) = f256::from(0.5_f64).to_f64_with_flags();
+    /// assert_eq!(f, 0.5_f64);
+    /// assert!(flags.is_empty());
+    /// let (f, flags) = (f256::ONE / f256::from(3)).to_f64_with_flags();
+    /// assert_eq!(flags, ExceptionFlags::INEXACT);
+    /// let (f, flags) = f256::MAX.to_f64_with_flags();
+    /// assert_eq!(f, f64::INFINITY);
+    /// assert_eq!(flags, ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_f64_with_flags(&self) -> (f64, ExceptionFlags) {
+        let mut flags = ExceptionFlags::default();
+        (to_float::<f64>(self, &mut flags), flags)
+    }
+
+    /// Returns `self` converted to `f32`, rounded to nearest, ties to even.
+    ///
+    /// Values exceeding the range of `f32` are converted to ±∞, values too
+    /// small to be represented as `f32` are converted to ±0. For a NaN, the
+    /// sign and the upper bits of the payload are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f256::f256;
+    /// let f = f256::from(0.1_f32);
+    /// assert_eq!(f.to_f32(), 0.1_f32);
+    /// let g = f256::ONE / f256::from(3);
+    /// assert_eq!(g.to_f32(), 1_f32 / 3_f32);
+    ///
+    /// assert_eq!(f256::from(f64::MAX).to_f32(), f32::INFINITY);
+    /// assert_eq!((-f256::from(f64::MIN_POSITIVE)).to_f32(), -0_f32);
+    /// assert!(f256::NAN.to_f32().is_nan());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_f32(&self) -> f32 {
+        to_float::<f32>(self, &mut ExceptionFlags::default())
+    }
+
+    /// Returns `self` converted to `f32`, rounded to nearest, ties to even,
+    /// together with the exception flags raised by the conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, ExceptionFlags};
+    /// let (f, flags) = f256::from(0.5_f32).to_f32_with_flags();
+    /// assert_eq!(f, 0.5_f32);
+    /// assert!(flags.is_empty());
+    /// let (f, flags) = f256::from(f64::MIN_POSITIVE).to_f32_with_flags();
+    /// assert_eq!(f, 0_f32);
+    /// assert_eq!(flags, ExceptionFlags::UNDERFLOW | ExceptionFlags::INEXACT);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_f32_with_flags(&self) -> (f32, ExceptionFlags) {
+        let mut flags = ExceptionFlags::default();
+        (to_float::<f32>(self, &mut flags), flags)
+    }
+}
+
+macro_rules! impl_from_f256_for_float {
+    ($($t:ty),*) => {
+        $(
+        impl From<f256> for $t {
+            #[inline]
+            fn from(value: f256) -> Self {
+                to_float::<Self>(&value, &mut ExceptionFlags::default())
+            }
+        }
+
+        impl From<&f256> for $t {
+            #[inline]
+            fn from(value: &f256) -> Self {
+                to_float::<Self>(value, &mut ExceptionFlags::default())
+            }
+        }
+        )*
+    }
+}
+
+impl_from_f256_for_float!(f32, f64);
+
+#[cfg(test)]
+mod to_f64_tests {
+    use super::*;
+    use crate::HI_FRACTION_BITS;
+
+    #[test]
+    fn test_nan() {
+        let f = f256::NAN.to_f64();
+        assert!(f.is_nan());
+        assert!(f.is_sign_positive());
+        let f = (-f256::NAN).to_f64();
+        assert!(f.is_nan());
+        assert!(f.is_sign_negative());
+        // NaN with payload
+        let nan = f256::from_bits((
+            f256::INFINITY.to_bits().0
+                | (0x5a5_u128 << (HI_FRACTION_BITS - 12)),
+            1,
+        ));
+        assert!(nan.is_nan());
+        let f = nan.to_f64();
+        assert!(f.is_nan());
+        assert_eq!(f.to_bits(), 0x7ffd_a500_0000_0000);
+    }
+
+    #[test]
+    fn test_inf() {
+        assert_eq!(f256::INFINITY.to_f64(), f64::INFINITY);
+        assert_eq!(f256::NEG_INFINITY.to_f64(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_zero() {
+        let z = f256::ZERO.to_f64();
+        assert_eq!(z, 0_f64);
+        assert!(z.is_sign_positive());
+        let z = f256::NEG_ZERO.to_f64();
+        assert_eq!(z, 0_f64);
+        assert!(z.is_sign_negative());
+    }
+
+    #[test]
+    fn test_exact() {
+        for f in [
+            1_f64,
+            -1_f64,
+            2_f64,
+            0.1_f64,
+            -17.625_f64,
+            109.04e-115_f64,
+            f64::MAX,
+            f64::MIN,
+            f64::MIN_POSITIVE,
+            f64::EPSILON,
+            7.4e-317_f64,
+            -0.984e-312_f64,
+            f64::from_bits(1),
+        ] {
+            assert_eq!(f256::from(f).to_f64(), f);
+            assert_eq!(f64::from(f256::from(f)), f);
+            assert_eq!(f64::from(&f256::from(f)), f);
+        }
+    }
+
+    #[test]
+    fn test_rounding() {
+        let one = f256::ONE;
+        let eps = f256::from(f64::EPSILON);
+        let half_eps = eps.div2();
+        // tie => round to even
+        assert_eq!((one + half_eps).to_f64(), 1_f64);
+        assert_eq!(
+            (one + eps + half_eps).to_f64(),
+            1_f64 + 2_f64 * f64::EPSILON
+        );
+        // above tie => round up
+        assert_eq!(
+            (one + half_eps + f256::EPSILON).to_f64(),
+            1_f64 + f64::EPSILON
+        );
+        // below tie => round down
+        assert_eq!((one + half_eps - f256::EPSILON).to_f64(), 1_f64);
+        assert_eq!(
+            (-(one + half_eps + f256::EPSILON)).to_f64(),
+            -1_f64 - f64::EPSILON
+        );
+        // rounding overflows into exponent
+        assert_eq!((f256::TWO - f256::EPSILON).to_f64(), 2_f64);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = f256::from(f64::MAX);
+        let half_ulp = max.ulp().mul_pow2(FRACTION_BITS - 53);
+        assert_eq!((max + half_ulp - max.ulp()).to_f64(), f64::MAX);
+        assert_eq!((max + half_ulp).to_f64(), f64::INFINITY);
+        assert_eq!((-(max + half_ulp)).to_f64(), f64::NEG_INFINITY);
+        assert_eq!(f256::MAX.to_f64(), f64::INFINITY);
+        assert_eq!(f256::MIN.to_f64(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_subnormal() {
+        let min = f256::from(f64::from_bits(1));
+        // tie => round to even
+        assert_eq!(min.div2().to_f64(), 0_f64);
+        assert_eq!((min * f256::from(3)).div2().to_f64(), f64::from_bits(2));
+        assert_eq!((min + min.div2()).to_f64(), f64::from_bits(2));
+        // above tie => round up
+        assert_eq!(
+            (min.div2() + min.div2().ulp()).to_f64(),
+            f64::from_bits(1)
+        );
+        // below tie => round down
+        assert_eq!((min.div2() - min.div2().ulp()).to_f64(), 0_f64);
+        let z = (-min.div_pow2(2)).to_f64();
+        assert_eq!(z, 0_f64);
+        assert!(z.is_sign_negative());
+        // rounding from subnormal to normal range
+        let f = f256::from(f64::MIN_POSITIVE) - min.div2();
+        assert_eq!(f.to_f64(), f64::MIN_POSITIVE);
+        assert_eq!(f256::MIN_POSITIVE.to_f64(), 0_f64);
+        assert_eq!(f256::MIN_GT_ZERO.to_f64(), 0_f64);
+    }
+}
+
+#[cfg(test)]
+mod to_f32_tests {
+    use super::*;
+
+    #[test]
+    fn test_specials() {
+        assert!(f256::NAN.to_f32().is_nan());
+        assert!((-f256::NAN).to_f32().is_sign_negative());
+        assert_eq!(f256::INFINITY.to_f32(), f32::INFINITY);
+        assert_eq!(f256::NEG_INFINITY.to_f32(), f32::NEG_INFINITY);
+        assert!(f256::ZERO.to_f32().is_sign_positive());
+        assert!(f256::NEG_ZERO.to_f32().is_sign_negative());
+    }
+
+    #[test]
+    fn test_exact() {
+        for f in [
+            1_f32,
+            -1_f32,
+            0.1_f32,
+            -17.625_f32,
+            3.782e-38_f32,
+            f32::MAX,
+            f32::MIN,
+            f32::MIN_POSITIVE,
+            f32::EPSILON,
+            f32::from_bits(1),
+        ] {
+            assert_eq!(f256::from(f).to_f32(), f);
+            assert_eq!(f32::from(f256::from(f)), f);
+        }
+    }
+
+    #[test]
+    fn test_rounding() {
+        let f = f256::from(0.1_f64);
+        assert_eq!(f.to_f32(), 0.1_f32);
+        let f = f256::ONE / f256::from(7);
+        assert_eq!(f.to_f32(), 1_f32 / 7_f32);
+        let f = f256::from(f64::from(f32::MAX) + f64::from(f32::MAX) / 1e8);
+        assert_eq!(f.to_f32(), f32::MAX);
+        let f =
+            f256::from(f64::from(f32::MAX) * (1.0 + f64::from(f32::EPSILON)));
+        assert_eq!(f.to_f32(), f32::INFINITY);
+        let f = f256::from(f64::from(f32::from_bits(1)) * 0.75);
+        assert_eq!(f.to_f32(), f32::from_bits(1));
+        let f = f256::from(f64::from(f32::from_bits(1)) * 0.5);
+        assert_eq!(f.to_f32(), 0_f32);
+    }
+}
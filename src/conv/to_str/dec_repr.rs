@@ -7,12 +7,15 @@
 // $Source$
 // $Revision$
 
-use alloc::string::ToString;
-use core::{cmp::max, fmt, mem::MaybeUninit};
+use core::{
+    cmp::max,
+    fmt::{self, Write},
+    mem::MaybeUninit,
+};
 
 use super::{
     common::floor_log10_pow2,
-    formatted::{Formatted, Part},
+    formatted::{Formatted, Part, SliceWriter},
     powers_of_five::is_multiple_of_pow5,
 };
 use crate::{
@@ -20,6 +23,22 @@ use crate::{
     f256, BigUInt, DivRem, U256, U512,
 };
 
+/// Maximum number of decimal digits of a U256.
+const MAX_N_DIGITS: usize = U256::MAX_N_DECIMAL_DIGITS as usize + 1;
+
+/// Maximum length of a decimal exponent (e.g. "-78984").
+const MAX_EXP_LEN: usize = 6;
+
+/// Writes the decimal digits of `n` into `buf` and returns them as str.
+fn write_digits<'a>(
+    n: &U256,
+    buf: &'a mut [u8; MAX_N_DIGITS],
+) -> Result<&'a str, fmt::Error> {
+    let mut w = SliceWriter::new(buf);
+    write!(w, "{n}")?;
+    Ok(w.into_str())
+}
+
 /// Returns ⌊log₁₀(5ⁱ)⌋ for 0 <= i <= 262380.
 #[inline(always)]
 #[allow(clippy::cast_sign_loss)]
@@ -298,25 +317,32 @@ impl DecNumRepr {
         (signif10, exp10)
     }
 
+    /// Calls `op` with the parts of `self` in scientific notation, using
+    /// the given exponent mark.
     #[allow(unsafe_code, trivial_casts)]
     #[allow(clippy::cast_possible_truncation)]
     #[allow(clippy::cast_possible_wrap)]
-    pub(crate) fn fmt_scientific(
-        self,
+    pub(crate) fn with_scientific_parts<F>(
+        &self,
         exp_mark: char,
-        form: &mut fmt::Formatter<'_>,
-    ) -> fmt::Result {
+        op: F,
+    ) -> fmt::Result
+    where
+        F: FnOnce(&Formatted<'_>) -> fmt::Result,
+    {
         let mut parts: [MaybeUninit<Part<'_>>; 5] =
             // SAFETY: only parts initialized by MaybeUninit::new are used
             // later.
             unsafe { MaybeUninit::uninit().assume_init() };
         let mut n_parts = 0_usize;
-        let zero_padding: &mut Part;
-        let signif = self.signif10.to_string();
-        let digits = signif.as_str();
+        let mut signif_buf = [0_u8; MAX_N_DIGITS];
+        let digits = write_digits(&self.signif10, &mut signif_buf)?;
         let n_digits = digits.len();
         let exp10 = self.exp10 + n_digits as i32 - 1;
-        let exp = exp10.to_string();
+        let mut exp_buf = [0_u8; MAX_EXP_LEN];
+        let mut w = SliceWriter::new(&mut exp_buf);
+        write!(w, "{exp10}")?;
+        let exp = w.into_str();
         parts[n_parts] = MaybeUninit::new(Part::Digits(&digits[..1]));
         n_parts += 1;
         if n_digits > 1 {
@@ -327,7 +353,7 @@ impl DecNumRepr {
         }
         parts[n_parts] = MaybeUninit::new(Part::Char(exp_mark));
         n_parts += 1;
-        parts[n_parts] = MaybeUninit::new(Part::Digits(&exp));
+        parts[n_parts] = MaybeUninit::new(Part::Digits(exp));
         n_parts += 1;
         let formatted = Formatted {
             // SAFETY: n_parts elements are initialized.
@@ -336,24 +362,25 @@ impl DecNumRepr {
                     as *const [Part<'_>])
             },
         };
-        formatted.pad_parts(self.sign == 1, form)
+        op(&formatted)
     }
-}
 
-impl fmt::Display for DecNumRepr {
+    /// Calls `op` with the parts of `self` in fixed-point notation.
     #[allow(unsafe_code, trivial_casts)]
     #[allow(clippy::cast_sign_loss)]
     #[allow(clippy::cast_possible_truncation)]
     #[allow(clippy::cast_possible_wrap)]
-    fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+    pub(crate) fn with_fixed_point_parts<F>(&self, op: F) -> fmt::Result
+    where
+        F: FnOnce(&Formatted<'_>) -> fmt::Result,
+    {
         let mut parts: [MaybeUninit<Part<'_>>; 4] =
             // SAFETY: only parts initialized by MaybeUninit::new are used
             // later.
             unsafe { MaybeUninit::uninit().assume_init() };
         let mut n_parts = 0_usize;
-        let zero_padding: &mut Part;
-        let signif = self.signif10.to_string();
-        let digits = signif.as_str();
+        let mut signif_buf = [0_u8; MAX_N_DIGITS];
+        let digits = write_digits(&self.signif10, &mut signif_buf)?;
         let n_digits = digits.len();
         let n_int_digits = max(0, (n_digits as i32 + self.exp10)) as usize;
         let n_frac_digits = max(0, -self.exp10) as usize;
@@ -399,7 +426,25 @@ impl fmt::Display for DecNumRepr {
                     as *const [Part<'_>])
             },
         };
-        formatted.pad_parts(self.sign == 1, form)
+        op(&formatted)
+    }
+
+    pub(crate) fn fmt_scientific(
+        self,
+        exp_mark: char,
+        form: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        self.with_scientific_parts(exp_mark, |formatted| {
+            formatted.pad_parts(self.sign == 1, form)
+        })
+    }
+}
+
+impl fmt::Display for DecNumRepr {
+    fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.with_fixed_point_parts(|formatted| {
+            formatted.pad_parts(self.sign == 1, form)
+        })
     }
 }
 
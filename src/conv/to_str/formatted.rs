@@ -7,7 +7,9 @@
 // $Source$
 // $Revision$
 
-use core::{fmt, slice::Iter};
+#[cfg(any(feature = "alloc", test))]
+use alloc::string::String;
+use core::{fmt, slice::Iter, str};
 
 /// Parts of a formatted decimal number.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -78,65 +80,350 @@ impl Formatted<'_> {
 
     /// Writes the formatted parts to the formatter after applying the
     /// padding.
-    #[allow(clippy::integer_division)]
     pub fn pad_parts(
         &self,
         is_sign_negative: bool,
         form: &mut fmt::Formatter<'_>,
     ) -> fmt::Result {
-        let mut width = form.width().unwrap_or(1);
-        let mut sign = if is_sign_negative {
-            "-"
-        } else if form.sign_plus() {
-            "+"
-        } else {
-            ""
-        };
-        let len = self.len() + sign.len();
-        let mut pre_padding: Option<Part<'_>> = None;
-        let mut post_padding: Option<Part<'_>> = None;
-        if len < width {
-            if form.sign_aware_zero_pad() {
-                // Render sign first, then zero padding.
-                form.write_str(sign)?;
-                sign = "";
-                pre_padding = Some(Part::Padding(width - len, '0'));
+        pad_with(is_sign_negative, self.len(), form, |form| self.write(form))
+    }
+}
+
+/// Calls `op` to write a number with the given byte length (without sign)
+/// to the formatter, writing the sign and applying the padding as specified
+/// by the formatter.
+#[allow(clippy::integer_division)]
+pub(crate) fn pad_with<F>(
+    is_sign_negative: bool,
+    len: usize,
+    form: &mut fmt::Formatter<'_>,
+    op: F,
+) -> fmt::Result
+where
+    F: FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    let width = form.width().unwrap_or(0);
+    let sign = if is_sign_negative {
+        "-"
+    } else if form.sign_plus() {
+        "+"
+    } else {
+        ""
+    };
+    let len = len + sign.len();
+    if len >= width {
+        // No alignment, no padding
+        form.write_str(sign)?;
+        return op(form);
+    }
+    let n_to_fill = width - len;
+    if form.sign_aware_zero_pad() {
+        // Render sign first, then zero padding.
+        form.write_str(sign)?;
+        Part::Padding(n_to_fill, '0').write(&mut *form)?;
+        return op(form);
+    }
+    let filler = form.fill();
+    let (n_pre, n_post) = match form.align() {
+        Some(fmt::Alignment::Center) => {
+            (n_to_fill / 2, n_to_fill - n_to_fill / 2)
+        }
+        Some(fmt::Alignment::Left) => (0, n_to_fill),
+        // Default to right align
+        _ => (n_to_fill, 0),
+    };
+    Part::Padding(n_pre, filler).write(&mut *form)?;
+    form.write_str(sign)?;
+    op(form)?;
+    Part::Padding(n_post, filler).write(&mut *form)
+}
+
+/// Round-Up the given fixed-point string representation of a decimal number.
+/// Returns true, if there is a carry left over, i.e. all digits were 9.
+#[allow(unsafe_code)]
+#[inline]
+fn round_up_fixed_point_inplace(num: &mut str) -> bool {
+    // SAFETY: see comment at str::as_bytes_mut.
+    unsafe {
+        for b in num.as_bytes_mut().iter_mut().rev() {
+            if *b == b'9' {
+                *b = b'0';
+            } else if *b != b'.' {
+                *b += 1;
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Round-Up the given scientific string representation of a decimal number.
+#[allow(unsafe_code)]
+#[inline]
+fn round_up_scientific_inplace(num: &mut str) -> i32 {
+    let mut carry = 0_i32;
+    let mut idx = num.len() - 1;
+    // SAFETY: see comment at str::as_bytes_mut.
+    unsafe {
+        let bytes = num.as_bytes_mut();
+        loop {
+            // First digit
+            if idx == 0 && bytes[idx] == b'9' {
+                bytes[idx] = b'1';
+                carry = 1;
+                break;
+            } else if bytes[idx] == b'9' {
+                bytes[idx] = b'0';
+                idx -= 1;
+            } else if bytes[idx] == b'.' {
+                idx -= 1;
             } else {
-                let filler = form.fill();
-                let n_to_fill = width - len;
-                (pre_padding, post_padding) = match form.align() {
-                    Some(fmt::Alignment::Center) => {
-                        let n_to_fill_pre = n_to_fill / 2;
-                        (
-                            Some(Part::Padding(n_to_fill_pre, filler)),
-                            Some(Part::Padding(
-                                n_to_fill - n_to_fill_pre,
-                                filler,
-                            )),
-                        )
-                    }
-                    Some(fmt::Alignment::Left) => {
-                        (None, Some(Part::Padding(n_to_fill, filler)))
-                    }
-                    _ => {
-                        // Default to right align
-                        (Some(Part::Padding(n_to_fill, filler)), None)
-                    }
-                };
+                bytes[idx] += 1;
+                break;
+            }
+        }
+    }
+    carry
+}
+
+#[inline]
+const fn is_odd_digit(c: u8) -> bool {
+    matches!(c, b'1' | b'3' | b'5' | b'7' | b'9')
+}
+
+/// Buffer which decimal digits can be written into and rounded up.
+pub(crate) trait DecBuf: fmt::Write {
+    /// Writes `n` zero digits.
+    fn write_zeroes(&mut self, n: usize) -> fmt::Result {
+        const ZEROES: &str = "0000000000000000000000000000000000000000";
+        let mut n = n;
+        while n > 0 {
+            let k = n.min(ZEROES.len());
+            self.write_str(&ZEROES[..k])?;
+            n -= k;
+        }
+        Ok(())
+    }
+
+    /// Rounds up the number in fixed-point notation written so far,
+    /// prepending a '1' in case of a carry.
+    fn round_up_fixed_point(&mut self) -> fmt::Result;
+
+    /// Rounds up the number in scientific notation written so far.
+    /// Returns 1, if the exponent has to be incremented because of a carry,
+    /// otherwise 0.
+    fn round_up_scientific(&mut self) -> Result<i32, fmt::Error>;
+
+    /// Returns true if the last char written is an odd digit.
+    fn ends_with_odd_digit(&self) -> bool;
+}
+
+#[cfg(any(feature = "alloc", test))]
+impl DecBuf for String {
+    fn round_up_fixed_point(&mut self) -> fmt::Result {
+        if round_up_fixed_point_inplace(self.as_mut_str()) {
+            self.insert(0, '1');
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn round_up_scientific(&mut self) -> Result<i32, fmt::Error> {
+        Ok(round_up_scientific_inplace(self.as_mut_str()))
+    }
+
+    #[inline(always)]
+    fn ends_with_odd_digit(&self) -> bool {
+        self.bytes().next_back().is_some_and(is_odd_digit)
+    }
+}
+
+/// Writer appending to a caller-supplied byte buffer.
+///
+/// Writing fails with `fmt::Error` if the buffer is too small.
+pub(crate) struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    /// Returns a new writer appending to `buf`.
+    pub(crate) const fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    /// Returns the number of bytes written so far.
+    pub(crate) const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the content written so far, consuming `self`.
+    pub(crate) fn into_str(self) -> &'a str {
+        // Only complete str slices are written into the buffer.
+        str::from_utf8(&self.buf[..self.len]).unwrap()
+    }
+
+    #[inline(always)]
+    fn as_mut_str(&mut self) -> &mut str {
+        // Only complete str slices are written into the buffer.
+        str::from_utf8_mut(&mut self.buf[..self.len]).unwrap()
+    }
+}
+
+impl fmt::Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl DecBuf for SliceWriter<'_> {
+    fn round_up_fixed_point(&mut self) -> fmt::Result {
+        if round_up_fixed_point_inplace(self.as_mut_str()) {
+            if self.len == self.buf.len() {
+                return Err(fmt::Error);
             }
-            if let Some(part) = pre_padding {
-                part.write(&mut *form)?;
+            self.buf.copy_within(..self.len, 1);
+            self.buf[0] = b'1';
+            self.len += 1;
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn round_up_scientific(&mut self) -> Result<i32, fmt::Error> {
+        Ok(round_up_scientific_inplace(self.as_mut_str()))
+    }
+
+    #[inline(always)]
+    fn ends_with_odd_digit(&self) -> bool {
+        self.len > 0 && is_odd_digit(self.buf[self.len - 1])
+    }
+}
+
+/// Writer which only counts the bytes written.
+pub(crate) struct LenCounter(pub(crate) usize);
+
+impl fmt::Write for LenCounter {
+    #[inline(always)]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// Writer which passes decimal digits through to another writer, holding
+/// back the last digit below 9 and the following 9s (and decimal point), so
+/// that the number written so far can be rounded up without having to
+/// modify output already passed on.
+pub(crate) struct StreamWriter<W: fmt::Write> {
+    inner: W,
+    // Last digit below 9 not yet passed on.
+    pending: Option<u8>,
+    // Number of 9s following the pending digit.
+    n_nines: usize,
+    // Number of 9s preceding a decimal point held back.
+    dot_pos: Option<usize>,
+    // Last char written.
+    last: u8,
+}
+
+impl<W: fmt::Write> StreamWriter<W> {
+    /// Returns a new writer passing its output on to `inner`.
+    pub(crate) const fn new(inner: W) -> Self {
+        Self {
+            inner,
+            pending: None,
+            n_nines: 0,
+            dot_pos: None,
+            last: 0,
+        }
+    }
+
+    /// Passes on all chars held back and returns the inner writer.
+    pub(crate) fn into_inner(mut self) -> Result<W, fmt::Error> {
+        self.flush(None, b'9')?;
+        Ok(self.inner)
+    }
+
+    /// Passes on the pending digit (if any) and the following 9s, which are
+    /// written as `nine`, with `first` replacing the first 9.
+    fn flush(&mut self, first: Option<u8>, nine: u8) -> fmt::Result {
+        if let Some(d) = self.pending.take() {
+            self.inner.write_char(char::from(d))?;
+        }
+        for i in 0..self.n_nines {
+            if self.dot_pos == Some(i) {
+                self.inner.write_char('.')?;
+            }
+            let d = match first {
+                Some(d) if i == 0 => d,
+                _ => nine,
+            };
+            self.inner.write_char(char::from(d))?;
+        }
+        if self.dot_pos == Some(self.n_nines) {
+            self.inner.write_char('.')?;
+        }
+        self.n_nines = 0;
+        self.dot_pos = None;
+        Ok(())
+    }
+
+    fn write_byte(&mut self, b: u8) -> fmt::Result {
+        match b {
+            b'0'..=b'8' => {
+                self.flush(None, b'9')?;
+                self.pending = Some(b);
             }
-            form.write_str(sign)?;
-            self.write(&mut *form)?;
-            if let Some(part) = post_padding {
-                part.write(&mut *form)?;
+            b'9' => self.n_nines += 1,
+            b'.' => self.dot_pos = Some(self.n_nines),
+            _ => {
+                self.flush(None, b'9')?;
+                self.inner.write_char(char::from(b))?;
             }
-        } else {
-            // No alignment, no padding
-            form.write_str(sign)?;
-            self.write(&mut *form)?;
+        }
+        self.last = b;
+        Ok(())
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for StreamWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for b in s.bytes() {
+            self.write_byte(b)?;
         }
         Ok(())
     }
 }
+
+impl<W: fmt::Write> DecBuf for StreamWriter<W> {
+    fn round_up_fixed_point(&mut self) -> fmt::Result {
+        match self.pending {
+            Some(ref mut d) => *d += 1,
+            None => self.inner.write_char('1')?,
+        }
+        self.flush(None, b'0')
+    }
+
+    fn round_up_scientific(&mut self) -> Result<i32, fmt::Error> {
+        if let Some(ref mut d) = self.pending {
+            *d += 1;
+            self.flush(None, b'0')?;
+            Ok(0)
+        } else {
+            self.flush(Some(b'1'), b'0')?;
+            Ok(1)
+        }
+    }
+
+    #[inline(always)]
+    fn ends_with_odd_digit(&self) -> bool {
+        is_odd_digit(self.last)
+    }
+}
@@ -0,0 +1,230 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use core::{fmt, str};
+
+use super::format_nan;
+use crate::{
+    f256, BigUInt, EMIN, EXP_BIAS, FRACTION_BITS, HI_FRACTION_MASK, U256,
+};
+
+/// Number of hex digits of the fraction (236 bits = 59 nibbles).
+#[allow(clippy::integer_division)]
+const N_FRACT_DIGITS: usize = (FRACTION_BITS / 4) as usize;
+
+/// Max length of the hex representation of the absolute value of a finite
+/// f256: 1 + 1 + N_FRACT_DIGITS + 1 + 7 ("1." + fraction + "p-262142").
+const MAX_LEN: usize = N_FRACT_DIGITS + 10;
+
+// Buffer holding the digits of the hex representation.
+struct HexBuf {
+    bytes: [u8; MAX_LEN],
+    len: usize,
+}
+
+impl HexBuf {
+    const fn new() -> Self {
+        Self {
+            bytes: [0_u8; MAX_LEN],
+            len: 0,
+        }
+    }
+
+    const fn push(&mut self, b: u8) {
+        self.bytes[self.len] = b;
+        self.len += 1;
+    }
+
+    fn push_exponent(&mut self, exp: i32) {
+        self.push(if exp < 0 { b'-' } else { b'+' });
+        let mut digits = [0_u8; 10];
+        let mut n = 0_usize;
+        let mut exp = exp.unsigned_abs();
+        loop {
+            digits[n] = b'0' + (exp % 10) as u8;
+            n += 1;
+            exp /= 10;
+            if exp == 0 {
+                break;
+            }
+        }
+        for d in digits[..n].iter().rev() {
+            self.push(*d);
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        // The buffer only contains ASCII characters.
+        str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+/// Write the exact hexadecimal representation of the absolute value of a
+/// finite f256 into `buf`, i.e. "1.<fraction>p<exp>" for normal values,
+/// "0.<fraction>p-262142" for subnormals and "0p+0" for zero, where the
+/// trailing zeroes of the fraction are omitted.
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_possible_wrap)]
+#[allow(clippy::integer_division)]
+fn fill_hex_buf(f: &f256, upper: bool, buf: &mut HexBuf) {
+    let digits = if upper {
+        b"0123456789ABCDEF"
+    } else {
+        b"0123456789abcdef"
+    };
+    let biased_exp = f.biased_exponent();
+    let fraction = U256::new(f.bits.hi.0 & HI_FRACTION_MASK, f.bits.lo.0);
+    let exp = match (biased_exp, fraction.is_zero()) {
+        (0, true) => 0,
+        (0, false) => EMIN,
+        _ => biased_exp as i32 - EXP_BIAS as i32,
+    };
+    buf.push(b'0' + (biased_exp != 0) as u8);
+    if !fraction.is_zero() {
+        buf.push(b'.');
+        let n_digits =
+            N_FRACT_DIGITS - fraction.trailing_zeros() as usize / 4;
+        for i in 0..n_digits {
+            let shift = 4 * (N_FRACT_DIGITS - 1 - i) as u32;
+            let d = (fraction >> shift).lo.0 & 0xf;
+            buf.push(digits[d as usize]);
+        }
+    }
+    buf.push(if upper { b'P' } else { b'p' });
+    buf.push_exponent(exp);
+}
+
+fn format_hex(
+    f: &f256,
+    upper: bool,
+    form: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    if f.is_nan() {
+        format_nan(form)
+    } else if f.is_infinite() {
+        form.pad_integral(f.is_sign_positive(), "", "inf")
+    } else {
+        let mut buf = HexBuf::new();
+        fill_hex_buf(f, upper, &mut buf);
+        form.pad_integral(f.is_sign_positive(), "0x", buf.as_str())
+    }
+}
+
+impl fmt::LowerHex for f256 {
+    /// Formats the value as exact hexadecimal floating point literal, like
+    /// the format specifier "%a" in C, i.e. as `1.8p+3` for 12.
+    /// Using the alternate flag `#` adds the prefix "0x", giving a literal
+    /// which can be parsed by `f256::from_str`.
+    /// A given precision is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// # use core::str::FromStr;
+    /// let f = f256::from(-12);
+    /// assert_eq!(format!("{f:x}"), "-1.8p+3");
+    /// assert_eq!(format!("{f:#x}"), "-0x1.8p+3");
+    /// let s = format!("{:#x}", f256::MIN_POSITIVE);
+    /// assert_eq!(s, "0x1p-262142");
+    /// assert_eq!(f256::from_str(&s).unwrap(), f256::MIN_POSITIVE);
+    /// ```
+    fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_hex(self, false, form)
+    }
+}
+
+impl fmt::UpperHex for f256 {
+    /// Formats the value like [`fmt::LowerHex`], but with upper case digits
+    /// and exponent mark, i.e. as `1.AP+3` for 13.
+    fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_hex(self, true, form)
+    }
+}
+
+#[cfg(test)]
+mod format_hex_tests {
+    use alloc::{format, string::String};
+    use core::str::FromStr;
+
+    use super::*;
+    use crate::consts::{E, PI};
+
+    #[test]
+    fn test_normal() {
+        assert_eq!(format!("{:x}", f256::ONE), "1p+0");
+        assert_eq!(format!("{:x}", f256::from(12)), "1.8p+3");
+        assert_eq!(format!("{:X}", f256::from(-13)), "-1.AP+3");
+        assert_eq!(format!("{:x}", f256::from(0.375)), "1.8p-2");
+        assert_eq!(format!("{:#x}", f256::EPSILON), "0x1p-236");
+        assert_eq!(
+            format!("{:x}", f256::MAX),
+            format!("1.{}p+262143", "f".repeat(59))
+        );
+        assert_eq!(
+            format!("{:x}", f256::ONE + f256::EPSILON),
+            format!("1.{}1p+0", "0".repeat(58))
+        );
+    }
+
+    #[test]
+    fn test_subnormal() {
+        assert_eq!(
+            format!("{:x}", f256::MIN_GT_ZERO),
+            format!("0.{}1p-262142", "0".repeat(58))
+        );
+        assert_eq!(
+            format!("{:#X}", -f256::MIN_POSITIVE.div2()),
+            "-0x0.8P-262142"
+        );
+    }
+
+    #[test]
+    fn test_special() {
+        assert_eq!(format!("{:x}", f256::ZERO), "0p+0");
+        assert_eq!(format!("{:#x}", f256::NEG_ZERO), "-0x0p+0");
+        assert_eq!(format!("{:x}", f256::INFINITY), "inf");
+        assert_eq!(format!("{:#x}", f256::NEG_INFINITY), "-inf");
+        assert_eq!(format!("{:X}", f256::NAN), "NaN");
+        assert_eq!(format!("{:>5x}", f256::NAN), "  NaN");
+    }
+
+    #[test]
+    fn test_padding() {
+        let f = f256::from(12);
+        assert_eq!(format!("{f:>10x}"), "    1.8p+3");
+        assert_eq!(format!("{f:<+10x}"), "+1.8p+3   ");
+        assert_eq!(format!("{f:#012x}"), "0x00001.8p+3");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let values = [
+            f256::MAX,
+            f256::MIN,
+            f256::MIN_POSITIVE,
+            f256::MIN_GT_ZERO,
+            f256::EPSILON,
+            f256::NEG_ZERO,
+            PI,
+            -E,
+            f256::ONE / f256::from(3),
+            f256::MIN_POSITIVE - f256::MIN_GT_ZERO,
+            f256::from(1e300).powi(200),
+        ];
+        for f in values {
+            let s: String = format!("{f:#x}");
+            let g = f256::from_str(&s).unwrap();
+            assert_eq!(f.to_bits(), g.to_bits(), "{s}");
+            let s: String = format!("{f:X}");
+            let g = f256::from_hex_str(&s, false).unwrap();
+            assert_eq!(f.to_bits(), g.to_bits(), "{s}");
+        }
+    }
+}
@@ -10,45 +10,54 @@
 mod common;
 mod dec_repr;
 mod formatted;
+mod hex;
 mod powers_of_five;
+#[cfg(feature = "alloc")]
+mod sig_digits;
+mod to_buf;
 mod to_fixed_prec;
 
-use alloc::{
-    borrow::ToOwned,
-    format,
-    string::{String, ToString},
-};
-use core::{
-    cmp::min,
-    fmt::{self, Display, Write},
-};
+use core::fmt::{self, Display};
 
 use dec_repr::DecNumRepr;
-use to_fixed_prec::{bin_2_dec_fixed_point, bin_2_dec_scientific};
+use formatted::{pad_with, Formatted, LenCounter, Part, StreamWriter};
+use to_fixed_prec::{
+    write_dec_fixed_point, write_dec_fixed_point_exact, write_dec_scientific,
+    write_dec_scientific_exact,
+};
 
 use crate::{f256, split_f256_enc, EXP_MAX, U256};
 
+#[cfg(feature = "alloc")]
+pub use sig_digits::SigDigits;
+
+// Maximum precision supported by the fast conversion based on lookup tables.
+// Higher precisions are handled by a slower conversion based on the exact
+// decimal representation.
 const MAX_PREC: usize = 75;
 
+#[allow(clippy::integer_division)]
 fn format_nan(form: &mut fmt::Formatter<'_>) -> fmt::Result {
-    let nan = "NaN".to_owned();
-    let s = if let Some(width) = form.width() {
-        match form.align() {
-            Some(fmt::Alignment::Center) => format!("{:^width$}", nan),
-            Some(fmt::Alignment::Left) => format!("{:<width$}", nan),
-            _ => format!("{:>width$}", nan),
+    const NAN: &str = "NaN";
+    let n_to_fill = form.width().unwrap_or(0).saturating_sub(NAN.len());
+    let (pre_padding, post_padding) = match form.align() {
+        Some(fmt::Alignment::Center) => {
+            (n_to_fill / 2, n_to_fill - n_to_fill / 2)
         }
-    } else {
-        nan
+        Some(fmt::Alignment::Left) => (0, n_to_fill),
+        _ => (n_to_fill, 0),
     };
-    form.write_str(s.as_str())
+    Part::Padding(pre_padding, ' ').write(&mut *form)?;
+    form.write_str(NAN)?;
+    Part::Padding(post_padding, ' ').write(&mut *form)
 }
 
 fn format_special(f: &f256, form: &mut fmt::Formatter<'_>) -> fmt::Result {
     if f.eq_zero() {
-        let prec = min(form.precision().unwrap_or(0), MAX_PREC);
-        let s = format!("{:.*}", prec, 0.);
-        form.pad_integral(f.is_sign_positive(), "", s.as_str())
+        let prec = form.precision().unwrap_or(0);
+        zero_parts(prec, None, |formatted| {
+            formatted.pad_parts(f.is_sign_negative(), form)
+        })
     } else if f.is_nan() {
         format_nan(form)
     } else {
@@ -56,19 +65,83 @@ fn format_special(f: &f256, form: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+#[inline]
+/// Calls `op` with the parts of a zero with the given number of fractional
+/// digits, in scientific notation if an exponent mark is given.
+fn zero_parts<F>(prec: usize, exp_mark: Option<char>, op: F) -> fmt::Result
+where
+    F: FnOnce(&Formatted<'_>) -> fmt::Result,
+{
+    let mut parts = [Part::Char('0'); 5];
+    let mut n_parts = 1_usize;
+    if prec > 0 {
+        parts[1] = Part::Char('.');
+        parts[2] = Part::Zeroes(prec);
+        n_parts = 3;
+    }
+    if let Some(exp_mark) = exp_mark {
+        parts[n_parts] = Part::Char(exp_mark);
+        parts[n_parts + 1] = Part::Char('0');
+        n_parts += 2;
+    }
+    op(&Formatted {
+        parts: &parts[..n_parts],
+    })
+}
+
+/// Writes the decimal representation of the absolute value of the finite
+/// non-zero `f` with `prec` fractional digits, in scientific notation if an
+/// exponent mark is given, to `w` and returns `w`.
+fn write_dec_prec<W: fmt::Write>(
+    f: &f256,
+    prec: usize,
+    exp_mark: Option<char>,
+    w: W,
+) -> Result<W, fmt::Error> {
+    let abs = f.abs();
+    let mut buf = StreamWriter::new(w);
+    match exp_mark {
+        None if prec <= MAX_PREC => {
+            write_dec_fixed_point(abs, prec, &mut buf)?;
+        }
+        None => write_dec_fixed_point_exact(abs, prec, &mut buf)?,
+        Some(exp_mark) if prec <= MAX_PREC => {
+            write_dec_scientific(abs, exp_mark, prec, &mut buf)?;
+        }
+        Some(exp_mark) => {
+            write_dec_scientific_exact(abs, exp_mark, prec, &mut buf)?;
+        }
+    }
+    buf.into_inner()
+}
+
+/// Formats the finite non-zero `f` with `prec` fractional digits, in
+/// scientific notation if an exponent mark is given, writing directly to
+/// the formatter.
+fn format_dec_prec(
+    f: &f256,
+    prec: usize,
+    exp_mark: Option<char>,
+    form: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    // The length is only needed for padding, so an additional pass for
+    // counting is only done if a width is given.
+    let len = match form.width() {
+        Some(_) => write_dec_prec(f, prec, exp_mark, LenCounter(0))?.0,
+        None => 0,
+    };
+    pad_with(f.is_sign_negative(), len, form, |form| {
+        write_dec_prec(f, prec, exp_mark, form).map(|_| ())
+    })
+}
+
 #[inline]
 fn format_exact(
     f: &f256,
     prec: usize,
     form: &mut fmt::Formatter<'_>,
 ) -> fmt::Result {
-    assert!(
-        prec <= MAX_PREC,
-        "Maximum precision exceeded: {prec} > {MAX_PREC}."
-    );
-    let s = bin_2_dec_fixed_point(f.abs(), prec);
-    let start = s.starts_with('0') as usize;
-    form.pad_integral(f.is_sign_positive(), "", &s[start..])
+    format_dec_prec(f, prec, None, form)
 }
 
 #[inline]
@@ -80,10 +153,6 @@ fn format_shortest(f: &f256, form: &mut fmt::Formatter<'_>) -> fmt::Result {
 
 impl fmt::Display for f256 {
     /// Formats the value using the given formatter.
-    ///
-    /// Panics:
-    /// -------
-    /// * The given precision exceeds 75.
     fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.is_special() {
             format_special(self, form)
@@ -101,7 +170,14 @@ impl fmt::Debug for f256 {
     fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
         let (sign, exp, signif) = split_f256_enc(self);
         if exp == EXP_MAX as i32 {
-            fmt::Debug::fmt(&self.to_string(), form)
+            let s = if self.is_nan() {
+                "NaN"
+            } else if self.is_sign_negative() {
+                "-inf"
+            } else {
+                "inf"
+            };
+            fmt::Debug::fmt(s, form)
         } else {
             fmt::Debug::fmt(&(sign, exp, signif), form)
         }
@@ -114,13 +190,10 @@ fn format_scientific_special(
     form: &mut fmt::Formatter<'_>,
 ) -> fmt::Result {
     if f.eq_zero() {
-        let prec = min(form.precision().unwrap_or(0), MAX_PREC);
-        let s = match exp_mark {
-            'e' => format!("{:.*e}", prec, 0.),
-            'E' => format!("{:.*E}", prec, 0.),
-            _ => unreachable!(),
-        };
-        form.pad_integral(f.is_sign_positive(), "", s.as_str())
+        let prec = form.precision().unwrap_or(0);
+        zero_parts(prec, Some(exp_mark), |formatted| {
+            formatted.pad_parts(f.is_sign_negative(), form)
+        })
     } else if f.is_nan() {
         format_nan(form)
     } else {
@@ -151,13 +224,7 @@ fn format_scientific_exact(
     prec: usize,
     form: &mut fmt::Formatter<'_>,
 ) -> fmt::Result {
-    assert!(
-        prec <= MAX_PREC,
-        "Maximum precision for scientific format exceeded: {prec} > \
-         {MAX_PREC}."
-    );
-    let s = bin_2_dec_scientific(f.abs(), exp_mark, prec);
-    form.pad_integral(f.is_sign_positive(), "", &s)
+    format_dec_prec(f, prec, Some(exp_mark), form)
 }
 
 #[inline]
@@ -174,10 +241,6 @@ fn format_scientific_shortest(
 impl fmt::LowerExp for f256 {
     /// Formats the value using the given formatter in scientific notation
     /// with a lower-case `e`.
-    ///
-    /// Panics:
-    /// -------
-    /// * The given precision exceeds 75.
     fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
         format_scientific_common(self, 'e', form)
     }
@@ -186,10 +249,6 @@ impl fmt::LowerExp for f256 {
 impl fmt::UpperExp for f256 {
     /// Formats the value using the given formatter in scientific notation
     /// with a lower-case `E`.
-    ///
-    /// Panics:
-    /// -------
-    /// * The given precision exceeds 75.
     fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
         format_scientific_common(self, 'E', form)
     }
@@ -197,15 +256,91 @@ impl fmt::UpperExp for f256 {
 
 #[cfg(test)]
 mod display_tests {
+    use alloc::{borrow::ToOwned, format, string::ToString};
     use core::str::FromStr;
 
-    use super::*;
+    use super::{to_fixed_prec::bin_2_dec_fixed_point_exact, *};
 
     #[test]
-    #[should_panic]
-    fn test_max_prec_exceeded() {
+    fn test_fixed_prec_above_fast_max_prec() {
         let f = f256::ONE;
-        let _ = format!("{f:.76}");
+        let s = format!("{f:.100}");
+        assert_eq!(s.len(), 102);
+        assert_eq!(&s[..4], "1.00");
+        assert!(s[2..].bytes().all(|b| b == b'0'));
+        let f = f256::ONE / f256::from_u64(3);
+        assert_eq!(
+            format!("{f:.100}"),
+            "0.333333333333333333333333333333333333333333333333333333333\
+                   3333333333333325786934100977739693742400591"
+        );
+        let f = -f256::ONE / f256::from_u64(10);
+        assert_eq!(
+            format!("{f:>125.120}"),
+            "  -0.1000000000000000000000000000000000000000000000000000000\
+                   000000000000000002263919769706678091877279822721947945\
+                   17063279953"
+        );
+        let f = f256::ZERO;
+        assert_eq!(format!("{f:.80}"), format!("{:.80}", 0.));
+    }
+
+    #[test]
+    fn test_fixed_prec_above_fast_max_prec_ties() {
+        // 2⁻⁸⁰ has 80 fractional digits, the last one being a 5.
+        let f = f256::ONE.div_pow2(80);
+        assert_eq!(
+            format!("{f:.80}"),
+            "0.000000000000000000000000827180612553027674871408692069962\
+                   85356581211090087890625"
+        );
+        assert_eq!(
+            format!("{f:.79}"),
+            "0.000000000000000000000000827180612553027674871408692069962\
+                   8535658121109008789062"
+        );
+        let f = f * f256::from_u64(3);
+        assert_eq!(
+            format!("{f:.79}"),
+            "0.000000000000000000000002481541837659083024614226076209888\
+                   5606974363327026367188"
+        );
+    }
+
+    #[test]
+    fn test_fixed_prec_exact_min_gt_zero() {
+        // 2⁻²⁶²³⁷⁸ has 262378 fractional digits. Precisions above u16::MAX
+        // can't be passed via a formatter, so the conversion is called
+        // directly.
+        let f = f256::MIN_GT_ZERO;
+        let s = bin_2_dec_fixed_point_exact(f, 262378);
+        let s = &s[1..];
+        assert_eq!(s.len(), 262380);
+        assert!(s.ends_with("8354129791259765625"));
+        let g = f256::from_str(s).unwrap();
+        assert_eq!(f, g);
+        let t = bin_2_dec_fixed_point_exact(f, 262400);
+        let t = &t[1..];
+        assert_eq!(&t[..s.len()], s);
+        assert!(t[s.len()..].bytes().all(|b| b == b'0'));
+        let u = format!("{f:.65535}");
+        assert_eq!(u, s[..65537]);
+    }
+
+    #[test]
+    fn test_fixed_prec_carry() {
+        for x in [9.96_f64, -0.96, 99.5, 0.5, 1.5, -2.5, 999.999, 0.0096] {
+            let f = f256::from(x);
+            for prec in [0, 1, 2, 3] {
+                assert_eq!(format!("{f:.prec$}"), format!("{x:.prec$}"));
+                assert_eq!(format!("{f:>9.prec$}"), format!("{x:>9.prec$}"));
+                assert_eq!(
+                    format!("{f:+09.prec$}"),
+                    format!("{x:+09.prec$}")
+                );
+                assert_eq!(format!("{f:^9.prec$}"), format!("{x:^9.prec$}"));
+            }
+        }
     }
 
     #[test]
@@ -552,6 +687,8 @@ mod display_tests {
 
 #[cfg(test)]
 mod debug_tests {
+    use alloc::format;
+
     use super::*;
 
     #[test]
@@ -613,15 +750,56 @@ mod debug_tests {
 
 #[cfg(test)]
 mod format_exp_tests {
+    use alloc::format;
     use core::str::FromStr;
 
-    use super::*;
+    use super::{to_fixed_prec::bin_2_dec_scientific_exact, *};
 
     #[test]
-    #[should_panic]
-    fn test_max_prec_exceeded() {
-        let f = f256::ONE;
-        let _ = format!("{f:.83e}");
+    fn test_fixed_prec_above_fast_max_prec() {
+        let f = f256::ONE / f256::from_u64(3);
+        assert_eq!(
+            format!("{f:.90e}"),
+            "3.333333333333333333333333333333333333333333333333333333333\
+                   333333333333325786934100977739694e-1"
+        );
+        let f = f256::ONE.div_pow2(80);
+        assert_eq!(
+            format!("{f:.80E}"),
+            "8.271806125530276748714086920699628535658121109008789062500\
+                   00000000000000000000000E-25"
+        );
+        let f = -f256::ONE;
+        assert_eq!(format!("{f:.80e}"), format!("{:.80e}", -1.));
+        let f = f256::ZERO;
+        assert_eq!(format!("{f:.80e}"), format!("{:.80e}", 0.));
+    }
+
+    #[test]
+    fn test_fixed_prec_exact_f256_max() {
+        let f = f256::MAX;
+        let s = bin_2_dec_scientific_exact(f, 'e', 78913);
+        assert!(s.starts_with("1.6113257174857604736195721184520050106440"));
+        assert!(s.ends_with("1252160996246028288e78913"));
+        assert_eq!(s.len(), 78921);
+    }
+
+    #[test]
+    fn test_fixed_prec_carry() {
+        for x in [9.96_f64, -0.96, 99.5, 0.5, 1.5, -2.5, 999.999, 0.0096] {
+            let f = f256::from(x);
+            for prec in [0, 1, 2, 3] {
+                assert_eq!(format!("{f:.prec$e}"), format!("{x:.prec$e}"));
+                assert_eq!(
+                    format!("{f:>9.prec$E}"),
+                    format!("{x:>9.prec$E}")
+                );
+                assert_eq!(
+                    format!("{f:+09.prec$e}"),
+                    format!("{x:+09.prec$e}")
+                );
+            }
+        }
     }
 
     #[test]
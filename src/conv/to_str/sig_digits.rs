@@ -0,0 +1,235 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use alloc::string::{String, ToString};
+use core::{cmp::max, fmt};
+
+use super::{
+    format_nan,
+    to_fixed_prec::{bin_2_dec_scientific, bin_2_dec_scientific_exact},
+    MAX_PREC,
+};
+use crate::f256;
+
+/// Smallest decimal exponent for which fixed-point notation is used.
+const MIN_FIXED_EXP: i32 = -4;
+
+/// Wrapper around an `f256` which formats the value with a given number of
+/// significant digits, like the conversion `%g` in C.
+///
+/// Returned by [`f256::format_sig`].
+///
+/// With n significant digits and x being the decimal exponent of the value
+/// rounded to n significant digits, the value is formatted in fixed-point
+/// notation if -4 ≤ x < n, otherwise in scientific notation. Trailing zeroes
+/// of the fractional part are removed, unless the alternate flag (`{:#}`)
+/// is given. Width, fill, alignment and sign flags are applied as for
+/// [`f256`].
+#[derive(Clone, Copy, Debug)]
+pub struct SigDigits {
+    value: f256,
+    n_digits: usize,
+}
+
+/// Returns the significant digits and the decimal exponent of the positive
+/// finite non-zero binary float `f`, rounded to `n_digits` significant
+/// digits.
+fn sig_digits_and_exp(f: f256, n_digits: usize) -> (String, i32) {
+    let prec = n_digits - 1;
+    let s = if prec <= MAX_PREC {
+        bin_2_dec_scientific(f, 'e', prec)
+    } else {
+        bin_2_dec_scientific_exact(f, 'e', prec)
+    };
+    let (mant, exp) = s.split_once('e').unwrap();
+    let digits = mant.chars().filter(|c| *c != '.').collect();
+    (digits, exp.parse().unwrap())
+}
+
+impl fmt::Display for SigDigits {
+    #[allow(clippy::cast_sign_loss)]
+    fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let f = self.value;
+        if f.is_nan() {
+            return format_nan(form);
+        }
+        if f.is_infinite() {
+            return form.pad_integral(f.is_sign_positive(), "", "inf");
+        }
+        let n_digits = max(self.n_digits, 1);
+        let (digits, exp) = if f.eq_zero() {
+            ("0".repeat(n_digits), 0)
+        } else {
+            sig_digits_and_exp(f.abs(), n_digits)
+        };
+        let fixed_point =
+            exp >= MIN_FIXED_EXP && (exp < 0 || (exp as usize) < n_digits);
+        let mut res = String::with_capacity(n_digits + 12);
+        let frac = if fixed_point {
+            if exp >= 0 {
+                let (int_part, frac) = digits.split_at(exp as usize + 1);
+                res.push_str(int_part);
+                String::from(frac)
+            } else {
+                res.push('0');
+                let mut frac = "0".repeat((-exp - 1) as usize);
+                frac.push_str(&digits);
+                frac
+            }
+        } else {
+            res.push_str(&digits[..1]);
+            String::from(&digits[1..])
+        };
+        let frac = if form.alternate() {
+            frac.as_str()
+        } else {
+            frac.trim_end_matches('0')
+        };
+        if !frac.is_empty() {
+            res.push('.');
+            res.push_str(frac);
+        }
+        if !fixed_point {
+            res.push('e');
+            res.push_str(exp.to_string().as_str());
+        }
+        form.pad_integral(f.is_sign_positive(), "", &res)
+    }
+}
+
+impl f256 {
+    /// Returns a wrapper which formats `self` with `n_digits` significant
+    /// digits, automatically choosing fixed-point or scientific notation
+    /// like the conversion `%.<n_digits>g` in C.
+    ///
+    /// The value is rounded to `n_digits` significant digits (tie to even),
+    /// `n_digits` = 0 is treated as 1. Trailing zeroes of the fractional
+    /// part are removed, unless the alternate flag (`{:#}`) is given. See
+    /// [`SigDigits`] for details.
+    ///
+    /// Only available with feature `alloc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f256::f256;
+    /// # use core::str::FromStr;
+    /// let f = f256::ONE / f256::from(3);
+    /// assert_eq!(f.format_sig(10).to_string(), "0.3333333333");
+    /// let f = f256::from(1234567);
+    /// assert_eq!(f.format_sig(3).to_string(), "1.23e6");
+    /// let f = f256::from(100);
+    /// assert_eq!(f.format_sig(5).to_string(), "100");
+    /// assert_eq!(format!("{:#}", f.format_sig(5)), "100.00");
+    /// let f = f256::from_str("-0.0000123").unwrap();
+    /// assert_eq!(f.format_sig(3).to_string(), "-1.23e-5");
+    /// assert_eq!(format!("{:>10}", (f * f256::TEN).format_sig(3)),
+    ///            " -0.000123");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn format_sig(&self, n_digits: usize) -> SigDigits {
+        SigDigits {
+            value: *self,
+            n_digits,
+        }
+    }
+}
+
+#[cfg(test)]
+mod format_sig_tests {
+    use alloc::{format, string::ToString};
+    use core::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_fixed_point() {
+        let f = f256::from(1234567);
+        assert_eq!(f.format_sig(7).to_string(), "1234567");
+        assert_eq!(f.format_sig(9).to_string(), "1234567");
+        assert_eq!(format!("{:#}", f.format_sig(9)), "1234567.00");
+        let f = f256::from_str("0.000123456").unwrap();
+        assert_eq!(f.format_sig(4).to_string(), "0.0001235");
+        assert_eq!(f.format_sig(8).to_string(), "0.000123456");
+        assert_eq!(format!("{:#}", f.format_sig(8)), "0.00012345600");
+        let f = f256::from_str("-2.5").unwrap();
+        assert_eq!(f.format_sig(1).to_string(), "-2");
+        assert_eq!(f.format_sig(0).to_string(), "-2");
+        assert_eq!(f.format_sig(2).to_string(), "-2.5");
+    }
+
+    #[test]
+    fn test_scientific() {
+        let f = f256::from(1234567);
+        assert_eq!(f.format_sig(6).to_string(), "1.23457e6");
+        assert_eq!(f.format_sig(1).to_string(), "1e6");
+        let f = f256::from(1000000);
+        assert_eq!(f.format_sig(3).to_string(), "1e6");
+        assert_eq!(format!("{:#}", f.format_sig(3)), "1.00e6");
+        let f = f256::from_str("0.0000123456").unwrap();
+        assert_eq!(f.format_sig(4).to_string(), "1.235e-5");
+        assert_eq!(
+            f256::MIN_GT_ZERO.format_sig(7).to_string(),
+            "2.248007e-78984"
+        );
+        assert_eq!(
+            f256::MAX.format_sig(40).to_string(),
+            "1.611325717485760473619572118452005010644e78913"
+        );
+    }
+
+    #[test]
+    fn test_rounding_changes_exponent() {
+        // 99999.5 rounded to 5 digits is 1.0000e5 => scientific
+        let f = f256::from_str("99999.5").unwrap();
+        assert_eq!(f.format_sig(5).to_string(), "1e5");
+        assert_eq!(f.format_sig(6).to_string(), "99999.5");
+        // 0.000099996 rounded to 4 digits is 1.000e-4 => fixed-point
+        let f = f256::from_str("0.000099996").unwrap();
+        assert_eq!(f.format_sig(4).to_string(), "0.0001");
+        assert_eq!(format!("{:#}", f.format_sig(4)), "0.0001000");
+        assert_eq!(f.format_sig(5).to_string(), "9.9996e-5");
+    }
+
+    #[test]
+    fn test_many_digits() {
+        let f = f256::ONE / f256::from(3);
+        assert_eq!(
+            f.format_sig(80).to_string(),
+            "0.333333333333333333333333333333333333333333333333333333333\
+             33333333333333257869341"
+        );
+        let f = f256::from(5);
+        assert_eq!(f.format_sig(100).to_string(), "5");
+        assert_eq!(
+            format!("{:#}", f.format_sig(100)),
+            format!("5.{}", "0".repeat(99))
+        );
+    }
+
+    #[test]
+    fn test_special() {
+        assert_eq!(f256::ZERO.format_sig(3).to_string(), "0");
+        assert_eq!(f256::NEG_ZERO.format_sig(3).to_string(), "-0");
+        assert_eq!(format!("{:#}", f256::ZERO.format_sig(3)), "0.00");
+        assert_eq!(f256::INFINITY.format_sig(3).to_string(), "inf");
+        assert_eq!(f256::NEG_INFINITY.format_sig(3).to_string(), "-inf");
+        assert_eq!(f256::NAN.format_sig(3).to_string(), "NaN");
+        assert_eq!(format!("{:>5}", f256::NAN.format_sig(3)), "  NaN");
+    }
+
+    #[test]
+    fn test_padding() {
+        let f = f256::from_str("1.5").unwrap();
+        assert_eq!(format!("{:>6}", f.format_sig(3)), "   1.5");
+        assert_eq!(format!("{:<+6}", f.format_sig(3)), "+1.5  ");
+        assert_eq!(format!("{:06}", (-f).format_sig(3)), "-001.5");
+    }
+}
@@ -0,0 +1,284 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use core::{
+    fmt::{self, Write},
+    str,
+};
+
+use super::{
+    dec_repr::DecNumRepr,
+    formatted::SliceWriter,
+    to_fixed_prec::{
+        write_dec_fixed_point, write_dec_fixed_point_exact,
+        write_dec_scientific, write_dec_scientific_exact,
+    },
+    zero_parts, MAX_PREC,
+};
+use crate::f256;
+
+/// Writes the decimal representation of `f` into `buf`, with `prec`
+/// fractional digits or the shortest representation which round-trips if
+/// `prec` is None, in scientific notation if an exponent mark is given.
+fn write_to_buf<'a>(
+    f: &f256,
+    prec: Option<usize>,
+    exp_mark: Option<char>,
+    buf: &'a mut [u8],
+) -> Result<&'a str, fmt::Error> {
+    if f.is_nan() {
+        let mut w = SliceWriter::new(buf);
+        w.write_str("NaN")?;
+        return Ok(w.into_str());
+    }
+    // The sign is written separately, so that the number can be rounded in
+    // place.
+    let sign_len = f.is_sign_negative() as usize;
+    if sign_len > buf.len() {
+        return Err(fmt::Error);
+    }
+    if sign_len > 0 {
+        buf[0] = b'-';
+    }
+    let mut w = SliceWriter::new(&mut buf[sign_len..]);
+    if f.is_infinite() {
+        w.write_str("inf")?;
+    } else if f.eq_zero() {
+        zero_parts(prec.unwrap_or(0), exp_mark, |formatted| {
+            formatted.write(&mut w)
+        })?;
+    } else {
+        let abs = f.abs();
+        match (prec, exp_mark) {
+            (None, None) => DecNumRepr::shortest_from_f256(&abs)
+                .with_fixed_point_parts(|formatted| {
+                    formatted.write(&mut w)
+                })?,
+            (None, Some(exp_mark)) => DecNumRepr::shortest_from_f256(&abs)
+                .with_scientific_parts(exp_mark, |formatted| {
+                    formatted.write(&mut w)
+                })?,
+            (Some(prec), None) => {
+                if prec <= MAX_PREC {
+                    write_dec_fixed_point(abs, prec, &mut w)?;
+                } else {
+                    write_dec_fixed_point_exact(abs, prec, &mut w)?;
+                }
+            }
+            (Some(prec), Some(exp_mark)) => {
+                if prec <= MAX_PREC {
+                    write_dec_scientific(abs, exp_mark, prec, &mut w)?;
+                } else {
+                    write_dec_scientific_exact(abs, exp_mark, prec, &mut w)?;
+                }
+            }
+        }
+    }
+    let len = sign_len + w.len();
+    // Only complete str slices have been written into the buffer.
+    str::from_utf8(&buf[..len]).map_err(|_| fmt::Error)
+}
+
+impl f256 {
+    /// Writes the shortest decimal representation of `self` which
+    /// round-trips into `buf` and returns it as string slice, without
+    /// allocating.
+    ///
+    /// The result is the same as the one of `format!("{}", self)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `fmt::Error` if `buf` is too small to hold the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f256::f256;
+    /// let mut buf = [0_u8; 80];
+    /// let f = f256::from(-1.5_f64);
+    /// assert_eq!(f.write_shortest(&mut buf), Ok("-1.5"));
+    /// let f = f256::from(1e50_f64);
+    /// assert!(f.write_shortest(&mut buf[..50]).is_err());
+    /// ```
+    pub fn write_shortest<'a>(
+        &self,
+        buf: &'a mut [u8],
+    ) -> Result<&'a str, fmt::Error> {
+        write_to_buf(self, None, None, buf)
+    }
+
+    /// Writes the decimal representation of `self` with `prec` fractional
+    /// digits (rounded tie to even) into `buf` and returns it as string
+    /// slice, without allocating.
+    ///
+    /// The result is the same as the one of `format!("{:.*}", prec, self)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `fmt::Error` if `buf` is too small to hold the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f256::f256;
+    /// let mut buf = [0_u8; 80];
+    /// let f = f256::ONE / f256::from(3);
+    /// assert_eq!(f.write_fixed(5, &mut buf), Ok("0.33333"));
+    /// let f = f256::from(-9.99_f64);
+    /// assert_eq!(f.write_fixed(1, &mut buf), Ok("-10.0"));
+    /// assert!(f.write_fixed(100, &mut buf).is_err());
+    /// ```
+    pub fn write_fixed<'a>(
+        &self,
+        prec: usize,
+        buf: &'a mut [u8],
+    ) -> Result<&'a str, fmt::Error> {
+        write_to_buf(self, Some(prec), None, buf)
+    }
+
+    /// Writes the shortest decimal representation of `self` which
+    /// round-trips in scientific notation into `buf` and returns it as
+    /// string slice, without allocating.
+    ///
+    /// The result is the same as the one of `format!("{:e}", self)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `fmt::Error` if `buf` is too small to hold the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f256::f256;
+    /// let mut buf = [0_u8; 80];
+    /// let f = f256::from(-1234.5_f64);
+    /// assert_eq!(f.write_shortest_exp(&mut buf), Ok("-1.2345e3"));
+    /// assert_eq!(f256::MIN_GT_ZERO.write_shortest_exp(&mut buf),
+    ///            Ok("2e-78984"));
+    /// ```
+    pub fn write_shortest_exp<'a>(
+        &self,
+        buf: &'a mut [u8],
+    ) -> Result<&'a str, fmt::Error> {
+        write_to_buf(self, None, Some('e'), buf)
+    }
+
+    /// Writes the decimal representation of `self` in scientific notation
+    /// with `prec` fractional digits (rounded tie to even) into `buf` and
+    /// returns it as string slice, without allocating.
+    ///
+    /// The result is the same as the one of `format!("{:.*e}", prec, self)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `fmt::Error` if `buf` is too small to hold the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f256::f256;
+    /// let mut buf = [0_u8; 80];
+    /// let f = f256::from(-1234.5_f64);
+    /// assert_eq!(f.write_fixed_exp(2, &mut buf), Ok("-1.23e3"));
+    /// assert_eq!(f256::ZERO.write_fixed_exp(1, &mut buf), Ok("0.0e0"));
+    /// ```
+    pub fn write_fixed_exp<'a>(
+        &self,
+        prec: usize,
+        buf: &'a mut [u8],
+    ) -> Result<&'a str, fmt::Error> {
+        write_to_buf(self, Some(prec), Some('e'), buf)
+    }
+}
+
+#[cfg(test)]
+mod write_to_buf_tests {
+    use alloc::format;
+    use core::str::FromStr;
+
+    use super::*;
+
+    fn test_values() -> [f256; 12] {
+        [
+            f256::ONE,
+            f256::from(-1234.5_f64),
+            f256::ONE / f256::from(3),
+            -f256::TWO / f256::from(3),
+            f256::from_str("9.9999e-5").unwrap(),
+            f256::from(1e100_f64),
+            f256::EPSILON,
+            f256::MIN_POSITIVE,
+            f256::ZERO,
+            f256::NEG_ZERO,
+            f256::INFINITY,
+            f256::NEG_INFINITY,
+        ]
+    }
+
+    #[test]
+    fn test_same_as_format() {
+        let mut buf = [0_u8; 400];
+        for f in test_values() {
+            assert_eq!(f.write_shortest(&mut buf).unwrap(), format!("{f}"));
+            assert_eq!(
+                f.write_shortest_exp(&mut buf).unwrap(),
+                format!("{f:e}")
+            );
+            for prec in [0, 1, 7, 75, 76, 120] {
+                assert_eq!(
+                    f.write_fixed(prec, &mut buf).unwrap(),
+                    format!("{f:.prec$}")
+                );
+                assert_eq!(
+                    f.write_fixed_exp(prec, &mut buf).unwrap(),
+                    format!("{f:.prec$e}")
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_nan() {
+        let mut buf = [0_u8; 3];
+        assert_eq!(f256::NAN.write_shortest(&mut buf), Ok("NaN"));
+        assert_eq!((-f256::NAN).write_fixed(5, &mut buf), Ok("NaN"));
+        assert_eq!(f256::NAN.write_shortest_exp(&mut buf), Ok("NaN"));
+        assert_eq!(f256::NAN.write_fixed_exp(5, &mut buf), Ok("NaN"));
+    }
+
+    #[test]
+    fn test_buf_too_small() {
+        let f = f256::from(-1234.5_f64);
+        let mut buf = [0_u8; 7];
+        assert_eq!(f.write_shortest(&mut buf), Ok("-1234.5"));
+        assert_eq!(f.write_shortest(&mut buf[..6]), Err(fmt::Error));
+        assert_eq!(f.write_fixed(0, &mut buf), Ok("-1234"));
+        assert_eq!(f.write_fixed(2, &mut buf), Err(fmt::Error));
+        assert_eq!(f.write_shortest_exp(&mut buf), Err(fmt::Error));
+        assert_eq!(f.write_fixed_exp(1, &mut buf), Ok("-1.2e3"));
+        assert_eq!(f.write_fixed_exp(80, &mut buf), Err(fmt::Error));
+        assert_eq!(
+            f256::NEG_INFINITY.write_shortest(&mut buf[..3]),
+            Err(fmt::Error)
+        );
+        let mut buf = [0_u8; 0];
+        assert_eq!(f256::ZERO.write_shortest(&mut buf), Err(fmt::Error));
+    }
+
+    #[test]
+    fn test_carry() {
+        // No char is preserved for a carry, so the buffer only has to hold
+        // the actual result.
+        let mut buf = [0_u8; 4];
+        let f = f256::from(9.96_f64);
+        assert_eq!(f.write_fixed(1, &mut buf), Ok("10.0"));
+        let f = f256::from(-0.5_f64);
+        assert_eq!(f.write_fixed(1, &mut buf), Ok("-0.5"));
+    }
+}
@@ -7,13 +7,11 @@
 // $Source$
 // $Revision$
 
-use alloc::{
-    format,
-    string::{String, ToString},
-};
+#[cfg(any(feature = "alloc", test))]
+use alloc::string::String;
 use core::{
     cmp::{max, min},
-    fmt,
+    fmt::{self, Write},
     mem::MaybeUninit,
     ops::{AddAssign, Rem},
 };
@@ -29,14 +27,17 @@ use f256_pow2_div_pow10_lut::{
 use super::{
     common::{floor_log10_pow2, floor_log10f},
     dec_repr::DecNumRepr,
-    formatted::{Formatted, Part},
+    formatted::{DecBuf, Formatted, Part, SliceWriter},
     powers_of_five::{get_power_of_five, is_multiple_of_pow5},
 };
 use crate::{
-    big_uint::rounding_div_pow10, f256, BigUInt, DivRem, HiLo, EMAX, EMIN,
-    FRACTION_BITS, SIGNIFICAND_BITS, U256, U512,
+    big_uint::rounding_div_pow10, conv::big_decimal::Decimal, f256, BigUInt,
+    DivRem, HiLo, EMAX, EMIN, FRACTION_BITS, SIGNIFICAND_BITS, U256, U512,
 };
 
+/// Maximum number of decimal digits of a U512 = ⌈log₁₀(2⁵¹²)⌉.
+const MAX_N_DIGITS_U512: usize = 155;
+
 #[derive(PartialEq)]
 enum Round {
     Up,
@@ -77,12 +78,12 @@ fn pow10_div_pow2(segment_idx: usize, chunk_idx: usize) -> U512 {
 #[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::cast_possible_wrap)]
 #[allow(clippy::cast_sign_loss)]
-fn bin_fract_2_dec_str(
+fn bin_fract_2_dec_str<B: DecBuf>(
     signif2: U256,
     exp2: i32,
     prec: usize,
-    buf: &mut String,
-) -> Round {
+    buf: &mut B,
+) -> Result<Round, fmt::Error> {
     let mut round = Round::Down;
     let n_chunks = prec as u32 / CHUNK_SIZE + 1;
     let (segment_idx, (n_zero_chunks, segment_shift)) =
@@ -91,13 +92,11 @@ fn bin_fract_2_dec_str(
     let shift = (segment_shift - exp2) as u32;
     // Special case: no significant digits to be output
     if n_chunks <= n_zero_chunks {
-        buf.push_str("0".repeat(prec).as_str());
-        return round;
+        buf.write_zeroes(prec)?;
+        return Ok(round);
     }
     if n_zero_chunks > 0 {
-        buf.push_str(
-            "0".repeat((n_zero_chunks * CHUNK_SIZE) as usize).as_str(),
-        );
+        buf.write_zeroes((n_zero_chunks * CHUNK_SIZE) as usize)?;
     }
     let n_signif_chunks = n_chunks - n_zero_chunks;
     debug_assert!(
@@ -108,9 +107,7 @@ fn bin_fract_2_dec_str(
         let t = pow10_div_pow2(segment_idx, chunk_idx as usize);
         let mut chunk = mul_shift_mod(&signif2, &t, shift);
         if chunk_idx < n_signif_chunks - 1 {
-            buf.push_str(
-                format!("{:01$}", chunk, CHUNK_SIZE as usize).as_str(),
-            );
+            write!(buf, "{:01$}", chunk, CHUNK_SIZE as usize)?;
         } else {
             // last chunk
             let n_digits = prec as u32 - (n_chunks - 1) * CHUNK_SIZE;
@@ -137,44 +134,36 @@ fn bin_fract_2_dec_str(
                 };
             }
             if n_digits > 0 {
-                buf.push_str(
-                    format!("{:01$}", chunk, n_digits as usize).as_str(),
-                );
-            }
-        }
-    }
-    round
-}
-
-/// Round-Up the given fixed-point string representation of a decimal number.
-#[allow(unsafe_code)]
-#[inline]
-fn round_up_fixed_point_inplace(num: &mut str) {
-    let mut idx = num.len() - 1;
-    // SAFETY: see comment at str::as_bytes_mut.
-    unsafe {
-        let bytes = num.as_bytes_mut();
-        loop {
-            if bytes[idx] == b'9' {
-                bytes[idx] = b'0';
-                idx -= 1;
-            } else if bytes[idx] == b'.' {
-                idx -= 1;
-            } else {
-                bytes[idx] += 1;
-                break;
+                write!(buf, "{:01$}", chunk, n_digits as usize)?;
             }
         }
     }
+    Ok(round)
 }
 
 /// Converts a positive finite binary float into a string representing a
 /// decimal number dₘ⋯d₀.d₋₁⋯d₋ₚ where d ∈ [0..9] and p is the given number of
 /// fractional digits.
 /// The result may have an additional leading zero!
+#[cfg(any(feature = "alloc", test))]
+pub(crate) fn bin_2_dec_fixed_point(f: f256, prec: usize) -> String {
+    // Preserve one char for carry
+    let mut res = String::from("0");
+    // Writing to a String can't fail.
+    write_dec_fixed_point(f, prec, &mut res).unwrap();
+    res
+}
+
+/// Writes the decimal representation of a positive finite binary float
+/// with the given number of fractional digits into `res` (see
+/// [`bin_2_dec_fixed_point`]), prepending a one in case of a carry.
 #[allow(clippy::cast_possible_wrap)]
 #[allow(clippy::cast_sign_loss)]
-pub(crate) fn bin_2_dec_fixed_point(f: f256, prec: usize) -> String {
+pub(crate) fn write_dec_fixed_point<B: DecBuf>(
+    f: f256,
+    prec: usize,
+    res: &mut B,
+) -> fmt::Result {
     debug_assert!(f.is_finite());
     debug_assert!(f.is_sign_positive());
     let mut exp2 = f.quantum_exponent();
@@ -187,27 +176,19 @@ pub(crate) fn bin_2_dec_fixed_point(f: f256, prec: usize) -> String {
     } else {
         (false, false, f.split())
     };
-    let buf_len = if is_less_than_one {
-        3 + prec
-    } else {
-        floor_log10_pow2(exp2 + SIGNIFICAND_BITS as i32) as usize + 3 + prec
-    };
-    let mut res = String::with_capacity(buf_len);
-    // Preserve one char for carry
-    res.push('0');
     // Integer part
     if is_less_than_one {
-        res.push('0');
+        res.write_char('0')?;
     } else {
-        res.push_str(ip.to_string().as_str());
+        write!(res, "{ip}")?;
     }
     // Fractional part
     if prec > 0 {
-        res.push('.');
+        res.write_char('.')?;
     }
     let mut round = Round::Down;
     if is_int {
-        res.push_str("0".repeat(prec).as_str());
+        res.write_zeroes(prec)?;
     } else {
         exp2 = fp.quantum_exponent();
         signif2 = fp.integral_significand();
@@ -217,33 +198,32 @@ pub(crate) fn bin_2_dec_fixed_point(f: f256, prec: usize) -> String {
             signif2 <<= adj;
             exp2 -= adj as i32;
         }
-        round = bin_fract_2_dec_str(signif2, exp2, prec, &mut res);
+        round = bin_fract_2_dec_str(signif2, exp2, prec, res)?;
     }
     if round == Round::Up
-        || (round == Round::ToEven
-            && res.ends_with(['1', '3', '5', '7', '9']))
+        || (round == Round::ToEven && res.ends_with_odd_digit())
     {
-        round_up_fixed_point_inplace(&mut res);
+        res.round_up_fixed_point()?;
     }
-    res
+    Ok(())
 }
 
 #[inline]
-fn split_into_buf(buf: &mut String, s: &str) {
-    buf.push_str(&s[..1]);
-    buf.push('.');
-    buf.push_str(&s[1..]);
+fn split_into_buf<B: DecBuf>(buf: &mut B, s: &str) -> fmt::Result {
+    buf.write_str(&s[..1])?;
+    buf.write_char('.')?;
+    buf.write_str(&s[1..])
 }
 
 #[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::cast_possible_wrap)]
 #[allow(clippy::cast_sign_loss)]
-fn bin_small_float_2_scientific(
+fn bin_small_float_2_scientific<B: DecBuf>(
     signif2: U256,
     exp2: i32,
     prec: usize,
-    buf: &mut String,
-) -> (Round, i32) {
+    buf: &mut B,
+) -> Result<(Round, i32), fmt::Error> {
     debug_assert!(exp2 > -(SIGNIFICAND_BITS as i32) && exp2 < 0);
     let mut exp10 = floor_log10f(signif2, exp2);
     // Need to calculate the prec+1 left-most decimal digits of the number.
@@ -281,29 +261,32 @@ fn bin_small_float_2_scientific(
         let mut t = signif2.rounding_div(&d);
         U512::from_hi_lo(U256::ZERO, t)
     };
-    let mut s = signif10.to_string();
+    let mut digits = [0_u8; MAX_N_DIGITS_U512];
+    let mut w = SliceWriter::new(&mut digits);
+    write!(w, "{signif10}")?;
+    let mut s = w.into_str();
     if s.len() > prec + 1 {
         // rounding overflow
-        s = s[..s.len() - 1].to_string();
+        s = &s[..s.len() - 1];
         exp10 += 1;
     }
     if prec == 0 {
-        buf.push_str(&s);
+        buf.write_str(s)?;
     } else {
-        split_into_buf(buf, &s);
+        split_into_buf(buf, s)?;
     }
-    (Round::Down, exp10)
+    Ok((Round::Down, exp10))
 }
 
 #[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::cast_possible_wrap)]
 #[allow(clippy::cast_sign_loss)]
-fn bin_small_int_2_scientific(
+fn bin_small_int_2_scientific<B: DecBuf>(
     signif2: U256,
     exp2: i32,
     prec: usize,
-    buf: &mut String,
-) -> (Round, i32) {
+    buf: &mut B,
+) -> Result<(Round, i32), fmt::Error> {
     debug_assert!(
         exp2 >= 0 && exp2 <= (U512::BITS - SIGNIFICAND_BITS) as i32
     );
@@ -326,30 +309,33 @@ fn bin_small_int_2_scientific(
         let (lo, hi) = signif2.widening_mul(&t);
         U512::from_hi_lo(hi, lo)
     };
-    let mut s = signif10.to_string();
+    let mut digits = [0_u8; MAX_N_DIGITS_U512];
+    let mut w = SliceWriter::new(&mut digits);
+    write!(w, "{signif10}")?;
+    let mut s = w.into_str();
     if s.len() > prec + 1 {
         // rounding overflow
-        s = s[..s.len() - 1].to_string();
+        s = &s[..s.len() - 1];
         exp10 += 1;
     }
     if prec == 0 {
-        buf.push_str(&s);
+        buf.write_str(s)?;
     } else {
-        split_into_buf(buf, &s);
+        split_into_buf(buf, s)?;
     }
-    (Round::Down, exp10)
+    Ok((Round::Down, exp10))
 }
 
 #[allow(clippy::integer_division)]
 #[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::cast_possible_wrap)]
 #[allow(clippy::cast_sign_loss)]
-fn bin_large_int_2_scientific(
+fn bin_large_int_2_scientific<B: DecBuf>(
     signif2: U256,
     exp2: i32,
     prec: usize,
-    buf: &mut String,
-) -> (Round, i32) {
+    buf: &mut B,
+) -> Result<(Round, i32), fmt::Error> {
     debug_assert!(exp2 > (U512::BITS - SIGNIFICAND_BITS) as i32);
     let mut round = Round::Down;
     let (segment_idx, (mut n_chunks, segment_shift)) =
@@ -373,19 +359,22 @@ fn bin_large_int_2_scientific(
         // First chunk is last chunk.
         let d = 10_u64.pow(n_digits);
         let i = chunk / d;
-        buf.push_str(i.to_string().as_str());
+        write!(buf, "{i}")?;
         if n_rem_digits > 0 {
-            buf.push('.');
+            buf.write_char('.')?;
         }
         chunk %= d;
         chunk_size = n_digits;
         n_digits = min(chunk_size, n_rem_digits);
     } else {
-        let s = chunk.to_string();
+        let mut digits = [0_u8; 20];
+        let mut w = SliceWriter::new(&mut digits);
+        write!(w, "{chunk}")?;
+        let s = w.into_str();
         if prec > 0 {
-            split_into_buf(buf, s.as_str());
+            split_into_buf(buf, s)?;
         } else {
-            buf.push_str(s.as_str());
+            buf.write_str(s)?;
         }
         n_rem_digits -= n_digits;
         chunk_idx += 1;
@@ -400,7 +389,7 @@ fn bin_large_int_2_scientific(
     }
     // Full chunks
     while n_digits == chunk_size {
-        buf.push_str(format!("{:01$}", chunk, n_digits as usize).as_str());
+        write!(buf, "{:01$}", chunk, n_digits as usize)?;
         n_rem_digits -= n_digits;
         chunk_idx += 1;
         assert!(
@@ -435,11 +424,9 @@ fn bin_large_int_2_scientific(
         };
     }
     if n_digits > 0 {
-        buf.push_str(
-            format!("{:01$}", chunk, n_rem_digits as usize).as_str(),
-        );
+        write!(buf, "{:01$}", chunk, n_rem_digits as usize)?;
     }
-    (round, exp10 as i32)
+    Ok((round, exp10 as i32))
 }
 
 #[allow(clippy::cognitive_complexity)]
@@ -447,12 +434,12 @@ fn bin_large_int_2_scientific(
 #[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::cast_possible_wrap)]
 #[allow(clippy::cast_sign_loss)]
-fn bin_fract_2_scientific(
+fn bin_fract_2_scientific<B: DecBuf>(
     signif2: U256,
     exp2: i32,
     prec: usize,
-    buf: &mut String,
-) -> (Round, i32) {
+    buf: &mut B,
+) -> Result<(Round, i32), fmt::Error> {
     debug_assert!(exp2 <= -(SIGNIFICAND_BITS as i32));
     let mut round = Round::Down;
     let (segment_idx, (n_zero_chunks, segment_shift)) =
@@ -480,19 +467,22 @@ fn bin_fract_2_scientific(
         // First chunk is last chunk.
         let d = 10_u64.pow(n_digits);
         let i = chunk / d;
-        buf.push_str(i.to_string().as_str());
+        write!(buf, "{i}")?;
         if n_rem_digits > 0 {
-            buf.push('.');
+            buf.write_char('.')?;
         }
         chunk %= d;
         chunk_size = n_digits;
         n_digits = min(chunk_size, n_rem_digits);
     } else {
-        let s = chunk.to_string();
+        let mut digits = [0_u8; 20];
+        let mut w = SliceWriter::new(&mut digits);
+        write!(w, "{chunk}")?;
+        let s = w.into_str();
         if prec > 0 {
-            split_into_buf(buf, s.as_str());
+            split_into_buf(buf, s)?;
         } else {
-            buf.push_str(s.as_str());
+            buf.write_str(s)?;
         }
         n_rem_digits -= n_digits;
         chunk_idx += 1;
@@ -507,7 +497,7 @@ fn bin_fract_2_scientific(
     }
     // Full chunks
     while n_digits == chunk_size {
-        buf.push_str(format!("{:01$}", chunk, n_digits as usize).as_str());
+        write!(buf, "{:01$}", chunk, n_digits as usize)?;
         n_rem_digits -= n_digits;
         chunk_idx += 1;
         debug_assert!(
@@ -540,52 +530,37 @@ fn bin_fract_2_scientific(
             };
     }
     if n_digits > 0 {
-        buf.push_str(
-            format!("{:01$}", chunk, n_rem_digits as usize).as_str(),
-        );
-    }
-    (round, exp10)
-}
-
-/// Round-Up the given scientific string representation of a decimal number.
-#[allow(unsafe_code)]
-#[inline]
-fn round_up_scientific_inplace(num: &mut str) -> i32 {
-    let mut carry = 0_i32;
-    let mut idx = num.len() - 1;
-    // SAFETY: see comment at str::as_bytes_mut.
-    unsafe {
-        let bytes = num.as_bytes_mut();
-        loop {
-            // First digit
-            if idx == 0 && bytes[idx] == b'9' {
-                bytes[idx] = b'1';
-                carry = 1;
-                break;
-            } else if bytes[idx] == b'9' {
-                bytes[idx] = b'0';
-                idx -= 1;
-            } else if bytes[idx] == b'.' {
-                idx -= 1;
-            } else {
-                bytes[idx] += 1;
-                break;
-            }
-        }
+        write!(buf, "{:01$}", chunk, n_rem_digits as usize)?;
     }
-    carry
+    Ok((round, exp10))
 }
 
 /// Converts a positive finite binary float into a string representing a
 /// decimal number d₀.d₋₁⋯d₋ₚEe where d ∈ [0..9], e ∈ [-78912..78913], E is
 /// the given exponent marker and p is the given number of fractional digits.
-#[allow(clippy::cast_possible_wrap)]
-#[allow(clippy::cast_sign_loss)]
+#[cfg(any(feature = "alloc", test))]
 pub(crate) fn bin_2_dec_scientific(
     f: f256,
     exp_mark: char,
     prec: usize,
 ) -> String {
+    let mut res = String::with_capacity(prec + 9);
+    // Writing to a String can't fail.
+    write_dec_scientific(f, exp_mark, prec, &mut res).unwrap();
+    res
+}
+
+/// Writes the decimal representation of a positive finite binary float in
+/// scientific notation with the given number of fractional digits into
+/// `res` (see [`bin_2_dec_scientific`]).
+#[allow(clippy::cast_possible_wrap)]
+#[allow(clippy::cast_sign_loss)]
+pub(crate) fn write_dec_scientific<B: DecBuf>(
+    f: f256,
+    exp_mark: char,
+    prec: usize,
+    res: &mut B,
+) -> fmt::Result {
     debug_assert!(f.is_finite());
     debug_assert!(f.is_sign_positive());
     const SUBNORMAL_EXP_LOWER_BOUND: i32 = EMIN - FRACTION_BITS as i32;
@@ -595,33 +570,30 @@ pub(crate) fn bin_2_dec_scientific(
     const EXP_UPPER_BOUND: i32 = EMAX - FRACTION_BITS as i32;
     let mut exp2 = f.quantum_exponent();
     let mut signif2 = f.integral_significand();
-    let mut res = String::with_capacity(prec + 9);
     let mut round = Round::Down;
     let mut exp10 = 0_i32;
     match exp2 {
         FAST_LOWER_BOUND..=-1 => {
             // 1 <= |f| < 2²³⁶
             (round, exp10) =
-                bin_small_float_2_scientific(signif2, exp2, prec, &mut res);
+                bin_small_float_2_scientific(signif2, exp2, prec, res)?;
         }
         0..SLOW_LOWER_BOUND => {
             // 2²³⁶ <= |f| < 2⁵¹²
             (round, exp10) =
-                bin_small_int_2_scientific(signif2, exp2, prec, &mut res);
+                bin_small_int_2_scientific(signif2, exp2, prec, res)?;
         }
         NORMAL_EXP_LOWER_BOUND..FAST_LOWER_BOUND => {
             // f256::MIN_POSITIVE <= |f| < 1
             (round, exp10) =
-                bin_fract_2_scientific(signif2, exp2, prec, &mut res);
+                bin_fract_2_scientific(signif2, exp2, prec, res)?;
         }
         SLOW_LOWER_BOUND..=EXP_UPPER_BOUND => {
             // 2⁵¹² <= |f| <= f256::MAX
             (round, exp10) =
-                bin_large_int_2_scientific(signif2, exp2, prec, &mut res);
+                bin_large_int_2_scientific(signif2, exp2, prec, res)?;
             // Need trailing zeroes?
-            res.push_str(
-                "0".repeat(prec - min(prec, exp10 as usize)).as_str(),
-            );
+            res.write_zeroes(prec - min(prec, exp10 as usize))?;
         }
         SUBNORMAL_EXP_LOWER_BOUND..NORMAL_EXP_LOWER_BOUND => {
             // f256::MIN_GT_ZERO <= |f| < MIN_POSITIVE
@@ -630,24 +602,180 @@ pub(crate) fn bin_2_dec_scientific(
             signif2 <<= adj;
             exp2 -= adj as i32;
             (round, exp10) =
-                bin_fract_2_scientific(signif2, exp2, prec, &mut res);
+                bin_fract_2_scientific(signif2, exp2, prec, res)?;
         }
         _ => {
             unreachable!()
         }
     }
     if round == Round::Up
-        || (round == Round::ToEven
-            && res.ends_with(['1', '3', '5', '7', '9']))
+        || (round == Round::ToEven && res.ends_with_odd_digit())
+    {
+        exp10 += res.round_up_scientific()?;
+    }
+    res.write_char(exp_mark)?;
+    write!(res, "{exp10}")
+}
+
+/// Returns the exact decimal representation of a positive finite non-zero
+/// binary float.
+#[allow(clippy::cast_possible_wrap)]
+fn bin_2_exact_decimal(f: f256) -> Decimal {
+    debug_assert!(f.is_finite() && !f.eq_zero());
+    debug_assert!(f.is_sign_positive());
+    let (_, mut exp2, signif2) = f.decode();
+    let mut dec = Decimal::from(signif2);
+    // Every f256 has atmost MAX_DIGITS significant decimal digits, so the
+    // following shifts are exact.
+    while exp2 > 0 {
+        let n = min(exp2.unsigned_abs(), Decimal::MAX_SHIFT);
+        dec.left_shift(n);
+        exp2 -= n as i32;
+    }
+    while exp2 < 0 {
+        let n = min(exp2.unsigned_abs(), Decimal::MAX_SHIFT);
+        dec.right_shift(n);
+        exp2 += n as i32;
+    }
+    debug_assert!(!dec.truncated);
+    dec
+}
+
+/// Returns the digit at index i of the given decimal, extended by zeroes to
+/// the left and to the right.
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_possible_wrap)]
+#[allow(clippy::cast_sign_loss)]
+const fn digit_at(dec: &Decimal, i: i64) -> char {
+    if i >= 0 && i < dec.n_digits as i64 {
+        (b'0' + dec.digits[i as usize]) as char
+    } else {
+        '0'
+    }
+}
+
+/// Returns how the digits of the given decimal left of index i have to be
+/// rounded, when all digits from index i onwards are dropped.
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_possible_wrap)]
+#[allow(clippy::cast_sign_loss)]
+const fn round_at(dec: &Decimal, i: i64) -> Round {
+    if i < 0 || i >= dec.n_digits as i64 {
+        return Round::Down;
+    }
+    let i = i as usize;
+    // The digits are trimmed, so there are non-zero digits right of index i
+    // if i is not the last index.
+    match dec.digits[i] {
+        0..=4 => Round::Down,
+        5 if i == dec.n_digits - 1 => Round::ToEven,
+        _ => Round::Up,
+    }
+}
+
+/// Converts a positive finite binary float into a string representing a
+/// decimal number dₘ⋯d₀.d₋₁⋯d₋ₚ where d ∈ [0..9] and p is the given number of
+/// fractional digits, based on the exact decimal representation of the
+/// binary float, so that there is no limit on p.
+/// The result may have an additional leading zero!
+#[cfg(any(feature = "alloc", test))]
+pub(crate) fn bin_2_dec_fixed_point_exact(f: f256, prec: usize) -> String {
+    // Preserve one char for carry
+    let mut res = String::from("0");
+    // Writing to a String can't fail.
+    write_dec_fixed_point_exact(f, prec, &mut res).unwrap();
+    res
+}
+
+/// Writes the exact decimal representation of a positive finite binary
+/// float, rounded to the given number of fractional digits, into `res` (see
+/// [`bin_2_dec_fixed_point_exact`]), prepending a one in case of a carry.
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_possible_wrap)]
+#[allow(clippy::cast_sign_loss)]
+pub(crate) fn write_dec_fixed_point_exact<B: DecBuf>(
+    f: f256,
+    prec: usize,
+    res: &mut B,
+) -> fmt::Result {
+    debug_assert!(f.is_finite());
+    debug_assert!(f.is_sign_positive());
+    let dec = bin_2_exact_decimal(f);
+    let dp = i64::from(dec.decimal_point);
+    // Integer part
+    if dp <= 0 {
+        res.write_char('0')?;
+    } else {
+        for i in 0..dp {
+            res.write_char(digit_at(&dec, i))?;
+        }
+    }
+    // Fractional part
+    let end = dp + prec as i64;
+    if prec > 0 {
+        res.write_char('.')?;
+        for i in dp..end {
+            res.write_char(digit_at(&dec, i))?;
+        }
+    }
+    let round = round_at(&dec, end);
+    if round == Round::Up
+        || (round == Round::ToEven && res.ends_with_odd_digit())
     {
-        exp10 += round_up_scientific_inplace(&mut res);
+        res.round_up_fixed_point()?;
     }
+    Ok(())
+}
 
-    res.push(exp_mark);
-    res.push_str(exp10.to_string().as_str());
+/// Converts a positive finite binary float into a string representing a
+/// decimal number d₀.d₋₁⋯d₋ₚEe where d ∈ [0..9], e ∈ [-78984..78913], E is
+/// the given exponent marker and p is the given number of fractional digits,
+/// based on the exact decimal representation of the binary float, so that
+/// there is no limit on p.
+#[cfg(any(feature = "alloc", test))]
+pub(crate) fn bin_2_dec_scientific_exact(
+    f: f256,
+    exp_mark: char,
+    prec: usize,
+) -> String {
+    let mut res = String::with_capacity(prec + 9);
+    // Writing to a String can't fail.
+    write_dec_scientific_exact(f, exp_mark, prec, &mut res).unwrap();
     res
 }
 
+/// Writes the exact decimal representation of a positive finite binary
+/// float in scientific notation, rounded to the given number of fractional
+/// digits, into `res` (see [`bin_2_dec_scientific_exact`]).
+#[allow(clippy::cast_possible_wrap)]
+pub(crate) fn write_dec_scientific_exact<B: DecBuf>(
+    f: f256,
+    exp_mark: char,
+    prec: usize,
+    res: &mut B,
+) -> fmt::Result {
+    debug_assert!(f.is_finite());
+    debug_assert!(f.is_sign_positive());
+    let dec = bin_2_exact_decimal(f);
+    let mut exp10 = dec.decimal_point - 1;
+    res.write_char(digit_at(&dec, 0))?;
+    let end = prec as i64 + 1;
+    if prec > 0 {
+        res.write_char('.')?;
+        for i in 1..end {
+            res.write_char(digit_at(&dec, i))?;
+        }
+    }
+    let round = round_at(&dec, end);
+    if round == Round::Up
+        || (round == Round::ToEven && res.ends_with_odd_digit())
+    {
+        exp10 += res.round_up_scientific()?;
+    }
+    res.write_char(exp_mark)?;
+    write!(res, "{exp10}")
+}
+
 #[cfg(test)]
 mod to_fixed_point_tests {
     use alloc::borrow::ToOwned;
@@ -0,0 +1,220 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use core::{
+    fmt,
+    ops::{BitAnd, BitOr, BitOrAssign},
+};
+
+/// Set of the status flags defined in IEEE 754-2019, section 7, signaling
+/// the exceptions raised by an operation.
+///
+/// An underflow is signaled when the result is tiny (i.e. its absolute value
+/// is less than [`f256::MIN_POSITIVE`](crate::f256::MIN_POSITIVE) before
+/// rounding) and inexact.
+///
+/// # Examples
+///
+/// ```
+/// # use ::f256::{f256, ExceptionFlags, RoundingMode};
+/// let rnd = RoundingMode::NearestTiesEven;
+/// let (z, flags) = f256::ONE.div_with_flags(f256::from(3), rnd);
+/// assert_eq!(flags, ExceptionFlags::INEXACT);
+/// let (z, flags) = f256::MAX.mul_with_flags(f256::TWO, rnd);
+/// assert!(flags.is_overflow() && flags.is_inexact());
+/// assert!(f256::ONE.div_with_flags(f256::ZERO, rnd).1.is_div_by_zero());
+/// assert!(f256::from(4).sqrt_with_flags(rnd).1.is_empty());
+/// ```
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct ExceptionFlags(u8);
+
+impl ExceptionFlags {
+    /// No exception.
+    pub const NONE: Self = Self(0);
+    /// Invalid operation, i.e. there is no usefully definable result (like
+    /// for 0 × ∞ or √-1). The result is NaN.
+    pub const INVALID: Self = Self(1);
+    /// Division by zero, i.e. an exact infinite result from finite operands.
+    pub const DIV_BY_ZERO: Self = Self(1 << 1);
+    /// Overflow, i.e. the rounded result exceeds the range of finite `f256`
+    /// values. Always signaled together with [`Self::INEXACT`].
+    pub const OVERFLOW: Self = Self(1 << 2);
+    /// Underflow, i.e. the result is tiny and inexact. Always signaled
+    /// together with [`Self::INEXACT`].
+    pub const UNDERFLOW: Self = Self(1 << 3);
+    /// Inexact, i.e. the rounded result differs from the exact result.
+    pub const INEXACT: Self = Self(1 << 4);
+
+    const NAMES: [(Self, &'static str); 5] = [
+        (Self::INVALID, "INVALID"),
+        (Self::DIV_BY_ZERO, "DIV_BY_ZERO"),
+        (Self::OVERFLOW, "OVERFLOW"),
+        (Self::UNDERFLOW, "UNDERFLOW"),
+        (Self::INEXACT, "INEXACT"),
+    ];
+
+    /// Returns `true` if no flag is set in `self`.
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if all flags set in `other` are also set in `self`.
+    #[must_use]
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if the invalid operation flag is set in `self`.
+    #[must_use]
+    #[inline]
+    pub const fn is_invalid(self) -> bool {
+        self.contains(Self::INVALID)
+    }
+
+    /// Returns `true` if the division by zero flag is set in `self`.
+    #[must_use]
+    #[inline]
+    pub const fn is_div_by_zero(self) -> bool {
+        self.contains(Self::DIV_BY_ZERO)
+    }
+
+    /// Returns `true` if the overflow flag is set in `self`.
+    #[must_use]
+    #[inline]
+    pub const fn is_overflow(self) -> bool {
+        self.contains(Self::OVERFLOW)
+    }
+
+    /// Returns `true` if the underflow flag is set in `self`.
+    #[must_use]
+    #[inline]
+    pub const fn is_underflow(self) -> bool {
+        self.contains(Self::UNDERFLOW)
+    }
+
+    /// Returns `true` if the inexact flag is set in `self`.
+    #[must_use]
+    #[inline]
+    pub const fn is_inexact(self) -> bool {
+        self.contains(Self::INEXACT)
+    }
+
+    /// Sets `INEXACT` if `rnd_bits` != 0, i.e. if bits have been truncated
+    /// from a result, and additionally `UNDERFLOW`, if the result is tiny.
+    #[inline(always)]
+    pub(crate) const fn set_inexact(&mut self, rnd_bits: u32, is_tiny: bool) {
+        if rnd_bits != 0 {
+            self.0 |= Self::INEXACT.0;
+            if is_tiny {
+                self.0 |= Self::UNDERFLOW.0;
+            }
+        }
+    }
+}
+
+impl BitOr for ExceptionFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for ExceptionFlags {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for ExceptionFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl fmt::Debug for ExceptionFlags {
+    fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+        form.write_str("ExceptionFlags(")?;
+        if self.is_empty() {
+            form.write_str("NONE")?;
+        }
+        let mut sep = "";
+        for (flag, name) in Self::NAMES {
+            if self.contains(flag) {
+                form.write_str(sep)?;
+                form.write_str(name)?;
+                sep = " | ";
+            }
+        }
+        form.write_str(")")
+    }
+}
+
+#[cfg(test)]
+mod exception_flags_tests {
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn test_ops() {
+        let flags = ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT;
+        assert!(flags.is_overflow());
+        assert!(flags.is_inexact());
+        assert!(!flags.is_underflow());
+        assert!(!flags.is_invalid());
+        assert!(!flags.is_div_by_zero());
+        assert!(flags.contains(ExceptionFlags::INEXACT));
+        assert!(!flags
+            .contains(ExceptionFlags::INEXACT | ExceptionFlags::INVALID));
+        assert_eq!(flags & ExceptionFlags::INEXACT, ExceptionFlags::INEXACT);
+        assert!((flags & ExceptionFlags::UNDERFLOW).is_empty());
+        let mut flags = ExceptionFlags::default();
+        assert!(flags.is_empty());
+        flags |= ExceptionFlags::INVALID;
+        assert_eq!(flags, ExceptionFlags::INVALID);
+    }
+
+    #[test]
+    fn test_set_inexact() {
+        let mut flags = ExceptionFlags::NONE;
+        flags.set_inexact(0, true);
+        assert!(flags.is_empty());
+        flags.set_inexact(1, false);
+        assert_eq!(flags, ExceptionFlags::INEXACT);
+        flags.set_inexact(2, true);
+        assert_eq!(
+            flags,
+            ExceptionFlags::INEXACT | ExceptionFlags::UNDERFLOW
+        );
+    }
+
+    #[test]
+    fn test_debug() {
+        assert_eq!(
+            format!("{:?}", ExceptionFlags::NONE),
+            "ExceptionFlags(NONE)"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                ExceptionFlags::UNDERFLOW | ExceptionFlags::INEXACT
+            ),
+            "ExceptionFlags(UNDERFLOW | INEXACT)"
+        );
+    }
+}
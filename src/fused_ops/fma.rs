@@ -15,11 +15,12 @@ use core::{
 
 use crate::{
     abs_bits, abs_bits_sticky, binops::mul::mul_abs_finite, exp_bits, f256,
-    left_adj_signif, norm_bit, sign_bits_hi, signif, BigUInt,
+    left_adj_signif, norm_bit, norm_signif, sign_bits_hi, signif, BigUInt,
     BinEncAnySpecial, HiLo, EMIN, EXP_BIAS, EXP_BITS, EXP_MAX, FRACTION_BITS,
-    HI_FRACTION_BITS, HI_SIGN_MASK, INF_HI, MAX_HI, SIGNIFICAND_BITS, U256,
-    U512,
+    HI_ABS_MASK, HI_FRACTION_BIAS, HI_FRACTION_BITS, HI_SIGN_MASK, INF_HI,
+    MAX_HI, SIGNIFICAND_BITS, U256, U512,
 };
+use crate::{ExceptionFlags, RoundingMode};
 
 /// Helper type representing signed integers of 768 bits.
 #[allow(non_camel_case_types)]
@@ -158,12 +159,30 @@ impl ShlAssign<u32> for u768 {
 }
 
 /// Compute z = x * y + a, only once rounded tie to even.
+#[inline(always)]
+pub(crate) fn fma(x: &f256, y: &f256, a: &f256) -> f256 {
+    fma_rounded(
+        x,
+        y,
+        a,
+        RoundingMode::NearestTiesEven,
+        &mut ExceptionFlags::default(),
+    )
+}
+
+/// Compute z = x * y + a, only once rounded according to `rnd`.
 #[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::cast_possible_wrap)]
 #[allow(clippy::cast_sign_loss)]
 #[allow(clippy::cognitive_complexity)]
 #[inline]
-pub(crate) fn fma(x: &f256, y: &f256, a: &f256) -> f256 {
+pub(crate) fn fma_rounded(
+    x: &f256,
+    y: &f256,
+    a: &f256,
+    rnd: RoundingMode,
+    flags: &mut ExceptionFlags,
+) -> f256 {
     // The products sign is the XOR of the signs of the operands.
     let sign_bits_hi_p = (x.bits.hi.0 ^ y.bits.hi.0) & HI_SIGN_MASK;
     let sign_bits_hi_a = sign_bits_hi(a);
@@ -197,13 +216,19 @@ pub(crate) fn fma(x: &f256, y: &f256, a: &f256) -> f256 {
                 if max_abs_bits_sticky_xy < INF_HI {
                     // The other is finite => product is zero
                     // => result is zero.
+                    let sign_bits_hi_z = if sign_bits_hi_p == sign_bits_hi_a {
+                        sign_bits_hi_p
+                    } else {
+                        rnd.zero_sum_sign_bits_hi()
+                    };
                     return f256 {
-                        bits: U256::new(sign_bits_hi_p & sign_bits_hi_a, 0),
+                        bits: U256::new(sign_bits_hi_z, 0),
                     };
                 };
                 if max_abs_bits_sticky_xy == INF_HI {
                     // The other is infinite => product is nan
                     // => result is nan.
+                    *flags |= ExceptionFlags::INVALID;
                     return f256::NAN;
                 }
             }
@@ -211,12 +236,26 @@ pub(crate) fn fma(x: &f256, y: &f256, a: &f256) -> f256 {
                 // Both multiplicands are finite => result = product.
                 let (mut bits_z, rnd_bits) =
                     mul_abs_finite(&abs_bits_x, &abs_bits_y);
+                if bits_z.hi.0 == INF_HI {
+                    // Product overflows.
+                    *flags |=
+                        ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT;
+                    return rnd.overflow(sign_bits_hi_p);
+                }
+                flags.set_inexact(rnd_bits, bits_z.hi.0 < HI_FRACTION_BIAS);
                 bits_z.hi.0 |= sign_bits_hi_p;
                 // Final rounding. Possibly overflowing into the exponent, but
                 // that is ok.
-                if rnd_bits > 0b10 || (rnd_bits == 0b10 && bits_z.lo.is_odd())
-                {
+                if rnd.round_up(
+                    sign_bits_hi_p,
+                    bits_z.lo.is_odd(),
+                    rnd_bits,
+                    0b10,
+                ) {
                     bits_z.incr();
+                    if bits_z.hi.0 & HI_ABS_MASK == INF_HI {
+                        *flags |= ExceptionFlags::OVERFLOW;
+                    }
                 }
                 return f256 { bits: bits_z };
             }
@@ -228,12 +267,14 @@ pub(crate) fn fma(x: &f256, y: &f256, a: &f256) -> f256 {
                 if min_abs_bits_sticky_xy == 0 {
                     // The other is zero => product is nan
                     // => result is nan.
+                    *flags |= ExceptionFlags::INVALID;
                     return f256::NAN;
                 }
                 // Product is infinite
                 if sign_bits_hi_p == sign_bits_hi_a {
                     return *a;
                 } else {
+                    *flags |= ExceptionFlags::INVALID;
                     return f256::NAN;
                 }
             } else {
@@ -249,6 +290,7 @@ pub(crate) fn fma(x: &f256, y: &f256, a: &f256) -> f256 {
             };
             if max_abs_bits_sticky_xy == INF_HI {
                 // The other is infinite => product is nan => result is nan.
+                *flags |= ExceptionFlags::INVALID;
                 return f256::NAN;
             }
         }
@@ -267,13 +309,16 @@ pub(crate) fn fma(x: &f256, y: &f256, a: &f256) -> f256 {
 
     // Calculate x * y + a, rounded tie to even.
 
-    // Extract biased exponents and significands.
-    let exp_bits_x = exp_bits(&abs_bits_x) as i32;
+    // Extract biased exponents and significands. The significands of
+    // subnormal multiplicands are normalized (and their exponents adjusted
+    // accordingly), so that the product always has its leading bit in one
+    // of the two expected positions.
+    let (signif_x, shift_x) = norm_signif(&abs_bits_x);
+    let exp_bits_x = exp_bits(&abs_bits_x) as i32 - shift_x as i32;
     let norm_bit_x = norm_bit(&abs_bits_x) as i32;
-    let signif_x = signif(&abs_bits_x);
-    let exp_bits_y = exp_bits(&abs_bits_y) as i32;
+    let (signif_y, shift_y) = norm_signif(&abs_bits_y);
+    let exp_bits_y = exp_bits(&abs_bits_y) as i32 - shift_y as i32;
     let norm_bit_y = norm_bit(&abs_bits_y) as i32;
-    let signif_y = signif(&abs_bits_y);
     let exp_bits_a = exp_bits(&abs_bits_a) as i32;
     let norm_bit_a = norm_bit(&abs_bits_a) as i32;
     let signif_a = signif(&abs_bits_a);
@@ -319,14 +364,10 @@ pub(crate) fn fma(x: &f256, y: &f256, a: &f256) -> f256 {
         i32::MIN..=ADDEND_TOO_SMALL_UPPER_LIMIT => {
             (&mut signif_p, &u768::STICKY_BIT, sign_bits_hi_p)
         }
-        PROD_ANCHORED_LOWER_LIMIT..=-1 => {
-            let shr = (REL_OFFSET as i32 - d) as u32;
-            signif_a_shifted = u768::from_u256_shifted(&signif_a, shr);
-            (&mut signif_p, &signif_a_shifted, sign_bits_hi_p)
-        }
-        0..=1 => {
+        PROD_ANCHORED_LOWER_LIMIT..=ADDEND_ANCHORED_UPPER_LIMIT => {
             // Because of the uncertainty on the leading digit of the product
-            // we have to check explicitly for max(p, a).
+            // and because the significand of a subnormal addend is not
+            // normalized, we have to check explicitly for max(p, a).
             let shr = (REL_OFFSET as i32 - d) as u32;
             signif_a_shifted = u768::from_u256_shifted(&signif_a, shr);
             if signif_p >= signif_a_shifted {
@@ -335,11 +376,6 @@ pub(crate) fn fma(x: &f256, y: &f256, a: &f256) -> f256 {
                 (&mut signif_a_shifted, &signif_p, sign_bits_hi_a)
             }
         }
-        2..=ADDEND_ANCHORED_UPPER_LIMIT => {
-            let shr = (REL_OFFSET as i32 - d) as u32;
-            signif_a_shifted = u768::from_u256_shifted(&signif_a, shr);
-            (&mut signif_a_shifted, &signif_p, sign_bits_hi_a)
-        }
         _ => {
             // Product too small.
             signif_a_shifted = u768::from_u256_shifted(&signif_a, 0);
@@ -354,7 +390,10 @@ pub(crate) fn fma(x: &f256, y: &f256, a: &f256) -> f256 {
     }
     let signif_z_nlz = signif_z.leading_zeros();
     if signif_z_nlz == u768::BITS {
-        return f256::ZERO;
+        // Exact zero result => sign depends on rounding mode.
+        return f256 {
+            bits: U256::new(rnd.zero_sum_sign_bits_hi(), 0),
+        };
     }
     // Calculate exponent and normalize result.
     let (exp_bits_m1_z, shl) = if d <= ADDEND_ANCHORED_UPPER_LIMIT {
@@ -362,19 +401,24 @@ pub(crate) fn fma(x: &f256, y: &f256, a: &f256) -> f256 {
         let carry = REL_OFFSET as i32 - n as i32;
         let t = exp_bits_p + carry + 1;
         // If the result overflows the range of values representable as
-        // `f256`, return +/- Infinity.
+        // `f256`, return ±Infinity or ±MAX, depending on the rounding mode.
         if t >= EXP_MAX as i32 {
-            return f256 {
-                bits: U256::new(sign_bits_hi_z | INF_HI, 0),
-            };
+            *flags |= ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT;
+            return rnd.overflow(sign_bits_hi_z);
         }
         if t >= 1 {
             (t - 1, n)
         } else {
-            (0, REL_OFFSET)
+            // The result is subnormal, so the significand has to be shifted
+            // 1 - t bits less. The product may be less than 2^Eₘᵢₙ, even if
+            // the result is not.
+            debug_assert!(exp_bits_p + REL_OFFSET as i32 >= 0);
+            (0, (exp_bits_p + REL_OFFSET as i32) as u32)
         }
     } else {
-        (exp_bits_a - 1, 0)
+        // The hidden bit of a normal addend increments the exponent, a
+        // subnormal one has none.
+        (exp_bits_a - norm_bit_a, 0)
     };
     *signif_z <<= shl;
     // Now we have the results preliminary significand in signif_z.hi, before
@@ -388,9 +432,14 @@ pub(crate) fn fma(x: &f256, y: &f256, a: &f256) -> f256 {
         signif_z.hi.hi + ((exp_bits_m1_z as u128) << HI_FRACTION_BITS),
         signif_z.hi.lo,
     );
+    // The result is tiny if its biased exponent is 0 before rounding.
+    flags.set_inexact(rnd_bits, bits_z.hi.0 < HI_FRACTION_BIAS);
     // Final rounding. Possibly overflowing into the exponent, but that is ok.
-    if rnd_bits > 0x4 || rnd_bits == 0x4 && bits_z.lo.is_odd() {
+    if rnd.round_up(sign_bits_hi_z, bits_z.lo.is_odd(), rnd_bits, 0x4) {
         bits_z.incr();
+        if bits_z.hi.0 == INF_HI {
+            *flags |= ExceptionFlags::OVERFLOW;
+        }
     }
     bits_z.hi.0 |= sign_bits_hi_z;
     f256 { bits: bits_z }
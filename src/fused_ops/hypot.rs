@@ -50,7 +50,7 @@ pub(crate) fn hypot(x: &f256, y: &f256) -> f256 {
 
     // Calculate √(x² + y²).
     let (signif, exp) = sum_squares(&mut abs_bits_x, &mut abs_bits_y);
-    let (mut p, mut q) = square_root(&signif.hi, exp);
+    let (mut p, mut q, _) = square_root(&signif.hi, exp);
     if p > EMAX {
         return f256::INFINITY;
     }
@@ -10,3 +10,4 @@
 pub(crate) mod fma;
 pub(crate) mod hypot;
 pub(crate) mod sos;
+pub(crate) mod sum;
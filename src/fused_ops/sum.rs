@@ -0,0 +1,500 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use core::{borrow::Borrow, iter::Sum};
+
+use crate::{f256, math::Float512, split_f256_enc, BigUInt, U256, U512};
+
+// Number of u128 chunks needed to hold a product of two significands
+// (atmost 474 bits) shifted left by atmost 127 bits.
+const TERM_CHUNKS: usize = 5;
+
+// Number of u128 chunks of a window (12288 bits).
+const WINDOW_CHUNKS: usize = 96;
+
+// Number of windows of an accumulator.
+const N_WINDOWS: usize = 4;
+
+// Minimal number of chunks between two windows. The windows below a window
+// then hold a value less than 2⁻⁵¹² times the unit of the lowest chunk of
+// that window, i.e. less than one unit in the 510th bit of its value.
+const GAP: i32 = 4;
+
+/// Helper type representing a fixed-point number with a movable position.
+#[derive(Clone, Copy)]
+struct Window {
+    // Two's complement integer, stored as little-endian u128 chunks. The
+    // value of the window is the integer held in chunks[..len]
+    // × 2^(128 × base). The most significant chunk in use is always a pure
+    // sign extension (0 or u128::MAX), except transiently while adding.
+    chunks: [u128; WINDOW_CHUNKS],
+    base: i32,
+    len: usize,
+}
+
+impl Window {
+    const EMPTY: Self = Self {
+        chunks: [0_u128; WINDOW_CHUNKS],
+        base: 0,
+        len: 0,
+    };
+
+    // Returns the index of the chunk above the window.
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_possible_wrap)]
+    const fn top(&self) -> i32 {
+        self.base + self.len as i32
+    }
+
+    // Returns the sign extension chunk of the window.
+    fn sign_ext(&self) -> u128 {
+        self.chunks[..self.len]
+            .last()
+            .map_or(0, |c| [0, u128::MAX][(c >> 127) as usize])
+    }
+
+    fn is_zero(&self) -> bool {
+        self.chunks[..self.len].iter().all(|c| *c == 0)
+    }
+
+    // Replaces the value of the window by its absolute value and returns
+    // its sign.
+    fn abs(&mut self) -> u32 {
+        let s = (self.sign_ext() != 0) as u32;
+        if s == 1 {
+            let mut carry = true;
+            for chunk in &mut self.chunks[..self.len] {
+                let (t, c) = (!*chunk).overflowing_add(carry as u128);
+                *chunk = t;
+                carry = c;
+            }
+        }
+        s
+    }
+
+    // Moves the window so that it covers the chunks lo..hi, where hi must
+    // not be less than the current top. The chunks below lo are cut off,
+    // i.e. the value is rounded toward -∞. Returns whether the cut off part
+    // is not zero.
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_possible_wrap)]
+    #[allow(clippy::cast_sign_loss)]
+    fn cover(&mut self, lo: i32, hi: i32) -> bool {
+        debug_assert!(lo < hi && hi - lo <= WINDOW_CHUNKS as i32);
+        debug_assert!(self.len == 0 || hi >= self.top());
+        let ext = self.sign_ext();
+        let mut cut = false;
+        if self.len > 0 && lo < self.base {
+            let sh = (self.base - lo) as usize;
+            self.chunks.copy_within(..self.len, sh);
+            self.chunks[..sh].fill(0);
+            self.len += sh;
+        } else if self.len > 0 && lo > self.base {
+            let sh = ((lo - self.base) as usize).min(self.len);
+            cut = self.chunks[..sh].iter().any(|c| *c != 0);
+            self.chunks.copy_within(sh..self.len, 0);
+            self.len -= sh;
+        }
+        self.base = lo;
+        let len = (hi - lo) as usize;
+        self.chunks[self.len..len].fill(ext);
+        self.len = len;
+        cut
+    }
+
+    // Adds (-1)ˢ × m × 2^(128 × pos) to the window, which must cover the
+    // chunks up to pos + m.len(). The chunks of m below the window are cut
+    // off, i.e. the result is rounded toward -∞. Returns whether the cut off
+    // part is not zero.
+    #[allow(clippy::cast_sign_loss)]
+    fn add(&mut self, s: u32, pos: i32, m: &[u128]) -> bool {
+        let (low, m) =
+            m.split_at(((self.base - pos).max(0) as usize).min(m.len()));
+        let cut = low.iter().any(|c| *c != 0);
+        let idx = (pos - self.base).max(0) as usize;
+        debug_assert!(idx + m.len() < self.len);
+        // Add or subtract m, propagating the carry resp. borrow up to the
+        // most significant chunk, if necessary. If a negative value has been
+        // cut, one more unit is subtracted.
+        let mut carry = s == 1 && cut;
+        for (i, chunk) in self.chunks[idx..self.len].iter_mut().enumerate() {
+            if i >= m.len() && !carry {
+                break;
+            }
+            let d = m.get(i).copied().unwrap_or(0);
+            let (t, c1, c2);
+            if s == 0 {
+                (t, c1) = chunk.overflowing_add(d);
+                (*chunk, c2) = t.overflowing_add(carry as u128);
+            } else {
+                (t, c1) = chunk.overflowing_sub(d);
+                (*chunk, c2) = t.overflowing_sub(carry as u128);
+            }
+            carry = c1 || c2;
+        }
+        cut
+    }
+}
+
+/// Helper type accumulating the exact sum of `f256` values or products of
+/// `f256` values (Kulisch accumulator).
+///
+/// Instead of a fixed-point number covering the full range of exponents
+/// (which would need 64 resp. 128 KiB), the accumulator holds upto
+/// `N_WINDOWS` windows of `WINDOW_CHUNKS` chunks (6 KiB in total), each
+/// covering a range of exponents touched by the terms added so far. The
+/// windows are moved and merged as terms arrive. The sum is held exactly,
+/// unless the terms fall into more than `N_WINDOWS` separate ranges or a
+/// window would have to span more than 12288 bits. In that case the least
+/// significant parts are discarded. This can only affect the rounded result,
+/// if the remaining parts cancel out almost completely.
+pub(crate) struct Accumulator {
+    // Windows in use, ordered by their base and separated by at least GAP
+    // chunks. None of them has the value zero.
+    windows: [Window; N_WINDOWS],
+    n_windows: usize,
+    // Sign (-1, 0 or 1) of the discarded parts and index of the chunk
+    // below which they reside. Windows are never extended below that chunk.
+    lost: i32,
+    lost_top: i32,
+    has_nan: bool,
+    has_pos_inf: bool,
+    has_neg_inf: bool,
+    only_neg_zeros: bool,
+}
+
+impl Default for Accumulator {
+    fn default() -> Self {
+        Self {
+            windows: [Window::EMPTY; N_WINDOWS],
+            n_windows: 0,
+            lost: 0,
+            lost_top: i32::MIN,
+            has_nan: false,
+            has_pos_inf: false,
+            has_neg_inf: false,
+            only_neg_zeros: true,
+        }
+    }
+}
+
+impl Accumulator {
+    /// Add `x` to the accumulator.
+    pub(crate) fn add(&mut self, x: &f256) {
+        if !x.is_finite() {
+            self.add_special(x);
+            return;
+        }
+        let (s, t, c) = split_f256_enc(x);
+        self.only_neg_zeros &= s == 1;
+        if !c.is_zero() {
+            self.add_term(
+                s,
+                t,
+                &U512 {
+                    hi: U256::ZERO,
+                    lo: c,
+                },
+            );
+        }
+    }
+
+    /// Add the exact product `x` × `y` to the accumulator.
+    pub(crate) fn add_prod(&mut self, x: &f256, y: &f256) {
+        if !x.is_finite() || !y.is_finite() {
+            if x.is_nan() || y.is_nan() || x.eq_zero() || y.eq_zero() {
+                // NaN or ∞ × 0
+                self.has_nan = true;
+            } else {
+                self.add_special(&(x * y));
+            }
+            return;
+        }
+        let (s_x, t_x, c_x) = split_f256_enc(x);
+        let (s_y, t_y, c_y) = split_f256_enc(y);
+        let s = s_x ^ s_y;
+        self.only_neg_zeros &= s == 1;
+        if !c_x.is_zero() && !c_y.is_zero() {
+            let (lo, hi) = c_x.widening_mul(&c_y);
+            self.add_term(s, t_x + t_y, &U512 { hi, lo });
+        }
+    }
+
+    const fn add_special(&mut self, x: &f256) {
+        self.only_neg_zeros = false;
+        if x.is_nan() {
+            self.has_nan = true;
+        } else if x.is_sign_negative() {
+            self.has_neg_inf = true;
+        } else {
+            self.has_pos_inf = true;
+        }
+    }
+
+    /// Add (-1)ˢ × 2ᵗ × c to the accumulator.
+    fn add_term(&mut self, s: u32, t: i32, c: &U512) {
+        debug_assert!(!c.is_zero());
+        let q = t.div_euclid(128);
+        let r = t.rem_euclid(128);
+        // Split c × 2ʳ into little-endian chunks.
+        let mut term = [c.lo.lo.0, c.lo.hi.0, c.hi.lo.0, c.hi.hi.0, 0];
+        if r > 0 {
+            for i in (1..TERM_CHUNKS).rev() {
+                term[i] = (term[i] << r) | (term[i - 1] >> (128 - r));
+            }
+            term[0] <<= r;
+        }
+        self.add_chunks(s, q, &term);
+    }
+
+    // Discards the windows 0..k. The highest of them determines the sign of
+    // the discarded parts.
+    fn discard_windows(&mut self, k: usize) {
+        if k > 0 {
+            let w = &self.windows[k - 1];
+            self.lost = [1, -1][(w.sign_ext() != 0) as usize];
+            self.lost_top = w.top();
+            self.windows[..self.n_windows].rotate_left(k);
+            self.n_windows -= k;
+        }
+    }
+
+    // Removes the window i.
+    fn remove_window(&mut self, i: usize) {
+        self.windows[i..self.n_windows].rotate_left(1);
+        self.n_windows -= 1;
+    }
+
+    /// Add (-1)ˢ × m × 2^(128 × pos) to the accumulator.
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_possible_wrap)]
+    fn add_chunks(&mut self, s: u32, pos: i32, m: &[u128]) {
+        // The chunks to be covered, including one for the sign extension.
+        let (mut lo, mut hi) = (pos, pos + m.len() as i32 + 1);
+        if self.lost != 0 {
+            if hi <= self.lost_top + 1 {
+                // Completely within the discarded part.
+                return;
+            }
+            lo = lo.max(self.lost_top);
+        }
+        // The windows i..j are near the chunks and get merged.
+        let n = self.n_windows;
+        let mut i = self.windows[..n]
+            .iter()
+            .position(|w| w.top() + GAP > lo)
+            .unwrap_or(n);
+        let mut j = i + self.windows[i..n]
+            .iter()
+            .take_while(|w| w.base < hi + GAP)
+            .count();
+        if i == j {
+            // Need a new window.
+            if n == N_WINDOWS {
+                if i == 0 {
+                    // The chunks are below all windows.
+                    if self.lost == 0 || hi > self.lost_top {
+                        self.lost = [1, -1][s as usize];
+                        self.lost_top = hi;
+                    }
+                    return;
+                }
+                self.discard_windows(1);
+                i -= 1;
+            }
+            self.windows[i..=self.n_windows].rotate_right(1);
+            self.windows[i].len = 0;
+            self.n_windows += 1;
+            j = i + 1;
+        } else {
+            lo = lo.min(self.windows[i].base);
+            hi = hi.max(self.windows[j - 1].top());
+        }
+        if hi - lo > WINDOW_CHUNKS as i32 {
+            lo = hi - WINDOW_CHUNKS as i32;
+            self.discard_windows(i);
+            (i, j) = (0, j - i);
+        }
+        let mut cut = self.windows[i].cover(lo, hi);
+        for k in i + 1..j {
+            let mut w = self.windows[k];
+            let s_w = w.abs();
+            cut |= self.windows[i].add(s_w, w.base, &w.chunks[..w.len - 1]);
+        }
+        if j > i + 1 {
+            self.windows[i + 1..self.n_windows].rotate_left(j - i - 1);
+            self.n_windows -= j - i - 1;
+        }
+        cut |= self.windows[i].add(s, pos, m);
+        if cut {
+            // The cut off parts are positive and above all other discarded
+            // parts.
+            self.lost = 1;
+            self.lost_top = lo;
+        }
+        self.normalize(i);
+    }
+
+    // Restores the invariants for the window i after adding to it.
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_possible_wrap)]
+    fn normalize(&mut self, mut i: usize) {
+        let w = &self.windows[i];
+        if w.is_zero() {
+            self.remove_window(i);
+            return;
+        }
+        let top = w.chunks[w.len - 1];
+        if top != 0 && top != u128::MAX {
+            // The sign extension chunk has been used up.
+            let hi = w.top() + 1;
+            let lo = w.base.max(hi - WINDOW_CHUNKS as i32);
+            if lo > w.base {
+                self.discard_windows(i);
+                i = 0;
+            }
+            if self.windows[i].cover(lo, hi) {
+                self.lost = 1;
+                self.lost_top = lo;
+            }
+        }
+        if i + 1 < self.n_windows
+            && self.windows[i].top() + GAP > self.windows[i + 1].base
+        {
+            // Too near to the next window.
+            let mut w = self.windows[i + 1];
+            self.remove_window(i + 1);
+            let s = w.abs();
+            self.add_chunks(s, w.base, &w.chunks[..w.len - 1]);
+        }
+    }
+
+    /// Returns the value of the accumulator, rounded tie to even.
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_possible_wrap)]
+    #[allow(clippy::cast_sign_loss)]
+    pub(crate) fn round(&mut self) -> f256 {
+        if self.has_nan || (self.has_pos_inf && self.has_neg_inf) {
+            return f256::NAN;
+        }
+        if self.has_pos_inf {
+            return f256::INFINITY;
+        }
+        if self.has_neg_inf {
+            return f256::NEG_INFINITY;
+        }
+        let Some(w) = self.windows[..self.n_windows].last() else {
+            return match self.lost {
+                0 => {
+                    [f256::ZERO, f256::NEG_ZERO][self.only_neg_zeros as usize]
+                }
+                l => [f256::ZERO, f256::NEG_ZERO][(l < 0) as usize],
+            };
+        };
+        // Get sign and absolute value of the highest window.
+        let mut w = *w;
+        let s = w.abs();
+        let chunks = &w.chunks[..w.len];
+        // The rest of the value is held by the windows below or has been
+        // discarded. It's sign is given by the highest window below resp.
+        // the discarded parts.
+        let rest = match self.n_windows {
+            1 => self.lost,
+            n => [1, -1][(self.windows[n - 2].sign_ext() != 0) as usize],
+        } * [1, -1][s as usize];
+        // Windows in use are never zero.
+        let h = chunks.iter().rposition(|c| *c != 0).unwrap();
+        // Extract the 510 most significant bits (the number of fractional
+        // bits of a Float512) and a sticky bit for the rest, so that the
+        // final rounding is not affected.
+        let msb = 128 * h as i64 + 127 - chunks[h].leading_zeros() as i64;
+        let lsb = msb - 509;
+        let bits_at = |i: i64| -> u128 {
+            // 128 bits starting at bit position i.
+            let chunk = |k: i64| -> u128 {
+                usize::try_from(k)
+                    .ok()
+                    .and_then(|k| chunks.get(k).copied())
+                    .unwrap_or(0)
+            };
+            let (k, r) = (i.div_euclid(128), i.rem_euclid(128) as u32);
+            if r == 0 {
+                chunk(k)
+            } else {
+                (chunk(k) >> r) | (chunk(k + 1) << (128 - r))
+            }
+        };
+        let mut c = [
+            bits_at(lsb + 384),
+            bits_at(lsb + 256),
+            bits_at(lsb + 128),
+            bits_at(lsb),
+        ];
+        let mut sticky = false;
+        if lsb > 0 {
+            let (k, r) = (lsb.div_euclid(128), lsb.rem_euclid(128) as u32);
+            sticky = chunks[..k as usize].iter().any(|c| *c != 0)
+                || (r > 0 && chunks[k as usize] << (128 - r) != 0);
+        }
+        // The absolute value of the rest is less than one unit of the lowest
+        // chunk of the highest window and less than one unit in the last
+        // extracted bit. If it has the opposite sign and no bits are set
+        // below the extracted ones, one unit has to be borrowed.
+        if rest < 0 && !sticky {
+            for d in c.iter_mut().rev() {
+                let (t, b) = d.overflowing_sub(1);
+                *d = t;
+                if !b {
+                    break;
+                }
+            }
+        }
+        c[3] |= (sticky || rest != 0) as u128;
+        let t = 128 * w.base + lsb as i32;
+        f256::from(&Float512::from_sign_exp_signif(s, t, &c))
+    }
+}
+
+/// Computes the sum of all values of `iter`, rounded tie to even only once.
+pub(crate) fn sum_exact<I, T>(iter: I) -> f256
+where
+    I: IntoIterator<Item = T>,
+    T: Borrow<f256>,
+{
+    let mut acc = Accumulator::default();
+    for x in iter {
+        acc.add(x.borrow());
+    }
+    acc.round()
+}
+
+/// Computes the sum of the products of the corresponding values of `a` and
+/// `b`, rounded tie to even only once.
+pub(crate) fn dot(a: &[f256], b: &[f256]) -> f256 {
+    assert_eq!(a.len(), b.len(), "Slices must have equal length.");
+    let mut acc = Accumulator::default();
+    for (x, y) in a.iter().zip(b) {
+        acc.add_prod(x, y);
+    }
+    acc.round()
+}
+
+impl Sum for f256 {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        sum_exact(iter)
+    }
+}
+
+impl<'a> Sum<&'a Self> for f256 {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        sum_exact(iter)
+    }
+}
@@ -47,7 +47,6 @@
 #![warn(clippy::print_stdout)]
 #![warn(clippy::semicolon_if_nothing_returned)]
 #![warn(clippy::str_to_string)]
-#![warn(clippy::string_to_string)]
 #![warn(clippy::undocumented_unsafe_blocks)]
 #![warn(clippy::unicode_not_nfc)]
 #![warn(clippy::unimplemented)]
@@ -58,6 +57,7 @@
 #![warn(clippy::used_underscore_binding)]
 #![warn(clippy::wildcard_imports)]
 
+#[cfg(any(feature = "alloc", test))]
 extern crate alloc;
 extern crate core;
 
@@ -66,14 +66,28 @@ use crate::big_uint::{
 };
 use core::{cmp::Ordering, convert::Into, num::FpCategory, ops::Neg};
 
+mod big_float;
 mod big_uint;
 mod binops;
 pub mod consts;
 mod conv;
+mod exceptions;
 mod fused_ops;
 mod math;
 #[cfg(feature = "num-traits")]
 mod num_traits;
+mod rounding;
+#[cfg(feature = "serde")]
+mod serde;
+mod total_order;
+
+pub use big_float::{BigFloat256, BigFloat512, NonFiniteError};
+#[cfg(feature = "alloc")]
+pub use conv::SigDigits;
+pub use conv::{ParseErrorKind, ParseF256Error, ParseOptions};
+pub use exceptions::ExceptionFlags;
+pub use rounding::RoundingMode;
+pub use total_order::TotalF256;
 
 /// Precision level in relation to single precision float (f32) = 8
 pub(crate) const PREC_LEVEL: u32 = 8;
@@ -891,9 +905,18 @@ impl f256 {
     pub fn total_cmp(&self, other: &Self) -> Ordering {
         // The internal representation of `f256` values gives - besides their
         // sign - a total ordering following the intended mathematical
-        // ordering. Thus, flipping the sign bit allows to compare the
-        // raw values.
-        self.negated().bits.cmp(&(*other).negated().bits)
+        // ordering. Thus, mapping negative values to their bitwise
+        // complement and setting the sign bit of positive values allows to
+        // compare the raw values.
+        const fn key(f: &f256) -> (u128, u128) {
+            let (hi, lo) = (f.bits.hi.0, f.bits.lo.0);
+            if hi & HI_SIGN_MASK == 0 {
+                (hi | HI_SIGN_MASK, lo)
+            } else {
+                (!hi, !lo)
+            }
+        }
+        key(self).cmp(&key(other))
     }
 
     /// Restrict a value to a certain interval unless it is NaN.
@@ -1384,6 +1407,77 @@ impl f256 {
         fused_ops::fma::fma(&self, &f, &a)
     }
 
+    /// Fused multiply-add with directed rounding.
+    ///
+    /// Computes `(self * f) + a` with only one rounding error, rounded
+    /// according to the rounding mode `rnd`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, RoundingMode};
+    /// let x = f256::ONE + f256::EPSILON;
+    /// let a = f256::NEG_ONE;
+    /// // x * x - 1 = 2⋅ε + ε²
+    /// let lo = x.mul_add_rounded(x, a, RoundingMode::TowardNegative);
+    /// let hi = x.mul_add_rounded(x, a, RoundingMode::TowardPositive);
+    /// assert_eq!(lo, f256::EPSILON.mul2());
+    /// assert_eq!(hi, lo.next_up());
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_add_rounded(
+        self,
+        f: Self,
+        a: Self,
+        rnd: RoundingMode,
+    ) -> Self {
+        fused_ops::fma::fma_rounded(
+            &self,
+            &f,
+            &a,
+            rnd,
+            &mut ExceptionFlags::default(),
+        )
+    }
+
+    /// Fused multiply-add with directed rounding and exception flags.
+    ///
+    /// Computes `(self * f) + a` with only one rounding error, rounded
+    /// according to the rounding mode `rnd`, and returns the result together
+    /// with the exception flags raised by the operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, ExceptionFlags, RoundingMode};
+    /// let rnd = RoundingMode::NearestTiesEven;
+    /// let x = f256::ONE + f256::EPSILON;
+    /// let (z, flags) = x.mul_add_with_flags(x, f256::NEG_ONE, rnd);
+    /// assert_eq!(z, f256::EPSILON.mul2());
+    /// assert_eq!(flags, ExceptionFlags::INEXACT);
+    /// let (z, flags) = x.mul_add_with_flags(x, -x * x, rnd);
+    /// assert!(flags.is_empty());
+    /// let (z, flags) =
+    ///     f256::INFINITY.mul_add_with_flags(f256::ZERO, f256::ONE, rnd);
+    /// assert!(z.is_nan());
+    /// assert_eq!(flags, ExceptionFlags::INVALID);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_add_with_flags(
+        self,
+        f: Self,
+        a: Self,
+        rnd: RoundingMode,
+    ) -> (Self, ExceptionFlags) {
+        let mut flags = ExceptionFlags::default();
+        (
+            fused_ops::fma::fma_rounded(&self, &f, &a, rnd, &mut flags),
+            flags,
+        )
+    }
+
     /// Fused sum of squares.
     ///
     /// Computes `(self * self) + (other * other)` with only one rounding
@@ -1412,6 +1506,163 @@ impl f256 {
         fused_ops::fma::fma(&self, &self, &a)
     }
 
+    /// Error-free transformation of a sum (2Sum).
+    ///
+    /// Returns `(hi, lo)`, where `hi` = `self + other` (rounded to nearest)
+    /// and `lo` is the rounding error of that addition, so that `hi + lo` is
+    /// exactly equal to `self + other`.
+    ///
+    /// This holds for all finite operands, incl. subnormal ones. If `hi` is
+    /// not finite, i.e. if an operand is not finite or the sum overflows,
+    /// `lo` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// let x = f256::ONE;
+    /// let y = f256::EPSILON.div2().div2();
+    /// let (hi, lo) = x.two_sum(y);
+    /// assert_eq!(hi, x);
+    /// assert_eq!(lo, y);
+    /// let (hi, lo) = f256::MAX.two_sum(f256::MAX);
+    /// assert_eq!(hi, f256::INFINITY);
+    /// assert!(lo.is_nan());
+    /// ```
+    #[must_use]
+    pub fn two_sum(self, other: Self) -> (Self, Self) {
+        let hi = self + other;
+        let x = hi - other;
+        let y = hi - x;
+        let lo = (self - x) + (other - y);
+        (hi, lo)
+    }
+
+    /// Error-free transformation of a sum (Fast2Sum).
+    ///
+    /// Returns `(hi, lo)`, where `hi` = `self + other` (rounded to nearest)
+    /// and `lo` is the rounding error of that addition, so that `hi + lo` is
+    /// exactly equal to `self + other`.
+    ///
+    /// Compared to [`f256::two_sum`], this takes only three instead of six
+    /// additions, but it requires the exponent of `self` to be not less than
+    /// that of `other`, which is the case if |`self`| >= |`other`|. If that
+    /// condition is not met, `lo` may be inexact.
+    ///
+    /// If `hi` is not finite, i.e. if an operand is not finite or the sum
+    /// overflows, `lo` is meaningless.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// let x = f256::from(3);
+    /// let y = f256::EPSILON;
+    /// let (hi, lo) = x.fast_two_sum(y);
+    /// assert_eq!(hi, x);
+    /// assert_eq!(lo, y);
+    /// ```
+    #[must_use]
+    pub fn fast_two_sum(self, other: Self) -> (Self, Self) {
+        let hi = self + other;
+        let lo = other - (hi - self);
+        (hi, lo)
+    }
+
+    /// Error-free transformation of a product (2MultFMA).
+    ///
+    /// Returns `(hi, lo)`, where `hi` = `self * other` (rounded to nearest)
+    /// and `lo` is the rounding error of that multiplication, calculated by
+    /// a fused multiply-add, so that `hi + lo` is exactly equal to
+    /// `self * other`.
+    ///
+    /// This holds as long as the product does not underflow, i.e. for
+    /// |`hi`| >= 2²³⁷ × [`f256::MIN_POSITIVE`]. Below that limit, `lo` can
+    /// be inexact, because it would need bits beyond the subnormal range.
+    /// If `hi` is not finite, i.e. if an operand is not finite or the
+    /// product overflows, `lo` is meaningless.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// let x = f256::ONE + f256::EPSILON;
+    /// let (hi, lo) = x.two_prod(x);
+    /// assert_eq!(hi, f256::ONE + f256::EPSILON.mul2());
+    /// assert_eq!(lo, f256::EPSILON.square());
+    /// ```
+    #[must_use]
+    pub fn two_prod(self, other: Self) -> (Self, Self) {
+        let hi = self * other;
+        let lo = fused_ops::fma::fma(&self, &other, &-hi);
+        (hi, lo)
+    }
+
+    /// Computes the sum of all values yielded by `iter` with only one
+    /// rounding error.
+    ///
+    /// The values are accumulated exactly in a wide fixed-point accumulator
+    /// (Kulisch accumulator), so that the result is the exact sum, rounded
+    /// tie to even, independent of the number and the order of the values.
+    /// The sum of an empty iterator is -0.
+    ///
+    /// The accumulator has a fixed size of 6 KiB and does not allocate. It
+    /// holds upto four ranges of binary digits, each spanning atmost 12288
+    /// bits. When the values do not fit into these ranges, the least
+    /// significant digits are discarded. The result is then not guaranteed
+    /// to be correctly rounded, if the remaining digits cancel out almost
+    /// completely.
+    ///
+    /// This is also used by the implementation of [`core::iter::Sum`] for
+    /// `f256`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// let eps = f256::EPSILON.div2().div2();
+    /// let v = [f256::ONE, eps, f256::NEG_ONE];
+    /// assert_eq!(v[0] + v[1] + v[2], f256::ZERO);
+    /// assert_eq!(f256::sum_exact(v), eps);
+    /// assert_eq!(f256::sum_exact(&[f256::MAX, f256::MAX, f256::MIN]),
+    ///            f256::MAX);
+    /// ```
+    #[must_use]
+    pub fn sum_exact<I, T>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: core::borrow::Borrow<Self>,
+    {
+        fused_ops::sum::sum_exact(iter)
+    }
+
+    /// Computes the dot product of `a` and `b`, i.e. the sum of the products
+    /// of their corresponding values, with only one rounding error.
+    ///
+    /// The products are accumulated exactly in a wide fixed-point
+    /// accumulator (Kulisch accumulator), so that the result is the exact
+    /// dot product, rounded tie to even. The limits of the accumulator
+    /// described for [`f256::sum_exact`] apply accordingly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` differ in length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// let x = f256::ONE + f256::EPSILON;
+    /// let a = [x, x * x];
+    /// let b = [x, f256::NEG_ONE];
+    /// // x * x - round(x * x)
+    /// assert_eq!(f256::dot(&a, &b), f256::EPSILON.square());
+    /// ```
+    #[must_use]
+    pub fn dot(a: &[Self], b: &[Self]) -> Self {
+        fused_ops::sum::dot(a, b)
+    }
+
     /// Returns `self` / 2 (rounded tie to even)
     #[inline(always)]
     #[must_use]
@@ -1455,31 +1706,6 @@ impl f256 {
             ),
         }
     }
-
-    /// Calculates Euclidean division, the matching method for rem_euclid.
-    ///
-    /// This computes the integer n such that
-    /// self = n * rhs + self.rem_euclid(rhs).
-    /// In other words, the result is self / rhs rounded to the integer n
-    /// such that self >= n * rhs.
-    pub fn div_euclid(self, rhs: Self) -> Self {
-        (self / rhs).floor()
-    }
-
-    /// Calculates the least nonnegative remainder of self (mod rhs).
-    ///
-    /// In particular, the return value r satisfies 0.0 <= r < rhs.abs() in
-    /// most cases. However, due to a floating point round-off error it can
-    /// result in r == rhs.abs(), violating the mathematical definition, if
-    /// self is much smaller than rhs.abs() in magnitude and self < 0.0.
-    /// This result is not an element of the function's codomain, but it is
-    /// the closest floating point number in the real numbers and thus
-    /// fulfills the property
-    /// self == self.div_euclid(rhs) * rhs + self.rem_euclid(rhs)
-    /// approximately.
-    pub fn rem_euclid(self, rhs: Self) -> Self {
-        self.div_euclid(rhs).mul_add(-rhs, self)
-    }
 }
 
 impl Neg for f256 {
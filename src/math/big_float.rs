@@ -25,36 +25,6 @@ use core::{
     },
 };
 
-fn add_signifs<T>(x: &T, y: &T) -> (T, i32)
-where
-    T: BigUInt + HiLo,
-{
-    debug_assert!(x.leading_zeros() == 1 || y.leading_zeros() == 1);
-    let mut sum = *x;
-    sum += y;
-    let mut exp_adj = 0;
-    if sum.leading_zeros() == 0 {
-        // sum.idiv_pow2(1);
-        sum >>= 1;
-        exp_adj = 1;
-    }
-    (sum, exp_adj)
-}
-
-#[allow(clippy::cast_possible_wrap)]
-fn sub_signifs<T>(x: &T, y: &T) -> (T, i32)
-where
-    T: BigUInt + HiLo,
-{
-    debug_assert!(x >= y);
-    debug_assert!(x.leading_zeros() == 1);
-    let mut diff = *x;
-    diff -= y;
-    let shl = diff.leading_zeros() - 1;
-    diff <<= shl;
-    (diff, -(shl as i32))
-}
-
 fn mul_signifs<T>(x: &T, y: &T) -> (UInt<T>, i32)
 where
     T: BigUInt + HiLo,
@@ -75,17 +45,18 @@ where
     debug_assert!(y.leading_zeros() == 1);
     // 2ⁿ <= x < 2ⁿ⁺¹ and 2ⁿ <= y < 2ⁿ⁺¹
     // => ½ < x/y < 2
-    // => 2ⁿ⁻¹ < (x/y⋅2ⁿ) < 2ⁿ⁺¹
-    let exp_adj = (x < y) as i32;
-    let x = UInt::<T>::from(x).shl(sh);
+    // => 2ⁿ <= (x/y⋅2ⁿ) < 2ⁿ⁺¹ for x >= y
+    // and 2ⁿ < (x/y⋅2ⁿ⁺¹) < 2ⁿ⁺¹ for x < y
+    // Shifting x before dividing gives a quotient which is already
+    // normalized, so that it is rounded correctly.
+    let x_lt_y = x < y;
+    let x = UInt::<T>::from(x).shl(sh + u32::from(x_lt_y));
     let y = UInt::<T>::from(y);
     let q = x.rounding_div(&y);
     debug_assert!(q.hi().is_zero());
-    debug_assert!(q.lo().leading_zeros() >= 2);
-    let mut quot = q.lo_t();
-    let nlz = quot.leading_zeros();
-    quot <<= nlz - 1;
-    (quot, exp_adj)
+    let quot = q.lo_t();
+    debug_assert!(quot.leading_zeros() == 1);
+    (quot, i32::from(x_lt_y))
 }
 
 /// Representation of the number s⋅m⋅2⁻ⁿ⋅2ᵉ with
@@ -241,38 +212,65 @@ where
         }
     }
 
+    #[allow(clippy::cast_possible_wrap)]
     #[allow(clippy::cast_sign_loss)]
     fn iadd(&mut self, other: &Self) {
-        let exp = max(self.exp, other.exp);
-        if self.is_zero() || (exp - self.exp) > Self::FRACTION_BITS as i32 {
+        // Zero must be checked first, because its exponent is meaningless.
+        if other.is_zero() {
+            return;
+        }
+        if self.is_zero() {
+            *self = *other;
+            return;
+        }
+        // If the exponents differ by more than T::BITS, the smaller operand
+        // is less than ¼ ulp of the larger one, so it can't change the
+        // rounded result (even if the larger one is a power of two and the
+        // smaller one is subtracted from it).
+        let mut exp = max(self.exp, other.exp);
+        if (exp - self.exp) > T::BITS as i32 {
             *self = *other;
             return;
         }
-        if other.is_zero() || (exp - other.exp) > Self::FRACTION_BITS as i32 {
+        if (exp - other.exp) > T::BITS as i32 {
             return;
         }
-        let (mut signif_self, rem_self) = match (exp - self.exp) as u32 {
-            0 => (self.signif, T::ZERO),
-            sh => self.signif.widening_shr(sh),
+        // Aligning both significands in double width makes the sum (or
+        // difference) exact, so that it is rounded only once.
+        let align = |f: &Self| {
+            UInt::<T>::from_hi_lo(f.signif, T::ZERO) >> (exp - f.exp) as u32
         };
-        let (mut signif_other, rem_other) = match (exp - other.exp) as u32 {
-            0 => (other.signif, T::ZERO),
-            sh => other.signif.widening_shr(sh),
-        };
-        let op = [add_signifs, sub_signifs]
-            [(self.signum != other.signum) as usize];
-        if signif_self < signif_other {
-            swap(&mut signif_self, &mut signif_other);
-            self.signum = other.signum;
+        let mut x = align(self);
+        let mut y = align(other);
+        if self.signum == other.signum {
+            x += &y;
+        } else {
+            if x < y {
+                swap(&mut x, &mut y);
+                self.signum = other.signum;
+            }
+            x -= &y;
+            if x.is_zero() {
+                *self = Self::ZERO;
+                return;
+            }
         }
-        let mut exp_adj = 0_i32;
-        (self.signif, exp_adj) = op(&signif_self, &signif_other);
-        if self.signif.is_zero() {
-            self.signum = 0;
-            self.exp = 0;
+        // Normalize, so that the leading bit becomes the second most
+        // significant bit of the high part, and round.
+        let nlz = x.leading_zeros();
+        self.signif = if nlz == 0 {
+            exp += 1;
+            x.rounding_div_pow2(T::BITS + 1).lo_t()
         } else {
-            self.exp = (exp + exp_adj);
+            exp -= (nlz - 1) as i32;
+            (x << (nlz - 1)).rounding_div_pow2(T::BITS).lo_t()
         };
+        // Rounding may have overflowed.
+        if self.signif.leading_zeros() == 0 {
+            self.signif >>= 1;
+            exp += 1;
+        }
+        self.exp = exp;
     }
 
     fn isub(&mut self, other: &Self) {
@@ -294,9 +292,11 @@ where
             let rnd = prod_signif.lo > T::TIE
                 || (prod_signif.lo == T::TIE && prod_signif.hi.is_odd());
             prod_signif.hi.incr_if(rnd);
-            prod_signif.hi >>= (prod_signif.hi.leading_zeros() == 0) as u32;
+            // Rounding may have overflowed.
+            let carry = (prod_signif.hi.leading_zeros() == 0) as u32;
+            prod_signif.hi >>= carry;
             self.signif = prod_signif.hi;
-            self.exp += other.exp + exp_adj;
+            self.exp += other.exp + exp_adj + carry as i32;
         }
     }
 
@@ -533,7 +533,7 @@ where
         Self::from_sign_exp_signif(
             (i < 0) as u32,
             0,
-            &T::from(i.unsigned_abs() as u128).as_vec_u128(),
+            &T::from(i.unsigned_abs() as u128).as_u128_chunks(),
         )
     }
 }
@@ -556,13 +556,13 @@ where
         let exp_adj: u32 =
             shl - ((Self::FRACTION_BITS % 256) - FRACTION_BITS);
         let exp_f = exp(&abs_bits_f) - exp_adj as i32;
-        let mut t = T::default().as_vec_u128();
+        let mut t = T::default().as_u128_chunks();
         t[0] = signif_f.hi.0;
         t[1] = signif_f.lo.0;
         Self {
             signum: (-1_i32).pow(f.sign()),
             exp: exp_f,
-            signif: T::from(&t),
+            signif: T::from(&t[..]),
         }
     }
 }
@@ -570,8 +570,8 @@ where
 #[inline(always)]
 fn extract_signif<T: BigUInt + HiLo>(f256_signif: T) -> U256 {
     let [hi, lo] = match T::N_CHUNKS {
-        2.. => *f256_signif.as_vec_u128().last_chunk::<2>().unwrap(),
-        _ => [f256_signif.as_vec_u128()[0], 0_u128],
+        2.. => *f256_signif.as_u128_chunks().last_chunk::<2>().unwrap(),
+        _ => [f256_signif.as_u128_chunks()[0], 0_u128],
     };
     U256::new(hi, lo)
 }
@@ -703,7 +703,7 @@ mod from_into_f256_tests {
         assert_eq!(f_exp, g.exp);
         let shl: u32 = (Float::<T>::FRACTION_BITS % 256) - FRACTION_BITS;
         let f_adj_signif = f_signif << shl;
-        let g_signif = g.signif.as_vec_u128();
+        let g_signif = g.signif.as_u128_chunks();
         let (g_signif_hi, g_signif_lo) = g_signif.split_at(2);
         assert_eq!(f_adj_signif, U256::from(g_signif_hi));
         assert!(g_signif_lo.iter().all(|x| *x == 0_u128));
@@ -1223,6 +1223,54 @@ impl Float512 {
         signif: U512::new(3_u128 << 125, 0, 0, 0),
         exp: 0,
     };
+    // PI = ◯₅₁₀(π) =
+    // 3.1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679821480865132823066470938446095505822317253594081284811
+    pub(crate) const PI: Self = Self::new(
+        1,
+        1,
+        &[
+            0x6487ed5110b4611a62633145c06e0e68,
+            0x948127044533e63a0105df531d89cd91,
+            0x28a5043cc71a026ef7ca8cd9e69d218d,
+            0x98158536f92f8a1ba7f09ab6b6a8e123,
+        ],
+    );
+    // FRAC_1_PI = ◯₅₁₀(1/π) =
+    // 3.1830988618379067153776752674502872406891929148091289749533468811779359526845307018022760553250617191214568545351591607378582369222915730575593482146339968e-1
+    pub(crate) const FRAC_1_PI: Self = Self::new(
+        1,
+        -2,
+        &[
+            0x517cc1b727220a94fe13abe8fa9a6ee0,
+            0x6db14acc9e21c820ff28b1d5ef5de2b0,
+            0xdb92371d2126e9700324977504e8c90e,
+            0x7f0ef58e5894d39f74411afa975da242,
+        ],
+    );
+    // FRAC_PI_180 = ◯₅₁₀(π/180) =
+    // 1.7453292519943295769236907684886127134428718885417254560971914401710091146034494436822415696345094822123044925073790592483854692275281012398474218934047117e-2
+    pub(crate) const FRAC_PI_180: Self = Self::new(
+        1,
+        -6,
+        &[
+            0x477d1a894a74e4570762fb374a42e26c,
+            0x805bd77a80daf35c728154da64a64289,
+            0x5b7b08b3c0c88a438862864b4ea8a064,
+            0xb06a535a4acc78f738d89b879e55f56e,
+        ],
+    );
+    // FRAC_180_PI = ◯₅₁₀(180/π) =
+    // 5.7295779513082320876798154814105170332405472466564321549160243861202847148321552632440968995851110944186223381632864893281448264601248315036068267863411942e1
+    pub(crate) const FRAC_180_PI: Self = Self::new(
+        1,
+        5,
+        &[
+            0x729770698f07dee1854ba9bfa0692beb,
+            0x9a41512fbe5f816e66d13a14d89c06c8,
+            0xb4c59d80f69eb845846b74fc8ee75abc,
+            0x62ad09502c9149983b7b8df064dbac2d,
+        ],
+    );
     // LN_2 = ◯₅₁₀(logₑ(2)) =
     // 6.9314718055994530941723212145817656807550013436025525412068000949339362196969471560586332699641868754200148102057068573368552023575813055703267075163507602e-1
     pub(crate) const LN_2: Self = Self::new(
@@ -1362,6 +1410,42 @@ mod add_sub_tests {
         assert_eq!(a, d);
     }
 
+    fn test_add_zero_<T: BigUInt + HiLo>() {
+        let tiny = Float::<T>::ONE.mul_pow2(-1000);
+        let mut f = tiny;
+        f += &Float::<T>::ZERO;
+        assert_eq!(f, tiny);
+        f -= &Float::<T>::ZERO;
+        assert_eq!(f, tiny);
+        let mut f = Float::<T>::ZERO;
+        f -= &tiny;
+        assert_eq!(f, -tiny);
+    }
+
+    #[test]
+    fn test_add_zero() {
+        test_add_zero_::<U256>();
+        test_add_zero_::<U512>();
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn test_sub_from_pow2_<T: BigUInt + HiLo + From<u128>>() {
+        // ¾ ulp of the binade below 1, but less than ½ ulp of 1
+        let d = Float::<T>::from(3).mul_pow2(-(T::BITS as i32) - 1);
+        let mut f = Float::<T>::ONE;
+        f -= &d;
+        assert_eq!(f, Float::<T>::ONE - Float::<T>::EPSILON.mul_pow2(-1));
+        let mut f = Float::<T>::ONE;
+        f += &d;
+        assert_eq!(f, Float::<T>::ONE);
+    }
+
+    #[test]
+    fn test_sub_from_pow2() {
+        test_sub_from_pow2_::<U256>();
+        test_sub_from_pow2_::<U512>();
+    }
+
     fn test_sub_diff_sign_<T: BigUInt + HiLo>() {
         let mut f = Float::<T>::NEG_ONE;
         f -= &Float::<T>::ONE;
@@ -1478,6 +1562,20 @@ mod mul_tests {
         test_imul_same_sign_::<U512>();
     }
 
+    fn test_imul_round_to_pow2_<T: BigUInt + HiLo + From<u128>>() {
+        // The exact product is less than 1, but rounds up to 1.
+        let x = Float::<T>::from(3);
+        let mut y = x.recip();
+        y *= x;
+        assert_eq!(y, Float::<T>::ONE);
+    }
+
+    #[test]
+    fn test_imul_round_to_pow2() {
+        test_imul_round_to_pow2_::<U256>();
+        test_imul_round_to_pow2_::<U512>();
+    }
+
     #[test]
     fn test_imul_diff_sign() {
         let mut x = Float256::FRAC_PI_2;
@@ -1594,7 +1692,7 @@ mod div_tests {
             -1,
             (
                 0x7ffff204dc4f6aaaaaaaaaaaaaaaaaaa,
-                0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa,
+                0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaab,
             ),
         );
         let mut q = x;
@@ -4142,7 +4142,7 @@ pub fn bkm_l(x: &Float512) -> Float512 {
     }
     let sh = ln.leading_zeros() - 1;
     ln <<= sh;
-    Float512::new(1, -(sh as i32), &ln.as_vec_u128())
+    Float512::new(1, -(sh as i32), &ln.as_u128_chunks())
 }
 
 // Returns an approximation of expₑ x.
@@ -0,0 +1,504 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use core::cmp::Ordering;
+
+use super::{
+    approx_cos::approx_cos, approx_sin::approx_sin,
+    approx_sin_cos::approx_sin_cos, asin_acos::atan, FP492,
+};
+use crate::{
+    abs_bits, abs_bits_sticky, f256, math::Float512, BinEncAnySpecial,
+    HI_ABS_MASK, HI_EXP_MASK, ONE_HALF, SIGNIFICAND_BITS,
+};
+
+const FP492_PI: FP492 = FP492::new(
+    0x00003243f6a8885a308d313198a2e037,
+    0x07344a4093822299f31d0082efa98ec4,
+    0xe6c89452821e638d01377be5466cf34e,
+    0x90c6cc0ac29b7c97c50dd3f84d5b5b54,
+);
+const FP492_FRAC_PI_180: FP492 = FP492::new(
+    0x000000477d1a894a74e4570762fb374a,
+    0x42e26c805bd77a80daf35c728154da64,
+    0xa642895b7b08b3c0c88a438862864b4e,
+    0xa8a064b06a535a4acc78f738d89b879e,
+);
+
+// Arguments with an exponent below this limit are not converted to FP492,
+// because that would not be exact. For these, the angle in radians is
+// < 2⁻¹¹⁸, so that two terms of the Taylor series give enough precision.
+const SMALL_EXP: i32 = -120;
+
+// |x| >= 2²³⁸ => atan x rounds to a quarter turn for all units
+const LARGE_EXP: i32 = 238;
+
+/// Unit of angles with an integral number of units per full turn, which
+/// allows an exact argument reduction.
+pub(super) struct AngleUnit {
+    // Number of units in a full turn
+    full_turn: u128,
+    // Number of units in a quarter turn
+    quarter_turn: f256,
+    // Factors converting the unit into radians and vice versa
+    fp_to_rad: FP492,
+    to_rad: Float512,
+    from_rad: Float512,
+}
+
+/// Angles given as multiples of π.
+pub(super) const HALF_TURNS: AngleUnit = AngleUnit {
+    full_turn: 2,
+    quarter_turn: ONE_HALF,
+    fp_to_rad: FP492_PI,
+    to_rad: Float512::PI,
+    from_rad: Float512::FRAC_1_PI,
+};
+
+/// Angles given in degrees.
+pub(super) const DEGREES: AngleUnit = AngleUnit {
+    full_turn: 360,
+    quarter_turn: f256::from_u64(90),
+    fp_to_rad: FP492_FRAC_PI_180,
+    to_rad: Float512::FRAC_PI_180,
+    from_rad: Float512::FRAC_180_PI,
+};
+
+// Returns 2ⁿ % m.
+const fn pow2_mod(mut n: u32, m: u128) -> u128 {
+    let mut res = 1 % m;
+    let mut base = 2 % m;
+    while n > 0 {
+        if n & 1 == 1 {
+            res = res * base % m;
+        }
+        base = base * base % m;
+        n >>= 1;
+    }
+    res
+}
+
+// Returns (x - x³/3)⋅f, i.e. atan(x) converted by f for tiny x.
+fn small_atan(x: &Float512, f: &Float512) -> f256 {
+    let x = *x;
+    f256::from(&((x - x * x * x / Float512::from(3)) * *f))
+}
+
+impl AngleUnit {
+    /// Calculate ⌈x/q⌋ % 4 and x - q⋅⌈x/q⌋ for finite x >= 0, where q is a
+    /// quarter turn.
+    ///
+    /// The second value is in [-½q, ½q] and is calculated exactly.
+    #[allow(clippy::cast_possible_wrap)]
+    #[allow(clippy::cast_sign_loss)]
+    fn reduce(&self, x: &f256) -> (u32, f256) {
+        debug_assert!(x.is_finite() && x.is_sign_positive());
+        let n = self.full_turn;
+        let r = if x.exponent() >= SIGNIFICAND_BITS as i32 {
+            // x = m⋅2ᵗ with t > 0 => x % n = (m % n)⋅(2ᵗ % n) % n
+            let m = x.integral_significand();
+            let m_mod_n =
+                ((m.hi.0 % n) * pow2_mod(u128::BITS, n) + m.lo.0 % n) % n;
+            let t = x.quantum_exponent() as u32;
+            f256::from(m_mod_n * pow2_mod(t, n) % n)
+        } else {
+            // The remainder is exact.
+            *x % f256::from(n)
+        };
+        let q = (r / self.quarter_turn).round();
+        // |r - q⋅quarter_turn| <= |r| and has no bits below ulp(r), so it
+        // is exact.
+        let y = r - q * self.quarter_turn;
+        (u32::try_from(&q).unwrap_or_default() % 4, y)
+    }
+
+    /// Returns y converted to radians as FP492, where |y| <= ½ quarter
+    /// turn and y.exponent() >= SMALL_EXP.
+    fn fp_to_rad(&self, y: &f256) -> FP492 {
+        let mut fy = FP492::from(y);
+        fy.imul_round(&self.fp_to_rad);
+        fy
+    }
+
+    /// Returns sin(y) for |y| <= ½ quarter turn.
+    fn sin_reduced(&self, y: &f256) -> f256 {
+        if y.eq_zero() {
+            return *y;
+        }
+        if y.exponent() < SMALL_EXP {
+            // sin(t) = t - t³/6 + …
+            let t = Float512::from(y) * self.to_rad;
+            return f256::from(&(t - t * t * t / Float512::from(6)));
+        }
+        f256::from(&approx_sin(&self.fp_to_rad(y)))
+    }
+
+    /// Returns cos(y) for |y| <= ½ quarter turn.
+    fn cos_reduced(&self, y: &f256) -> f256 {
+        if y.exponent() < SMALL_EXP {
+            // cos(t) = 1 - t²/2 + …
+            let t = Float512::from(y) * self.to_rad;
+            return f256::from(&(Float512::ONE - t.square().mul_pow2(-1)));
+        }
+        f256::from(&approx_cos(&self.fp_to_rad(y)))
+    }
+
+    /// Returns tan(y) for 0 < |y| <= ½ quarter turn, or -cot(y) if `cot`
+    /// is true.
+    fn tan_or_neg_cot_reduced(&self, y: &f256, cot: bool) -> f256 {
+        debug_assert!(!y.eq_zero());
+        let res = if y.exponent() < SMALL_EXP {
+            let t = Float512::from(y) * self.to_rad;
+            if cot {
+                // cot(t) = 1/t - t/3 - …
+                t / Float512::from(3) - t.recip()
+            } else {
+                // tan(t) = t + t³/3 + …
+                t + t * t * t / Float512::from(3)
+            }
+        } else {
+            let (sin, cos) = approx_sin_cos(&self.fp_to_rad(y));
+            let (sin, cos) = (Float512::from(&sin), Float512::from(&cos));
+            if cot {
+                -(cos / sin)
+            } else {
+                sin / cos
+            }
+        };
+        f256::from(&res)
+    }
+
+    // Returns sin(x) for x = q⋅¼τ + y, where q is the quadrant.
+    fn sin_quadrant(&self, quadrant: u32, y: &f256) -> f256 {
+        match quadrant {
+            // sin(n⋅½τ) = +0 for n > 0
+            0 | 2 if y.eq_zero() => f256::ZERO,
+            0 => self.sin_reduced(y),
+            1 => self.cos_reduced(y),
+            2 => -self.sin_reduced(y),
+            3 => -self.cos_reduced(y),
+            _ => unreachable!(),
+        }
+    }
+
+    // Returns cos(x) for x = q⋅¼τ + y, where q is the quadrant.
+    fn cos_quadrant(&self, quadrant: u32, y: &f256) -> f256 {
+        match quadrant {
+            // cos((n + ½)⋅½τ) = +0
+            1 | 3 if y.eq_zero() => f256::ZERO,
+            0 => self.cos_reduced(y),
+            1 => -self.sin_reduced(y),
+            2 => -self.cos_reduced(y),
+            3 => self.sin_reduced(y),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Computes the sine of x, which is exactly ±0 for multiples of a half
+    /// turn (with the sign of x).
+    pub(super) fn sin(&self, x: &f256) -> f256 {
+        if x.is_special() {
+            // x is NAN or infinite => sine x is NAN
+            if (x.bits.hi.0 & HI_ABS_MASK) > f256::MAX.bits.hi.0 {
+                return f256::NAN;
+            }
+            // x = ±0 => sine x = ±0
+            return *x;
+        }
+        let (quadrant, y) = self.reduce(&x.abs());
+        let res = self.sin_quadrant(quadrant, &y);
+        // sine is an odd function
+        [res, -res][x.sign() as usize]
+    }
+
+    /// Computes the cosine of x, which is exactly +0 for odd multiples of a
+    /// quarter turn.
+    pub(super) fn cos(&self, x: &f256) -> f256 {
+        if x.is_special() {
+            // x is NAN or infinite => cosine x is NAN
+            if (x.bits.hi.0 & HI_ABS_MASK) > f256::MAX.bits.hi.0 {
+                return f256::NAN;
+            }
+            // x = 0 => cosine x = 1
+            return f256::ONE;
+        }
+        let (quadrant, y) = self.reduce(&x.abs());
+        // cosine is an even function
+        self.cos_quadrant(quadrant, &y)
+    }
+
+    /// Simultaneously computes the sine and cosine of x.
+    pub(super) fn sin_cos(&self, x: &f256) -> (f256, f256) {
+        if x.is_special() {
+            // x is NAN or infinite => sine x and cosine x are NAN
+            if (x.bits.hi.0 & HI_ABS_MASK) > f256::MAX.bits.hi.0 {
+                return (f256::NAN, f256::NAN);
+            }
+            // x = ±0 => sine x = ±0 and cosine x = 1
+            return (*x, f256::ONE);
+        }
+        let (quadrant, y) = self.reduce(&x.abs());
+        let sin = self.sin_quadrant(quadrant, &y);
+        let cos = self.cos_quadrant(quadrant, &y);
+        ([sin, -sin][x.sign() as usize], cos)
+    }
+
+    /// Computes the tangent of x, which is exactly ±0 for multiples of a
+    /// half turn, ±1 for odd multiples of an eighth turn and ±∞ for odd
+    /// multiples of a quarter turn.
+    pub(super) fn tan(&self, x: &f256) -> f256 {
+        if x.is_special() {
+            // x is NAN or infinite => tangent x is NAN
+            if (x.bits.hi.0 & HI_ABS_MASK) > f256::MAX.bits.hi.0 {
+                return f256::NAN;
+            }
+            // x = ±0 => tangent x = ±0
+            return *x;
+        }
+        let (quadrant, y) = self.reduce(&x.abs());
+        let res = if y.eq_zero() {
+            // tan(n⋅½τ) = ±0, tan((n + ½)⋅½τ) = ±∞
+            [
+                f256::ZERO,
+                f256::INFINITY,
+                f256::NEG_ZERO,
+                f256::NEG_INFINITY,
+            ][quadrant as usize]
+        } else if y.abs() == self.quarter_turn.div2() {
+            // tan(n⋅½τ ± ⅛τ) = ±1
+            [f256::ONE, f256::NEG_ONE][((quadrant % 2) ^ y.sign()) as usize]
+        } else {
+            self.tan_or_neg_cot_reduced(&y, quadrant % 2 == 1)
+        };
+        // tangent is an odd function
+        [res, -res][x.sign() as usize]
+    }
+
+    // Returns atan(x), converted from radians.
+    fn atan_from_rad(&self, x: &Float512) -> Float512 {
+        Float512::from(&atan(x)) * self.from_rad
+    }
+
+    /// Computes the arcsine of x, which is exactly ±¼τ for x = ±1.
+    pub(super) fn asin(&self, x: &f256) -> f256 {
+        let abs_bits_x = abs_bits(x);
+        // if x is NAN or |x| > 1, asin x is NAN
+        if abs_bits_x > f256::ONE.bits {
+            return f256::NAN;
+        }
+        // asin(±1) = ±¼τ
+        if abs_bits_x == f256::ONE.bits {
+            return [self.quarter_turn, -self.quarter_turn]
+                [x.sign() as usize];
+        }
+        // asin(±0) = ±0
+        if x.eq_zero() {
+            return *x;
+        }
+        if x.exponent() < SMALL_EXP {
+            // asin(x) = x + x³/6 + …
+            let x = Float512::from(x);
+            return f256::from(
+                &((x + x * x * x / Float512::from(6)) * self.from_rad),
+            );
+        }
+        // Now we have ε < |x| < 1
+        // asin(x) = atan(x/√(1-x²)), where 1-x² = (1-x)⋅(1+x) is used to
+        // avoid cancellation. 1-x and 1+x are exact.
+        let x = Float512::from(x);
+        let q = x / ((Float512::ONE - x) * (Float512::ONE + x)).sqrt();
+        f256::from(&self.atan_from_rad(&q))
+    }
+
+    /// Computes the arccosine of x, which is exactly 0 for x = 1, ¼τ for
+    /// x = 0 and ½τ for x = -1.
+    pub(super) fn acos(&self, x: &f256) -> f256 {
+        let abs_bits_x = abs_bits(x);
+        // if x is NAN or |x| > 1, acos x is NAN
+        if abs_bits_x > f256::ONE.bits {
+            return f256::NAN;
+        }
+        // acos(1) = 0, acos(-1) = ½τ
+        if abs_bits_x == f256::ONE.bits {
+            return [f256::ZERO, self.quarter_turn.mul2()][x.sign() as usize];
+        }
+        // acos(±0) = ¼τ
+        if x.eq_zero() {
+            return self.quarter_turn;
+        }
+        // Now we have 0 < |x| < 1
+        // acos(|x|) = atan(√(1-x²)/|x|), where 1-x² = (1-|x|)⋅(1+|x|) is
+        // used to avoid cancellation. 1-|x| and 1+|x| are exact.
+        let x_abs = Float512::from(&x.abs());
+        let a = self.atan_from_rad(
+            &(((Float512::ONE - x_abs) * (Float512::ONE + x_abs)).sqrt()
+                / x_abs),
+        );
+        // acos(-x) = ½τ - acos(x)
+        match x.sign() {
+            0 => f256::from(&a),
+            _ => f256::from(&(Float512::from(&self.quarter_turn.mul2()) - a)),
+        }
+    }
+
+    /// Computes the arctangent of x, which is exactly ±⅛τ for x = ±1 and
+    /// ±¼τ for x = ±∞.
+    pub(super) fn atan(&self, x: &f256) -> f256 {
+        let abs_bits_x = abs_bits(x);
+        // If x is NAN, atan x is NAN.
+        if (abs_bits_x.hi.0 | (abs_bits_x.lo.0 != 0) as u128) > HI_EXP_MASK {
+            return f256::NAN;
+        }
+        // atan(±0) = ±0
+        if x.eq_zero() {
+            return *x;
+        }
+        // If |x| >= 2²³⁸ (incl. ∞), atan x = ±¼τ.
+        if x.is_infinite() || x.exponent() >= LARGE_EXP {
+            return [self.quarter_turn, -self.quarter_turn]
+                [x.sign() as usize];
+        }
+        // atan(±1) = ±⅛τ
+        if abs_bits_x == f256::ONE.bits {
+            let eighth_turn = self.quarter_turn.div2();
+            return [eighth_turn, -eighth_turn][x.sign() as usize];
+        }
+        if x.exponent() < SMALL_EXP {
+            return small_atan(&Float512::from(x), &self.from_rad);
+        }
+        f256::from(&self.atan_from_rad(&Float512::from(x)))
+    }
+
+    /// Computes the four quadrant arctangent of y and x.
+    pub(super) fn atan2(&self, y: &f256, x: &f256) -> f256 {
+        let abs_bits_x = abs_bits(x);
+        let abs_bits_y = abs_bits(y);
+        let abs_bits_sticky_x = abs_bits_sticky(&abs_bits_x);
+        let abs_bits_sticky_y = abs_bits_sticky(&abs_bits_y);
+        let quarter_turn = self.quarter_turn;
+        let half_turn = quarter_turn.mul2();
+        let eighth_turn = quarter_turn.div2();
+        let sign_y = y.sign() as usize;
+        if (abs_bits_sticky_x, abs_bits_sticky_y).any_special() {
+            if abs_bits_sticky_x.max(abs_bits_sticky_y) > HI_EXP_MASK {
+                // Atleast one operand is NAN.
+                return f256::NAN;
+            }
+            // y = ±0, x >= +0 or x = +∞ => ±0
+            // y = ±0, x <= -0 or x = -∞ => ±½τ
+            let zero_or_half_turn =
+                [[f256::ZERO, f256::NEG_ZERO], [half_turn, -half_turn]]
+                    [x.sign() as usize][sign_y];
+            if abs_bits_sticky_y == 0_u128 {
+                return zero_or_half_turn;
+            }
+            if abs_bits_sticky_x == 0_u128 {
+                // x = ±0, y != 0 => ±¼τ
+                return [quarter_turn, -quarter_turn][sign_y];
+            }
+            if abs_bits_sticky_y == HI_EXP_MASK {
+                // y = ±∞
+                let res = if abs_bits_sticky_x < HI_EXP_MASK {
+                    quarter_turn
+                } else if x.sign() == 0 {
+                    eighth_turn
+                } else {
+                    quarter_turn + eighth_turn
+                };
+                return [res, -res][sign_y];
+            }
+            // x = ±∞, y finite
+            return zero_or_half_turn;
+        }
+
+        // Both operands are finite and non-zero.
+
+        let sign_q = (y.sign() + x.sign()) % 2;
+        let atan = match abs_bits_y.cmp(&abs_bits_x) {
+            Ordering::Less => {
+                let q = Float512::from(y) / Float512::from(x);
+                if x.sign() == 0 && q.exp() < SMALL_EXP {
+                    return small_atan(&q, &self.from_rad);
+                }
+                self.atan_from_rad(&q)
+            }
+            Ordering::Greater => {
+                self.atan_from_rad(&(Float512::from(y) / Float512::from(x)))
+            }
+            // |y| = |x| => atan(±1) = ±⅛τ
+            _ => {
+                Float512::from(&[eighth_turn, -eighth_turn][sign_q as usize])
+            }
+        };
+        let res = match (y.sign(), x.sign()) {
+            (0, 1) => atan + Float512::from(&half_turn),
+            (1, 1) => atan - Float512::from(&half_turn),
+            _ => atan,
+        };
+        f256::from(&res)
+    }
+}
+
+#[cfg(test)]
+mod angle_unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_pow2_mod() {
+        for n in [0_u32, 1, 2, 3, 7, 64, 127] {
+            for m in [2_u128, 3, 360] {
+                assert_eq!(pow2_mod(n, m), (1_u128 << n) % m, "{n} {m}");
+            }
+        }
+        assert_eq!(pow2_mod(128, 360), 256);
+        assert_eq!(pow2_mod(262143, 2), 0);
+    }
+
+    #[test]
+    fn test_reduce_large() {
+        for unit in [HALF_TURNS, DEGREES] {
+            for f in [f256::MAX, f256::EPSILON.recip(), f256::MAX.div2()] {
+                let (q, y) = unit.reduce(&f);
+                let n = unit.full_turn;
+                let m = f.integral_significand();
+                let t = f.quantum_exponent();
+                let mut r = f256::from((m.hi.0 % n) * pow2_mod(128, n));
+                r += f256::from(m.lo.0 % n);
+                r %= f256::from(n);
+                let mut p = f256::ONE;
+                for _ in 0..t {
+                    p = p.mul2() % f256::from(n);
+                }
+                r = r * p % f256::from(n);
+                assert_eq!(
+                    f256::from(q) * unit.quarter_turn + y,
+                    r.max(f256::ZERO),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_reduce_degrees() {
+        let cases = [
+            (0_u32, 0_u32, 0_i32),
+            (45, 1, -45),
+            (46, 1, -44),
+            (180, 2, 0),
+            (359, 0, -1),
+            (360, 0, 0),
+            (720 + 271, 3, 1),
+            (123456789, 2, 9),
+        ];
+        for (x, q, y) in cases {
+            let (quadrant, r) = DEGREES.reduce(&f256::from(x));
+            assert_eq!((quadrant, r), (q, f256::from(y)), "{x}");
+        }
+    }
+}
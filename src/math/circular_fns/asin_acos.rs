@@ -7,11 +7,11 @@
 // $Source$
 // $Revision$
 
-use super::{approx_atan::approx_atan, Float256, FP492};
+use super::{approx_atan::approx_atan, Float, Float256, FP492};
 use crate::{
     abs_bits,
     consts::{FRAC_PI_2, PI},
-    f256, HI_EXP_MASK, U256,
+    f256, BigUInt, HiLo, HI_EXP_MASK, U256,
 };
 use core::cmp::Ordering;
 
@@ -41,10 +41,14 @@ const FP492_NEG_FRAC_PI_4: FP492 = FP492::new(
 );
 
 /// Computes the arctangent of a number (in radians).
-fn atan(x: &Float256) -> FP492 {
+pub(super) fn atan<T>(x: &Float<T>) -> FP492
+where
+    T: BigUInt + HiLo + for<'a> From<&'a [u128]>,
+    for<'a> FP492: From<&'a Float<T>>,
+{
     let x_abs = x.abs();
     let sign = (x.signum() < 0) as usize;
-    match x_abs.cmp(&Float256::ONE) {
+    match x_abs.cmp(&Float::<T>::ONE) {
         Ordering::Less => approx_atan(&FP492::from(x)),
         Ordering::Greater => {
             // atan(±x) = ±½π - atan(1/x) for |x| > 1
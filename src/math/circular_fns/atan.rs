@@ -135,7 +135,8 @@ impl f256 {
                 [Float256::FRAC_PI_2, -Float256::FRAC_PI_2][sign_q as usize]
                     - Float256::from(&approx_atan(&FP492::from(&q)))
             }
-            _ => [Float256::FRAC_PI_2, -Float256::FRAC_PI_2][sign_q as usize],
+            // |y| = |x| => atan(±1) = ±¼π
+            _ => [Float256::FRAC_PI_4, -Float256::FRAC_PI_4][sign_q as usize],
         };
         match (self.sign(), other.sign()) {
             (0, 1) => {
@@ -277,4 +278,15 @@ mod atan_tests {
         let f = n / d;
         assert_eq!(n.atan2(&d), a);
     }
+
+    #[test]
+    fn test_atan2_diagonals() {
+        let one = f256::ONE;
+        let f = f256::from(7);
+        assert_eq!(one.atan2(&one), FRAC_PI_4);
+        assert_eq!(f.atan2(&f), FRAC_PI_4);
+        assert_eq!((-f).atan2(&f), -FRAC_PI_4);
+        assert_eq!(f.atan2(&-f), PI - FRAC_PI_4);
+        assert_eq!((-f).atan2(&-f), FRAC_PI_4 - PI);
+    }
 }
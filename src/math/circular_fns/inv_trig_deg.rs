@@ -0,0 +1,409 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use super::angle_unit::DEGREES;
+use crate::f256;
+
+impl f256 {
+    /// Computes the arcsine of a number in degrees.
+    ///
+    /// Return value is in the range [-90, 90], or NaN if the number is
+    /// outside the range [-1, 1]. `asind(±1)` is exactly ±90.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// assert_eq!(f256::NEG_ONE.asind(), f256::from(-90));
+    /// assert_eq!(f256::from(0.5).asind(), f256::from(30));
+    /// ```
+    #[must_use]
+    pub fn asind(&self) -> Self {
+        DEGREES.asin(self)
+    }
+
+    /// Computes the arccosine of a number in degrees.
+    ///
+    /// Return value is in the range [0, 180], or NaN if the number is
+    /// outside the range [-1, 1]. `acosd(1)` is exactly 0, `acosd(0)`
+    /// exactly 90 and `acosd(-1)` exactly 180.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// assert_eq!(f256::NEG_ONE.acosd(), f256::from(180));
+    /// assert_eq!(f256::ZERO.acosd(), f256::from(90));
+    /// assert_eq!(f256::from(-0.5).acosd(), f256::from(120));
+    /// ```
+    #[must_use]
+    pub fn acosd(&self) -> Self {
+        DEGREES.acos(self)
+    }
+
+    /// Computes the arctangent of a number in degrees.
+    ///
+    /// Return value is in the range [-90, 90]. `atand(±1)` is exactly ±45
+    /// and `atand(±∞)` exactly ±90.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// assert_eq!(f256::NEG_ONE.atand(), f256::from(-45));
+    /// assert_eq!(f256::INFINITY.atand(), f256::from(90));
+    /// ```
+    #[must_use]
+    pub fn atand(&self) -> Self {
+        DEGREES.atan(self)
+    }
+
+    /// Computes the four quadrant arctangent of `self` (`y`) and `other`
+    /// (`x`) in degrees.
+    ///
+    /// * `x = ±0`, `y = ±0`: `±0` for `x = +0` and `±180` for `x = -0`
+    /// * `x >= 0`: `arctan(y/x)` -> `[-90, 90]`
+    /// * `y >= 0`: `arctan(y/x) + 180` -> `(90, 180]`
+    /// * `y < 0`: `arctan(y/x) - 180` -> `[-180, -90)`
+    ///
+    /// Results which are multiples of 45 are exact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// let (x, y) = (f256::from(-2), f256::from(2));
+    /// assert_eq!(y.atan2d(&x), f256::from(135));
+    /// assert_eq!(f256::ZERO.atan2d(&x), f256::from(180));
+    /// assert_eq!(f256::NEG_ONE.atan2d(&f256::ZERO), f256::from(-90));
+    /// ```
+    #[must_use]
+    pub fn atan2d(&self, other: &Self) -> Self {
+        DEGREES.atan2(self, other)
+    }
+}
+
+#[cfg(test)]
+mod inv_trig_deg_tests {
+    use super::*;
+    use crate::ONE_HALF;
+
+    #[test]
+    fn test_asind_acosd() {
+        for f in [f256::NAN, f256::INFINITY, f256::from(1.5), -f256::TWO] {
+            assert!(f.asind().is_nan());
+            assert!(f.acosd().is_nan());
+        }
+        let (d90, d180) = (f256::from(90), f256::from(180));
+        assert_eq!(f256::ZERO.asind().to_bits(), f256::ZERO.to_bits());
+        assert_eq!(
+            f256::NEG_ZERO.asind().to_bits(),
+            f256::NEG_ZERO.to_bits()
+        );
+        assert_eq!(f256::ONE.asind(), d90);
+        assert_eq!(f256::NEG_ONE.asind(), -d90);
+        assert_eq!(f256::ZERO.acosd(), d90);
+        assert_eq!(f256::NEG_ZERO.acosd(), d90);
+        assert_eq!(f256::ONE.acosd(), f256::ZERO);
+        assert_eq!(f256::NEG_ONE.acosd(), d180);
+        assert_eq!(ONE_HALF.asind(), f256::from(30));
+        assert_eq!(ONE_HALF.acosd(), f256::from(60));
+        assert_eq!((-ONE_HALF).asind(), f256::from(-30));
+        assert_eq!((-ONE_HALF).acosd(), f256::from(120));
+        // (x, asind(x), acosd(x))
+        let cases = [
+            (
+                0.1,
+                f256::from_sign_exp_signif(
+                    0,
+                    -234,
+                    (
+                        0x000016f4e91b081e44fd85b9894722ae,
+                        0x7052d249fe65cf4e67227895e11b0a78,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -230,
+                    (
+                        0x00001510b16e4f7e1bb027a4676b8dd5,
+                        0x18fad2db6019a30b198dd876a1ee4f58,
+                    ),
+                ),
+            ),
+            (
+                -0.7,
+                f256::from_sign_exp_signif(
+                    1,
+                    -231,
+                    (
+                        0x00001636a8112d5c5eb1da19aa5a19e8,
+                        0xe348d15c58a6fcae45a330ba72ff81e8,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -229,
+                    (
+                        0x000010cdaa044b5717ac76866a96867a,
+                        0x38d234571629bf2b9168cc2e9cbfe07a,
+                    ),
+                ),
+            ),
+            (
+                0.9999999,
+                f256::from_sign_exp_signif(
+                    0,
+                    -230,
+                    (
+                        0x0000167e5c2f7428d5fa40d8c57b19b4,
+                        0x08047a767078ca9d54cc0011cf5a9fd2,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -242,
+                    (
+                        0x00001a3d08bd72a05bf273a84e64bf7f,
+                        0xb85898f873562ab33ffee30a5602e309,
+                    ),
+                ),
+            ),
+            (
+                1e-20,
+                f256::from_sign_exp_signif(
+                    0,
+                    -297,
+                    (
+                        0x000015236ef1b4e0fd153e3232e94a3e,
+                        0x0ae1fa45f532e562485e61ec26307709,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -230,
+                    (
+                        0x0000167ffffffffffffffd5b9221c963,
+                        0xe05d5839b9a2d6b83ea3c0b74159a354,
+                    ),
+                ),
+            ),
+            (
+                0.25,
+                f256::from_sign_exp_signif(
+                    0,
+                    -233,
+                    (
+                        0x00001cf47c7a2c055f85de7e7a471fed,
+                        0xebc1daf8f424e5f3a5c50466f13da8da,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -230,
+                    (
+                        0x000012e17070ba7f540f443030b71c02,
+                        0x4287c4a0e17b63418b475f7321d84ae5,
+                    ),
+                ),
+            ),
+        ];
+        for (x, asin, acos) in cases {
+            let x = f256::from(x);
+            assert_eq!(x.asind(), asin, "{x:?}");
+            assert_eq!(x.acosd(), acos, "{x:?}");
+        }
+    }
+
+    #[test]
+    fn test_atand() {
+        let (d45, d90) = (f256::from(45), f256::from(90));
+        assert!(f256::NAN.atand().is_nan());
+        assert_eq!(f256::ZERO.atand().to_bits(), f256::ZERO.to_bits());
+        assert_eq!(
+            f256::NEG_ZERO.atand().to_bits(),
+            f256::NEG_ZERO.to_bits()
+        );
+        assert_eq!(f256::INFINITY.atand(), d90);
+        assert_eq!(f256::NEG_INFINITY.atand(), -d90);
+        assert_eq!(f256::MAX.atand(), d90);
+        assert_eq!(f256::ONE.atand(), d45);
+        assert_eq!(f256::NEG_ONE.atand(), -d45);
+        assert!(f256::EPSILON.recip().atand() < d90);
+        assert_eq!(f256::from(3).sqrt().atand(), f256::from(60));
+        // (x, atand(x))
+        let cases = [
+            (
+                0.1,
+                f256::from_sign_exp_signif(
+                    0,
+                    -234,
+                    (
+                        0x000016d7a5ba394abfc43ae219db3693,
+                        0x95fa508ac672e68efd15179ef93982da,
+                    ),
+                ),
+            ),
+            (
+                -0.7,
+                f256::from_sign_exp_signif(
+                    1,
+                    -231,
+                    (
+                        0x0000117efa8492e3f3a36ac4f77d084e,
+                        0x9ee9ff5be9faa07c5f7e3bad35d9e6f1,
+                    ),
+                ),
+            ),
+            (
+                12345.678,
+                f256::from_sign_exp_signif(
+                    0,
+                    -230,
+                    (
+                        0x0000167fb3f668be4a8186c5d4bae94a,
+                        0x8a65ec4a3ef4723dfb14f5eae8a36d1d,
+                    ),
+                ),
+            ),
+            (
+                1e-20,
+                f256::from_sign_exp_signif(
+                    0,
+                    -297,
+                    (
+                        0x000015236ef1b4e0fd153e3232e94a3e,
+                        0x0ae1f9e9e3150d7559667261ddbd6db4,
+                    ),
+                ),
+            ),
+            (
+                -3e+40,
+                f256::from_sign_exp_signif(
+                    1,
+                    -230,
+                    (
+                        0x0000167fffffffffffffffffffffffff,
+                        0xffffffd6682db23ee3e56d277cc504eb,
+                    ),
+                ),
+            ),
+        ];
+        for (x, atan) in cases {
+            let x = f256::from(x);
+            assert_eq!(x.atand(), atan, "{x:?}");
+        }
+    }
+
+    #[test]
+    fn test_atan2d_special() {
+        let (d45, d90, d135, d180) = (
+            f256::from(45),
+            f256::from(90),
+            f256::from(135),
+            f256::from(180),
+        );
+        let zeros = [f256::ZERO, f256::NEG_ZERO];
+        let infs = [f256::INFINITY, f256::NEG_INFINITY];
+        for y in [f256::NAN, f256::ONE, f256::INFINITY] {
+            assert!(y.atan2d(&f256::NAN).is_nan());
+            assert!(f256::NAN.atan2d(&y).is_nan());
+        }
+        for (s, y) in zeros.iter().enumerate() {
+            let exp = [[f256::ZERO, f256::NEG_ZERO][s], [d180, -d180][s]];
+            for (i, x) in [zeros[0], f256::ONE, infs[0]].iter().enumerate() {
+                assert_eq!(y.atan2d(x).to_bits(), exp[0].to_bits(), "{i}");
+            }
+            for (i, x) in
+                [zeros[1], f256::NEG_ONE, infs[1]].iter().enumerate()
+            {
+                assert_eq!(y.atan2d(x).to_bits(), exp[1].to_bits(), "{i}");
+            }
+        }
+        for x in zeros {
+            assert_eq!(f256::TEN.atan2d(&x), d90);
+            assert_eq!(infs[0].atan2d(&x), d90);
+            assert_eq!(f256::NEG_ONE.atan2d(&x), -d90);
+            assert_eq!(infs[1].atan2d(&x), -d90);
+        }
+        assert_eq!(f256::ONE.atan2d(&infs[1]), d180);
+        assert_eq!(f256::NEG_ONE.atan2d(&infs[1]), -d180);
+        assert_eq!(infs[0].atan2d(&f256::NEG_ONE), d90);
+        assert_eq!(infs[0].atan2d(&infs[0]), d45);
+        assert_eq!(infs[0].atan2d(&infs[1]), d135);
+        assert_eq!(infs[1].atan2d(&infs[0]), -d45);
+        assert_eq!(infs[1].atan2d(&infs[1]), -d135);
+    }
+
+    #[test]
+    fn test_atan2d() {
+        let (d45, d135) = (f256::from(45), f256::from(135));
+        let one = f256::ONE;
+        assert_eq!(one.atan2d(&one), d45);
+        assert_eq!(one.atan2d(&-one), d135);
+        assert_eq!((-one).atan2d(&one), -d45);
+        assert_eq!((-one).atan2d(&-one), -d135);
+        // (y, x, atan2d(y, x))
+        let cases = [
+            (
+                1.0,
+                2.0,
+                f256::from_sign_exp_signif(
+                    0,
+                    -232,
+                    (
+                        0x00001a90a731a61dc3cfe9b09b23cfaa,
+                        0xba7f3417921429f07b068ef543be6bff,
+                    ),
+                ),
+            ),
+            (
+                -3.5,
+                -0.25,
+                f256::from_sign_exp_signif(
+                    1,
+                    -230,
+                    (
+                        0x000017857abecd7ec76e97da27d76fc3,
+                        0x743411e7db05ea46fdf0a0762b040eb2,
+                    ),
+                ),
+            ),
+            (
+                2.5,
+                -7.0,
+                f256::from_sign_exp_signif(
+                    0,
+                    -229,
+                    (
+                        0x0000140b13df91aa564e1d9c2561552d,
+                        0x4fed4a131a562c06a14d40e937bd03eb,
+                    ),
+                ),
+            ),
+            (
+                -1e-30,
+                5.0,
+                f256::from_sign_exp_signif(
+                    1,
+                    -333,
+                    (
+                        0x00001d0d6adaaeaaff7a6e63d7631f57,
+                        0x6858337fdfd0218ee1c36b01f48a1e89,
+                    ),
+                ),
+            ),
+        ];
+        for (y, x, atan2) in cases {
+            let (y, x) = (f256::from(y), f256::from(x));
+            assert_eq!(y.atan2d(&x), atan2, "{y:?} {x:?}");
+        }
+        assert_eq!(f256::MIN_GT_ZERO.atan2d(&f256::MAX), f256::ZERO);
+    }
+}
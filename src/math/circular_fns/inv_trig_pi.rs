@@ -0,0 +1,446 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use super::angle_unit::HALF_TURNS;
+use crate::f256;
+
+impl f256 {
+    /// Computes the arcsine of a number in half turns, i.e. asin(x)/π.
+    ///
+    /// Return value is in the range [-½, ½], or NaN if the number is outside
+    /// the range [-1, 1]. `asinpi(±1)` is exactly ±½.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// assert_eq!(f256::NEG_ONE.asinpi(), f256::from(-0.5));
+    /// let f = f256::from(0.5).asinpi();
+    /// assert_eq!(f, f256::ONE / f256::from(6));
+    /// ```
+    #[must_use]
+    pub fn asinpi(&self) -> Self {
+        HALF_TURNS.asin(self)
+    }
+
+    /// Computes the arccosine of a number in half turns, i.e. acos(x)/π.
+    ///
+    /// Return value is in the range [0, 1], or NaN if the number is outside
+    /// the range [-1, 1]. `acospi(1)` is exactly 0, `acospi(0)` exactly ½ and
+    /// `acospi(-1)` exactly 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// assert_eq!(f256::NEG_ONE.acospi(), f256::ONE);
+    /// assert_eq!(f256::ZERO.acospi(), f256::from(0.5));
+    /// let f = f256::from(-0.5).acospi();
+    /// assert_eq!(f, f256::TWO / f256::from(3));
+    /// ```
+    #[must_use]
+    pub fn acospi(&self) -> Self {
+        HALF_TURNS.acos(self)
+    }
+
+    /// Computes the arctangent of a number in half turns, i.e. atan(x)/π.
+    ///
+    /// Return value is in the range [-½, ½]. `atanpi(±1)` is exactly ±¼ and
+    /// `atanpi(±∞)` exactly ±½.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// assert_eq!(f256::NEG_ONE.atanpi(), f256::from(-0.25));
+    /// assert_eq!(f256::INFINITY.atanpi(), f256::from(0.5));
+    /// assert_eq!(f256::MAX.atanpi(), f256::from(0.5));
+    /// ```
+    #[must_use]
+    pub fn atanpi(&self) -> Self {
+        HALF_TURNS.atan(self)
+    }
+
+    /// Computes the four quadrant arctangent of `self` (`y`) and `other`
+    /// (`x`) in half turns, i.e. atan2(y, x)/π.
+    ///
+    /// * `x = ±0`, `y = ±0`: `±0` for `x = +0` and `±1` for `x = -0`
+    /// * `x >= 0`: `arctan(y/x)/π` -> `[-½, ½]`
+    /// * `y >= 0`: `arctan(y/x)/π + 1` -> `(½, 1]`
+    /// * `y < 0`: `arctan(y/x)/π - 1` -> `[-1, -½)`
+    ///
+    /// Results which are multiples of ¼ are exact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// let (x, y) = (f256::from(-2), f256::from(2));
+    /// assert_eq!(y.atan2pi(&x), f256::from(0.75));
+    /// assert_eq!(f256::ZERO.atan2pi(&x), f256::ONE);
+    /// assert_eq!(f256::NEG_ONE.atan2pi(&f256::ZERO), f256::from(-0.5));
+    /// ```
+    #[must_use]
+    pub fn atan2pi(&self, other: &Self) -> Self {
+        HALF_TURNS.atan2(self, other)
+    }
+}
+
+#[cfg(test)]
+mod inv_trig_pi_tests {
+    use super::*;
+    use crate::ONE_HALF;
+
+    const ONE_QUARTER: f256 = f256::power_of_two(-2);
+
+    #[test]
+    fn test_asinpi() {
+        for f in [f256::NAN, f256::INFINITY, f256::from(1.5), -f256::TWO] {
+            assert!(f.asinpi().is_nan());
+        }
+        assert_eq!(f256::ZERO.asinpi().to_bits(), f256::ZERO.to_bits());
+        assert_eq!(
+            f256::NEG_ZERO.asinpi().to_bits(),
+            f256::NEG_ZERO.to_bits()
+        );
+        assert_eq!(f256::ONE.asinpi(), ONE_HALF);
+        assert_eq!(f256::NEG_ONE.asinpi(), -ONE_HALF);
+        // (x, asinpi(x), acospi(x))
+        let cases = [
+            (
+                0.1,
+                f256::from_sign_exp_signif(
+                    0,
+                    -241,
+                    (
+                        0x0000105322eb667beccb09c283c0ceb4,
+                        0xef29d41ddcba2d048d9b5b70450d8a4a,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -238,
+                    (
+                        0x00001df59ba2933082669ec7af87e629,
+                        0x621ac57c4468ba5f6e4c9491f75e4eb7,
+                    ),
+                ),
+            ),
+            (
+                -0.7,
+                f256::from_sign_exp_signif(
+                    1,
+                    -239,
+                    (
+                        0x00001f97b07373b6920e02fcae02fd06,
+                        0xf39ac35b83c5aba2852c5c0edc7113c7,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -237,
+                    (
+                        0x000017e5ec1cdceda48380bf2b80bf41,
+                        0xbce6b0d6e0f16ae8a14b1703b71c44f2,
+                    ),
+                ),
+            ),
+            (
+                0.9999999,
+                f256::from_sign_exp_signif(
+                    0,
+                    -238,
+                    (
+                        0x00001ffdaaee280c9108e4c284f357c1,
+                        0x777dd5fdc7c83c9b7e49f4b89e5ec12a,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -249,
+                    (
+                        0x000012a88ebf9b77b8d9ebd86541f444,
+                        0x115011c1be1b240db05a3b0d09f6acd3,
+                    ),
+                ),
+            ),
+            (
+                1e-20,
+                f256::from_sign_exp_signif(
+                    0,
+                    -305,
+                    (
+                        0x00001e1042c3d96d7eb21f9159794d25,
+                        0x09c9ec7a404862ade9c4cf88bed8d6cf,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -238,
+                    (
+                        0x00001ffffffffffffffffc3df7a784d2,
+                        0x5029bc0dd4d0d65b5ec6c270b7f6f3aa,
+                    ),
+                ),
+            ),
+            (
+                -0.5,
+                f256::from_sign_exp_signif(
+                    1,
+                    -239,
+                    (
+                        0x00001555555555555555555555555555,
+                        0x55555555555555555555555555555555,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -237,
+                    (
+                        0x00001555555555555555555555555555,
+                        0x55555555555555555555555555555555,
+                    ),
+                ),
+            ),
+        ];
+        for (x, asin, acos) in cases {
+            let x = f256::from(x);
+            assert_eq!(x.asinpi(), asin, "{x:?}");
+            assert_eq!(x.acospi(), acos, "{x:?}");
+        }
+    }
+
+    #[test]
+    fn test_acospi() {
+        for f in [f256::NAN, f256::INFINITY, f256::from(1.5), -f256::TWO] {
+            assert!(f.acospi().is_nan());
+        }
+        assert_eq!(f256::ZERO.acospi(), ONE_HALF);
+        assert_eq!(f256::NEG_ZERO.acospi(), ONE_HALF);
+        assert_eq!(f256::ONE.acospi(), f256::ZERO);
+        assert_eq!(f256::NEG_ONE.acospi(), f256::ONE);
+        // Near 1 there is no cancellation.
+        let f = f256::ONE - f256::EPSILON;
+        let res = f256::from_sign_exp_signif(
+            0,
+            -356,
+            (
+                0x00001ccf6429be66211fce159c2bb59b,
+                0x6b8265948d0cdb1bb5ff030c7312a976,
+            ),
+        );
+        assert_eq!(f.acospi(), res);
+    }
+
+    #[test]
+    fn test_atanpi() {
+        assert!(f256::NAN.atanpi().is_nan());
+        assert_eq!(f256::ZERO.atanpi().to_bits(), f256::ZERO.to_bits());
+        assert_eq!(
+            f256::NEG_ZERO.atanpi().to_bits(),
+            f256::NEG_ZERO.to_bits()
+        );
+        assert_eq!(f256::INFINITY.atanpi(), ONE_HALF);
+        assert_eq!(f256::NEG_INFINITY.atanpi(), -ONE_HALF);
+        assert_eq!(f256::MAX.atanpi(), ONE_HALF);
+        assert_eq!(f256::ONE.atanpi(), ONE_QUARTER);
+        assert_eq!(f256::NEG_ONE.atanpi(), -ONE_QUARTER);
+        assert!(f256::EPSILON.recip().atanpi() < ONE_HALF);
+        // (x, atanpi(x))
+        let cases = [
+            (
+                0.1,
+                f256::from_sign_exp_signif(
+                    0,
+                    -241,
+                    (
+                        0x0000103e53b7a03527a7fc5c842a1b6e,
+                        0xa38a2de5876e26cc14ae49afa028e595,
+                    ),
+                ),
+            ),
+            (
+                -0.7,
+                f256::from_sign_exp_signif(
+                    1,
+                    -239,
+                    (
+                        0x000018e21a56263e825481182167de4d,
+                        0xaecfa410f1bf78285a4d21ac635809df,
+                    ),
+                ),
+            ),
+            (
+                12345.678,
+                f256::from_sign_exp_signif(
+                    0,
+                    -238,
+                    (
+                        0x00001fff93db9aa83c73f2e078814bca,
+                        0xb9748341c59fec6ee7f04caecdb53a79,
+                    ),
+                ),
+            ),
+            (
+                1e-20,
+                f256::from_sign_exp_signif(
+                    0,
+                    -305,
+                    (
+                        0x00001e1042c3d96d7eb21f9159794d25,
+                        0x09c9ebf74e56d490241feca1f1686e83,
+                    ),
+                ),
+            ),
+            (
+                -3e+40,
+                f256::from_sign_exp_signif(
+                    1,
+                    -238,
+                    (
+                        0x00001fffffffffffffffffffffffffff,
+                        0xffffffc4d86e805971a1514939fbc2ba,
+                    ),
+                ),
+            ),
+        ];
+        for (x, atan) in cases {
+            let x = f256::from(x);
+            assert_eq!(x.atanpi(), atan, "{x:?}");
+        }
+    }
+
+    #[test]
+    fn test_atan2pi_special() {
+        let zeros = [f256::ZERO, f256::NEG_ZERO];
+        let infs = [f256::INFINITY, f256::NEG_INFINITY];
+        for y in [f256::NAN, f256::ONE, f256::INFINITY] {
+            assert!(y.atan2pi(&f256::NAN).is_nan());
+            assert!(f256::NAN.atan2pi(&y).is_nan());
+        }
+        for (s, y) in zeros.iter().enumerate() {
+            let exp = [
+                [f256::ZERO, f256::NEG_ZERO][s],
+                [f256::ONE, f256::NEG_ONE][s],
+            ];
+            for (i, x) in [zeros[0], f256::ONE, infs[0]].iter().enumerate() {
+                assert_eq!(y.atan2pi(x).to_bits(), exp[0].to_bits(), "{i}");
+            }
+            for (i, x) in
+                [zeros[1], f256::NEG_ONE, infs[1]].iter().enumerate()
+            {
+                assert_eq!(y.atan2pi(x).to_bits(), exp[1].to_bits(), "{i}");
+            }
+        }
+        for x in zeros {
+            assert_eq!(f256::TEN.atan2pi(&x), ONE_HALF);
+            assert_eq!(infs[0].atan2pi(&x), ONE_HALF);
+            assert_eq!(f256::NEG_ONE.atan2pi(&x), -ONE_HALF);
+            assert_eq!(infs[1].atan2pi(&x), -ONE_HALF);
+        }
+        assert_eq!(
+            f256::ONE.atan2pi(&infs[0]).to_bits(),
+            f256::ZERO.to_bits()
+        );
+        assert_eq!(f256::ONE.atan2pi(&infs[1]), f256::ONE);
+        assert_eq!(f256::NEG_ONE.atan2pi(&infs[1]), f256::NEG_ONE);
+        assert_eq!(infs[0].atan2pi(&f256::NEG_ONE), ONE_HALF);
+        assert_eq!(infs[0].atan2pi(&infs[0]), ONE_QUARTER);
+        assert_eq!(infs[0].atan2pi(&infs[1]), ONE_HALF + ONE_QUARTER);
+        assert_eq!(infs[1].atan2pi(&infs[0]), -ONE_QUARTER);
+        assert_eq!(infs[1].atan2pi(&infs[1]), -ONE_HALF - ONE_QUARTER);
+    }
+
+    #[test]
+    fn test_atan2pi() {
+        let one = f256::ONE;
+        let three_quarters = ONE_HALF + ONE_QUARTER;
+        assert_eq!(one.atan2pi(&one), ONE_QUARTER);
+        assert_eq!(one.atan2pi(&-one), three_quarters);
+        assert_eq!((-one).atan2pi(&one), -ONE_QUARTER);
+        assert_eq!((-one).atan2pi(&-one), -three_quarters);
+        // (y, x, atan2pi(y, x))
+        let cases = [
+            (
+                1.0,
+                2.0,
+                f256::from_sign_exp_signif(
+                    0,
+                    -239,
+                    (
+                        0x000012e4051d9df308665688f6dae351,
+                        0x95afca05623078d884fef991f748d555,
+                    ),
+                ),
+            ),
+            (
+                -3.5,
+                -0.25,
+                f256::from_sign_exp_signif(
+                    1,
+                    -237,
+                    (
+                        0x000010b9f0e2b44365feffe516a493be,
+                        0x30800cbba1704b9331bc2dd6e002e2a1,
+                    ),
+                ),
+            ),
+            (
+                2.5,
+                -7.0,
+                f256::from_sign_exp_signif(
+                    0,
+                    -237,
+                    (
+                        0x00001c81885a68c4bf03024a29c901ac,
+                        0x886dec31ec914fae68405c516056c6ff,
+                    ),
+                ),
+            ),
+            (
+                1e-30,
+                -1.0,
+                f256::from_sign_exp_signif(
+                    0,
+                    -237,
+                    (
+                        0x00001fffffffffffffffffffffffff31,
+                        0x67b2d07b4003b5d28f59b0e83d19fe92,
+                    ),
+                ),
+            ),
+            (
+                -1e-30,
+                5.0,
+                f256::from_sign_exp_signif(
+                    1,
+                    -340,
+                    (
+                        0x000014a8d484c079993a9e24dd6e4f2d,
+                        0x1700249f331128ee23632445a834b500,
+                    ),
+                ),
+            ),
+        ];
+        for (y, x, atan2) in cases {
+            let (y, x) = (f256::from(y), f256::from(x));
+            assert_eq!(y.atan2pi(&x), atan2, "{y:?} {x:?}");
+        }
+        // Quotient underflows.
+        let y = f256::MIN_GT_ZERO;
+        assert_eq!(y.atan2pi(&f256::MAX), f256::ZERO);
+        assert_eq!(
+            f256::MIN_POSITIVE.atan2pi(&f256::TEN),
+            f256::from_bits((
+                0x826135f1d8367754c9b9130e5d,
+                0xc3e49a491baae0fd02d9ce650de9564c
+            ))
+        );
+    }
+}
@@ -7,6 +7,7 @@
 // $Source$
 // $Revision$
 
+mod angle_unit;
 mod approx_atan;
 mod approx_cos;
 mod approx_sin;
@@ -14,10 +15,14 @@ mod approx_sin_cos;
 mod asin_acos;
 mod atan;
 mod cos;
+mod inv_trig_deg;
+mod inv_trig_pi;
 mod reduce;
 mod sin;
 mod sin_cos;
 mod tan;
+mod trig_deg;
+mod trig_pi;
 mod two_over_pi;
 
-use super::{Float256, FP492, U256};
+use super::{Float, Float256, FP492, U256};
@@ -196,7 +196,7 @@ mod sin_cos_tests {
             g += d;
             assert!(f < g);
             assert!(f.sin() >= g.sin());
-            assert!(f.cos() <= g.cos());
+            assert!(f.cos() >= g.cos());
             f = g;
         }
         let mut f = c;
@@ -221,7 +221,7 @@ mod sin_cos_tests {
             g += d;
             assert!(f < g);
             assert!(f.sin() >= g.sin());
-            assert!(f.cos() <= g.cos());
+            assert!(f.cos() >= g.cos());
             f = g;
         }
     }
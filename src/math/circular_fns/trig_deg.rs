@@ -0,0 +1,404 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use super::angle_unit::DEGREES;
+use crate::f256;
+
+impl f256 {
+    /// Computes the sine of a number given in degrees.
+    ///
+    /// The argument is reduced exactly modulo 360, so that `sind(x)` is much
+    /// more accurate than `(x * PI / 180).sin()`. The result is exactly ±0
+    /// for multiples of 180 (with the sign of `self`) and ±1 for odd
+    /// multiples of 90.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// assert_eq!(f256::from(30).sind(), f256::from(0.5));
+    /// assert_eq!(f256::from(-450).sind(), f256::NEG_ONE);
+    /// assert_eq!(f256::from(720).sind(), f256::ZERO);
+    /// ```
+    #[must_use]
+    pub fn sind(&self) -> Self {
+        DEGREES.sin(self)
+    }
+
+    /// Computes the cosine of a number given in degrees.
+    ///
+    /// The argument is reduced exactly modulo 360, so that `cosd(x)` is much
+    /// more accurate than `(x * PI / 180).cos()`. The result is exactly ±1
+    /// for multiples of 180 and +0 for odd multiples of 90.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// assert_eq!(f256::from(60).cosd(), f256::from(0.5));
+    /// assert_eq!(f256::from(-540).cosd(), f256::NEG_ONE);
+    /// assert_eq!(f256::from(270).cosd(), f256::ZERO);
+    /// ```
+    #[must_use]
+    pub fn cosd(&self) -> Self {
+        DEGREES.cos(self)
+    }
+
+    /// Computes the tangent of a number given in degrees.
+    ///
+    /// The result is exactly ±0 for multiples of 180, ±1 for odd multiples
+    /// of 45 and ±∞ for odd multiples of 90 (+∞ for 90 + 360⋅n, -∞ for
+    /// 270 + 360⋅n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// assert_eq!(f256::from(45).tand(), f256::ONE);
+    /// assert_eq!(f256::from(135).tand(), f256::NEG_ONE);
+    /// assert_eq!(f256::from(90).tand(), f256::INFINITY);
+    /// assert_eq!(f256::from(-90).tand(), f256::NEG_INFINITY);
+    /// ```
+    #[must_use]
+    pub fn tand(&self) -> Self {
+        DEGREES.tan(self)
+    }
+
+    /// Simultaneously computes the sine and cosine of a number given in
+    /// degrees.
+    ///
+    /// Returns (sind(x), cosd(x)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// let (sin, cos) = f256::from(150).sin_cosd();
+    /// assert_eq!(sin, f256::from(0.5));
+    /// assert_eq!(cos, -f256::from(0.75).sqrt());
+    /// ```
+    #[must_use]
+    pub fn sin_cosd(&self) -> (Self, Self) {
+        DEGREES.sin_cos(self)
+    }
+}
+
+#[cfg(test)]
+mod trig_deg_tests {
+    use super::*;
+    use crate::{consts::PI, ONE_HALF};
+
+    #[test]
+    fn test_special() {
+        for f in [f256::NAN, f256::INFINITY, f256::NEG_INFINITY] {
+            assert!(f.sind().is_nan());
+            assert!(f.cosd().is_nan());
+            assert!(f.tand().is_nan());
+            let (sin, cos) = f.sin_cosd();
+            assert!(sin.is_nan() && cos.is_nan());
+        }
+        for f in [f256::ZERO, f256::NEG_ZERO] {
+            assert_eq!(f.sind().to_bits(), f.to_bits());
+            assert_eq!(f.cosd(), f256::ONE);
+            assert_eq!(f.tand().to_bits(), f.to_bits());
+            let (sin, cos) = f.sin_cosd();
+            assert_eq!(sin.to_bits(), f.to_bits());
+            assert_eq!(cos, f256::ONE);
+        }
+    }
+
+    #[test]
+    fn test_multiples_of_90() {
+        const SIN: [f256; 4] =
+            [f256::ZERO, f256::ONE, f256::ZERO, f256::NEG_ONE];
+        const TAN: [f256; 4] = [
+            f256::ZERO,
+            f256::INFINITY,
+            f256::NEG_ZERO,
+            f256::NEG_INFINITY,
+        ];
+        for i in 1_i64..=12 {
+            let f = f256::from(90 * i);
+            let q = (i % 4) as usize;
+            assert_eq!(f.sind().to_bits(), SIN[q].to_bits(), "{i}");
+            assert_eq!(f.cosd().to_bits(), SIN[(q + 1) % 4].to_bits(), "{i}");
+            assert_eq!(f.tand().to_bits(), TAN[q].to_bits(), "{i}");
+            assert_eq!(f.sin_cosd(), (f.sind(), f.cosd()));
+            assert_eq!((-f).sind(), -f.sind());
+            assert_eq!((-f).cosd().to_bits(), f.cosd().to_bits());
+            assert_eq!((-f).tand().to_bits(), (-f.tand()).to_bits());
+        }
+        // Huge values are integers, reduced exactly modulo 360.
+        for (f, r) in [(f256::MAX, 248), (f256::power_of_two(236), 256)] {
+            let r = f256::from(r);
+            assert_eq!(f.sind(), r.sind());
+            assert_eq!(f.cosd(), r.cosd());
+            assert_eq!(f.tand(), r.tand());
+        }
+        // 2²³⁶ % 360 = 256, 2²³⁹ % 360 = 248
+        let f = f256::power_of_two(236) + f256::from(284);
+        assert_eq!(f.sind().to_bits(), f256::ZERO.to_bits());
+        assert_eq!(f.cosd(), f256::NEG_ONE);
+        let f = f256::power_of_two(239) + f256::from(112);
+        assert_eq!(f.sind().to_bits(), f256::ZERO.to_bits());
+        assert_eq!(f.cosd(), f256::ONE);
+    }
+
+    #[test]
+    fn test_special_angles() {
+        let sqrt_3 = f256::from(3).sqrt();
+        let frac_1_sqrt_2 = ONE_HALF.sqrt();
+        // (x, sind(x), cosd(x), tand(x))
+        let cases = [
+            (30, ONE_HALF, sqrt_3.div2(), sqrt_3.recip()),
+            (45, frac_1_sqrt_2, frac_1_sqrt_2, f256::ONE),
+            (60, sqrt_3.div2(), ONE_HALF, sqrt_3),
+            (135, frac_1_sqrt_2, -frac_1_sqrt_2, f256::NEG_ONE),
+            (210, -ONE_HALF, -sqrt_3.div2(), sqrt_3.recip()),
+            (300, -sqrt_3.div2(), ONE_HALF, -sqrt_3),
+            (-330, ONE_HALF, sqrt_3.div2(), sqrt_3.recip()),
+            (
+                360 * 1_000_001 + 150,
+                ONE_HALF,
+                -sqrt_3.div2(),
+                -sqrt_3.recip(),
+            ),
+        ];
+        for (x, sin, cos, tan) in cases {
+            let f = f256::from(x);
+            assert_eq!(f.sind(), sin, "{x}");
+            assert_eq!(f.cosd(), cos, "{x}");
+            assert_eq!(f.tand(), tan, "{x}");
+            assert_eq!(f.sin_cosd(), (sin, cos), "{x}");
+        }
+    }
+
+    #[test]
+    fn test_some() {
+        // (x, sind(x), cosd(x), tand(x))
+        let cases = [
+            (
+                1.0,
+                f256::from_sign_exp_signif(
+                    0,
+                    -242,
+                    (
+                        0x000011df0b2b89dd1e560d35a0521e1e,
+                        0x5bcee3f4fc8ec28965b4507ae6d37031,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -237,
+                    (
+                        0x00001ffec097f5af89b9dae801ff97aa,
+                        0x13a8122ba223c38097cf12d83a66903c,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -242,
+                    (
+                        0x000011dfbd9410a42218c721220dd6f1,
+                        0x4965f50109f4c9b4ef190f3d1931056c,
+                    ),
+                ),
+            ),
+            (
+                10.5,
+                f256::from_sign_exp_signif(
+                    0,
+                    -239,
+                    (
+                        0x000017537e63143e2e7b5d94ee0ca082,
+                        0x7e7b9261d8c59382ce6667ce606a2507,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -237,
+                    (
+                        0x00001f76d2fef3cc4ab635a402b98459,
+                        0x90b97bf7383ac21f68a4f0aac2cc16a1,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -239,
+                    (
+                        0x000017b93098538f6e95e0b1bf0a0309,
+                        0x33ff065bcfb55b1cf8b6870386dd023c,
+                    ),
+                ),
+            ),
+            (
+                -12345.678,
+                f256::from_sign_exp_signif(
+                    1,
+                    -237,
+                    (
+                        0x00001ecf38ac55ffcc3a7b7e226b96f4,
+                        0x61399e9333f34624f642fc5961737066,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    1,
+                    -238,
+                    (
+                        0x0000114b76097b91947cf17172315f7a,
+                        0x42019ab2e9589532e50569fb6ab41f5e,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -235,
+                    (
+                        0x00001c80bef754706981cfc8666a5dee,
+                        0x3d47a6b7a278a277e2bfdf8d4add421a,
+                    ),
+                ),
+            ),
+            (
+                44.9999,
+                f256::from_sign_exp_signif(
+                    0,
+                    -237,
+                    (
+                        0x000016a09bcfecfac80ee497d068c326,
+                        0x4a16f3115f41846347ba4d1966331d15,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -237,
+                    (
+                        0x000016a0a0fd113107d4880e8ce6f166,
+                        0xd123c1150a1ecbc13b5f7c91d8309fcb,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -237,
+                    (
+                        0x00001ffff8adf862b6247da36605adb6,
+                        0x9f5fc9fcd456437c674409ff143ae172,
+                    ),
+                ),
+            ),
+            (
+                1e-20,
+                f256::from_sign_exp_signif(
+                    0,
+                    -309,
+                    (
+                        0x00001a5fea5ec6fd1cacd911d89c8774,
+                        0x40c8688cabad812e07036ec2553bdab3,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -237,
+                    (
+                        0x00001fffffffffffffffffffffffffff,
+                        0xfffffffff52181d3daadfdd09a96d981,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -309,
+                    (
+                        0x00001a5fea5ec6fd1cacd911d89c8774,
+                        0x40c8688cb4a2dbd3a430f418bd317e28,
+                    ),
+                ),
+            ),
+            (
+                271.0001,
+                f256::from_sign_exp_signif(
+                    1,
+                    -237,
+                    (
+                        0x00001ffec0879b0f1b2cb476bf7caa14,
+                        0xa48d48334df6d0df1d2cd7ad200df4ec,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -242,
+                    (
+                        0x000011df80477fdfd99c15c9640ceeeb,
+                        0x1c5b202cf75b259899be35dad8e41a09,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    1,
+                    -231,
+                    (
+                        0x00001ca461b732824d0ad47970cf1df5,
+                        0x1036325027474c1efa43a9691891302e,
+                    ),
+                ),
+            ),
+            (
+                1e+30,
+                f256::from_sign_exp_signif(
+                    0,
+                    -238,
+                    (
+                        0x000011a40add328e2966f232ee48940e,
+                        0x24e0b7caf33b8bf1fdf5d6c16154f9dd,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -237,
+                    (
+                        0x00001ec2a7e35e7b7fb5ac9d23daf30e,
+                        0xe7ef55f71e6c6902a5aef1d8969ea26c,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -238,
+                    (
+                        0x0000125a0951873b2246d7fc98825458,
+                        0xd97106c10e2e2965a88999cfcdbed933,
+                    ),
+                ),
+            ),
+        ];
+        for (x, sin, cos, tan) in cases {
+            let x = f256::from(x);
+            assert_eq!(x.sind(), sin, "{x:?}");
+            assert_eq!(x.cosd(), cos, "{x:?}");
+            assert_eq!(x.tand(), tan, "{x:?}");
+        }
+    }
+
+    #[test]
+    fn test_small() {
+        let frac_pi_180 = PI / f256::from(180);
+        for f in [
+            f256::MIN_GT_ZERO,
+            f256::MIN_POSITIVE,
+            f256::EPSILON.div_pow2(100),
+            f256::EPSILON,
+        ] {
+            let r = f * frac_pi_180;
+            assert!((f.sind() - r).abs() <= r.ulp(), "{f:?}");
+            assert_eq!(f.cosd(), f256::ONE);
+            assert!((f.tand() - r).abs() <= r.ulp(), "{f:?}");
+            assert_eq!((-f).sind(), -f.sind());
+            assert_eq!((-f).tand(), -f.tand());
+        }
+        let f = f256::from(90) - f256::EPSILON.mul_pow2(6);
+        assert!(f.tand().is_finite());
+        assert!(f.cosd().is_sign_positive());
+    }
+}
@@ -0,0 +1,386 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use super::angle_unit::HALF_TURNS;
+use crate::f256;
+
+impl f256 {
+    /// Computes the sine of π times a number, i.e. of the number given in
+    /// half turns.
+    ///
+    /// The argument reduction is exact, so that `sinpi(x)` is much more
+    /// accurate than `(x * PI).sin()`. The result is exactly ±0 for
+    /// integers (with the sign of `self`) and ±1 for half-integers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// assert_eq!(f256::from(2.5).sinpi(), f256::ONE);
+    /// assert_eq!(f256::from(-7).sinpi(), f256::NEG_ZERO);
+    /// let f = f256::ONE / f256::from(6);
+    /// assert_eq!(f.sinpi(), f256::from(0.5));
+    /// ```
+    #[must_use]
+    pub fn sinpi(&self) -> Self {
+        HALF_TURNS.sin(self)
+    }
+
+    /// Computes the cosine of π times a number, i.e. of the number given in
+    /// half turns.
+    ///
+    /// The argument reduction is exact, so that `cospi(x)` is much more
+    /// accurate than `(x * PI).cos()`. The result is exactly ±1 for integers
+    /// and +0 for half-integers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// assert_eq!(f256::from(-3).cospi(), f256::NEG_ONE);
+    /// assert_eq!(f256::from(1.5).cospi(), f256::ZERO);
+    /// assert_eq!(f256::from(0.75).cospi(), -f256::from(0.5).sqrt());
+    /// ```
+    #[must_use]
+    pub fn cospi(&self) -> Self {
+        HALF_TURNS.cos(self)
+    }
+
+    /// Computes the tangent of π times a number, i.e. of the number given in
+    /// half turns.
+    ///
+    /// The result is exactly ±0 for integers, ±1 for odd multiples of ¼ and
+    /// ±∞ for half-integers (+∞ for n + ½ with even n, -∞ for odd n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// assert_eq!(f256::from(0.25).tanpi(), f256::ONE);
+    /// assert_eq!(f256::from(2.5).tanpi(), f256::INFINITY);
+    /// assert_eq!(f256::from(-0.5).tanpi(), f256::NEG_INFINITY);
+    /// assert_eq!(f256::from(3).tanpi(), f256::NEG_ZERO);
+    /// ```
+    #[must_use]
+    pub fn tanpi(&self) -> Self {
+        HALF_TURNS.tan(self)
+    }
+}
+
+#[cfg(test)]
+mod trig_pi_tests {
+    use super::*;
+    use crate::consts::{FRAC_1_SQRT_2, PI};
+    use crate::ONE_HALF;
+
+    #[test]
+    fn test_special() {
+        for f in [f256::NAN, f256::INFINITY, f256::NEG_INFINITY] {
+            assert!(f.sinpi().is_nan());
+            assert!(f.cospi().is_nan());
+            assert!(f.tanpi().is_nan());
+        }
+        for f in [f256::ZERO, f256::NEG_ZERO] {
+            assert_eq!(f.sinpi().to_bits(), f.to_bits());
+            assert_eq!(f.cospi(), f256::ONE);
+            assert_eq!(f.tanpi().to_bits(), f.to_bits());
+        }
+    }
+
+    #[test]
+    fn test_integers() {
+        for i in 1_i64..=9 {
+            let f = f256::from(i);
+            let sign = (i % 2) as u32;
+            assert_eq!(f.sinpi().to_bits(), f256::ZERO.to_bits());
+            assert_eq!((-f).sinpi().to_bits(), f256::NEG_ZERO.to_bits());
+            assert_eq!(f.cospi(), [f256::ONE, f256::NEG_ONE][sign as usize]);
+            assert_eq!((-f).cospi(), f.cospi());
+            assert_eq!(f.tanpi().sign(), sign);
+            assert!(f.tanpi().eq_zero());
+            assert_eq!((-f).tanpi().sign(), 1 - sign);
+            assert!((-f).tanpi().eq_zero());
+        }
+        for f in [f256::MAX, f256::MAX.div2(), f256::EPSILON.recip()] {
+            assert_eq!(f.sinpi().to_bits(), f256::ZERO.to_bits());
+            assert_eq!(f.cospi(), f256::ONE);
+            assert_eq!(f.tanpi().to_bits(), f256::ZERO.to_bits());
+        }
+        // 2²³⁶ + 1 is odd
+        let f = f256::EPSILON.recip().div2() + f256::ONE;
+        assert_eq!(f.cospi(), f256::NEG_ONE);
+        assert_eq!(f.tanpi().to_bits(), f256::NEG_ZERO.to_bits());
+    }
+
+    #[test]
+    fn test_half_integers() {
+        for i in 0_i64..=9 {
+            let f = f256::from(i) + ONE_HALF;
+            let sign = (i % 2) as usize;
+            assert_eq!(f.sinpi(), [f256::ONE, f256::NEG_ONE][sign]);
+            assert_eq!((-f).sinpi(), -f.sinpi());
+            assert_eq!(f.cospi().to_bits(), f256::ZERO.to_bits());
+            assert_eq!((-f).cospi().to_bits(), f256::ZERO.to_bits());
+            assert_eq!(f.tanpi(), [f256::INFINITY, f256::NEG_INFINITY][sign]);
+            assert_eq!((-f).tanpi(), -f.tanpi());
+        }
+    }
+
+    #[test]
+    fn test_quarters() {
+        for i in 0_i64..=7 {
+            let f = f256::from(2 * i + 1).div_pow2(2);
+            let sin = [FRAC_1_SQRT_2, -FRAC_1_SQRT_2][(i as usize / 2) % 2];
+            let cos =
+                [FRAC_1_SQRT_2, -FRAC_1_SQRT_2][((i as usize + 1) / 2) % 2];
+            assert_eq!(f.sinpi(), sin);
+            assert_eq!(f.cospi(), cos);
+            assert_eq!(f.tanpi(), [f256::ONE, f256::NEG_ONE][i as usize % 2]);
+        }
+    }
+
+    #[test]
+    fn test_some() {
+        // (x, sinpi(x), cospi(x), tanpi(x))
+        let cases = [
+            (
+                0.1,
+                f256::from_sign_exp_signif(
+                    0,
+                    -238,
+                    (
+                        0x000013c6ef372fe94fcf3b822765b173,
+                        0xb0dc96416b17b4f7b2cf72b6cf903468,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -237,
+                    (
+                        0x00001e6f0e134454ff51f6542fd0a139,
+                        0x7931a1f842d785eaa968999831d06b39,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -238,
+                    (
+                        0x000014cb7bfb4961af14b18a7d41ef60,
+                        0x1396089656e00804640d4f0fc6ef8a15,
+                    ),
+                ),
+            ),
+            (
+                1.3,
+                f256::from_sign_exp_signif(
+                    1,
+                    -237,
+                    (
+                        0x000019e3779b97f4a87e7642b08fae86,
+                        0x4d21794c5ebeff146b6834c179fe4cc8,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    1,
+                    -237,
+                    (
+                        0x000012cf2304755a5ce9719517624e44,
+                        0x45cd20c3b05137c6f03a8b1a644cdcc1,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -236,
+                    (
+                        0x00001605a90c73ab7aa02422bdc93dd1,
+                        0xa92a8987f0a2bf6445ef1538d4c6de18,
+                    ),
+                ),
+            ),
+            (
+                -12345.678,
+                f256::from_sign_exp_signif(
+                    0,
+                    -237,
+                    (
+                        0x00001b202d7aa0dd8b8abc7a9fc2c06c,
+                        0x38d98575b609029d7a1441eecbdcd708,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -237,
+                    (
+                        0x000010f9f29758442798e8cf7bb68a77,
+                        0xd4e3fdf18b1441126d09f93631b8f853,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -236,
+                    (
+                        0x00001990cc6d53eb642fe6241b1f822f,
+                        0x66fd23ad9741512e3c73cda7f337a3cc,
+                    ),
+                ),
+            ),
+            (
+                0.2499999,
+                f256::from_sign_exp_signif(
+                    0,
+                    -237,
+                    (
+                        0x000016a09def3bf83fbba21a5598e81c,
+                        0xec9fd2052871c67083b375d7e4941756,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -237,
+                    (
+                        0x000016a09eddc27ce4cbcd4948bd6bda,
+                        0xb15879b641ee59f96e932268de21b149,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -237,
+                    (
+                        0x00001ffffeaeac95f829e3c4e7f9524c,
+                        0xe49c9735ddf3fa7c31bf161b8a2385d7,
+                    ),
+                ),
+            ),
+            (
+                1e-20,
+                f256::from_sign_exp_signif(
+                    0,
+                    -301,
+                    (
+                        0x0000128b70caa3e9f82988a08c4e0f3d,
+                        0xbd8ce8792ed9e1f40cbb214ea1a659f3,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -237,
+                    (
+                        0x00001fffffffffffffffffffffffffff,
+                        0xfffffaa060bf4ccca4cb716d33e80336,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -301,
+                    (
+                        0x0000128b70caa3e9f82988a08c4e0f3d,
+                        0xbd8ceb9662babc516fd8fcc56996445a,
+                    ),
+                ),
+            ),
+            (
+                7.000001,
+                f256::from_sign_exp_signif(
+                    1,
+                    -255,
+                    (
+                        0x00001a5a84d390170b7d6976be9621bf,
+                        0x8fd2c583f0d3bb5796ff9e3c151cb35d,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    1,
+                    -237,
+                    (
+                        0x00001fffffffff525f40d741f27758ab,
+                        0x172c8ba1ff3ea2324766cdc56d3b7c94,
+                    ),
+                ),
+                f256::from_sign_exp_signif(
+                    0,
+                    -255,
+                    (
+                        0x00001a5a84d390a6093d87350ce01110,
+                        0x7fc1cf902c0ab8c0317fd371a2129192,
+                    ),
+                ),
+            ),
+        ];
+        for (x, sin, cos, tan) in cases {
+            let x = f256::from(x);
+            assert_eq!(x.sinpi(), sin, "{x:?}");
+            assert_eq!(x.cospi(), cos, "{x:?}");
+            assert_eq!(x.tanpi(), tan, "{x:?}");
+        }
+        let f = f256::ONE / f256::from(6);
+        assert_eq!(f.sinpi(), ONE_HALF);
+    }
+
+    #[test]
+    fn test_small() {
+        for f in [
+            f256::MIN_GT_ZERO,
+            f256::MIN_POSITIVE,
+            f256::EPSILON.div_pow2(100),
+            f256::EPSILON,
+        ] {
+            let d = (f.sinpi() - f * PI).abs();
+            assert!(d <= (f * PI).ulp(), "{f:?}");
+            assert_eq!(f.cospi(), f256::ONE);
+            let d = (f.tanpi() - f * PI).abs();
+            assert!(d <= (f * PI).ulp(), "{f:?}");
+            assert_eq!((-f).sinpi(), -f.sinpi());
+            assert_eq!((-f).tanpi(), -f.tanpi());
+        }
+        assert!((ONE_HALF - f256::EPSILON).tanpi().is_finite());
+        assert!((f256::ONE - f256::EPSILON).tanpi().is_sign_negative());
+    }
+
+    #[test]
+    fn test_small_cos() {
+        // cos(π⋅f) ≈ 1 - ½⋅(π⋅f)² is below the midpoint between 1 and its
+        // predecessor.
+        let f = f256::from(123).div_pow2(127);
+        let one_minus_ulp = f256::ONE.next_down();
+        assert_eq!(f.cospi(), one_minus_ulp);
+        assert_eq!((-f).cospi(), one_minus_ulp);
+        assert_eq!((ONE_HALF - f).sinpi(), one_minus_ulp);
+        assert_eq!((ONE_HALF + f).sinpi(), one_minus_ulp);
+        assert_eq!((f256::ONE - f).cospi(), -one_minus_ulp);
+        assert_eq!((f256::TWO + f).cospi(), one_minus_ulp);
+        // For smaller f, the cosine rounds to 1.
+        let f = f256::from(115).div_pow2(127);
+        assert_eq!(f.cospi(), f256::ONE);
+        assert_eq!((ONE_HALF - f).sinpi(), f256::ONE);
+    }
+
+    #[test]
+    fn test_vs_radians() {
+        // The results are close to those of the circular functions applied
+        // to the values in radians (apart from integers, where sinpi and
+        // tanpi are exact zeroes).
+        for i in (-40_i64..=40).filter(|i| i % 13 != 0) {
+            let f = f256::from(i) / f256::from(13);
+            let x = f * PI;
+            assert!(f.sinpi().diff_within_n_bits(&x.sin(), 8), "{f}");
+            assert!(f.cospi().diff_within_n_bits(&x.cos(), 8), "{f}");
+            assert!(f.tanpi().diff_within_n_bits(&x.tan(), 8), "{f}");
+        }
+    }
+
+    #[test]
+    fn test_large() {
+        // 123456789.375 = 2⋅61728395 - 0.625
+        let f = f256::from(123456789.375);
+        let g = f256::from(-0.625);
+        assert_eq!(f.sinpi(), g.sinpi());
+        assert_eq!(f.cospi(), g.cospi());
+        assert_eq!(f.tanpi(), g.tanpi());
+    }
+}
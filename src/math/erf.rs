@@ -0,0 +1,475 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use core::num::FpCategory;
+
+use super::{exp::approx_exp, log::approx_ln, Float512};
+use crate::f256;
+
+// FRAC_1_SQRT_PI = ◯₅₁₀(1/√π) =
+// 0.56418958354775628694807945156077258584405062932899885684408572171064246844149341448674366020210736344302834790636170735168993149482616286636548952001776899
+const FRAC_1_SQRT_PI: Float512 = Float512::new(
+    1,
+    -1,
+    &[
+        0x48375d410a6db446b8ea453fb5ff61a2,
+        0x0a04d075d61f3a8b9cd0ac1866731058,
+        0x603ace7c2c938788a0601b04b663cd75,
+        0xde8fa7772470e53c3a7bb7c3bfff6129,
+    ],
+);
+
+// For |x| < 8 erf(x) is evaluated by a power series, for |x| >= 8 erfc(x)
+// is evaluated by a continued fraction.
+const SERIES_LIMIT_EXP: i32 = 3;
+
+// |x| >= 16 => 1 - erf(|x|) = erfc(|x|) < 2⁻³⁷⁰
+const ERF_LIMIT: f256 = f256::from_u64(16);
+
+// x >= 427 => erfc(x) < 2⁻²⁶³⁰⁵⁰ < ½⋅f256::MIN_GT_ZERO
+const ERFC_LIMIT: f256 = f256::from_u64(427);
+
+// Returns 2/√π⋅e^(-x²), i.e. the derivative of erf(x).
+fn d_erf(x: &Float512) -> Float512 {
+    let x2 = x.square();
+    let e = if x2.exp() < -170 {
+        // e⁻ᵗ ≈ 1 - t + ½t², the truncation error is less than 2⁻⁵¹²
+        Float512::ONE - x2 + x2.square().mul_pow2(-1)
+    } else {
+        approx_exp(&-x2)
+    };
+    (FRAC_1_SQRT_PI * e).mul_pow2(1)
+}
+
+// Returns erf(x) for |x| < 8, using the series
+// erf(x) = 2/√π⋅e^(-x²)⋅∑ 2ⁿ⋅x²ⁿ⁺¹ / (1⋅3⋅…⋅(2n+1))
+// Because all terms have the same sign, there is no cancellation.
+fn erf_series(x: &Float512) -> Float512 {
+    debug_assert!(x.exp() < SERIES_LIMIT_EXP);
+    if x.is_zero() {
+        return *x;
+    }
+    let f = x.square().mul_pow2(1);
+    let mut term = *x;
+    let mut sum = term;
+    let mut d = 1_i32;
+    loop {
+        d += 2;
+        term = term * f / Float512::from(d);
+        if term.exp() < sum.exp() - 514 {
+            return d_erf(x) * sum;
+        }
+        sum += term;
+    }
+}
+
+// Returns erfc(x) for x >= 8, using the continued fraction
+// erfc(x) = e^(-x²)/√π⋅1/(x + ½/(x + 1/(x + (3/2)/(x + 2/(x + …)))))
+// With n >= 3000/x + 40 terms its truncation error is less than 2⁻⁵²⁰.
+fn erfc_cf(x: &Float512) -> Float512 {
+    debug_assert!(x.signum() == 1 && x.exp() >= SERIES_LIMIT_EXP);
+    // x >= 2ᵉ where e = x.exp() <= 8
+    let n = (3000_i32 >> x.exp()) + 40;
+    let mut t = *x;
+    for i in (1..=n).rev() {
+        t = *x + Float512::from(i).mul_pow2(-1) / t;
+    }
+    d_erf(x).mul_pow2(-1) / t
+}
+
+// Returns erfc(x) for x > 0.
+fn erfc_pos(x: &Float512) -> Float512 {
+    if x.exp() < SERIES_LIMIT_EXP {
+        Float512::ONE - erf_series(x)
+    } else {
+        erfc_cf(x)
+    }
+}
+
+// Returns erf(x).
+fn erf(x: &Float512) -> Float512 {
+    if x.exp() < SERIES_LIMIT_EXP {
+        erf_series(x)
+    } else {
+        let mut y = Float512::ONE - erfc_cf(&x.abs());
+        y.copy_sign(x);
+        y
+    }
+}
+
+// Refines x so that f(x) = y by Halley's method, where f is erf or erfc.
+// For both, f''(x) / f'(x) = -2x, so that the correction is
+// t / (1 + x⋅t) where t = (f(x) - y) / f'(x).
+fn halley(
+    x0: Float512,
+    y: &Float512,
+    f: fn(&Float512) -> Float512,
+    sign: i32,
+) -> Float512 {
+    let mut x = x0;
+    // The convergence is cubic, so the number of iterations is small.
+    for _ in 0..16 {
+        let mut df = d_erf(&x);
+        if sign < 0 {
+            df.flip_sign();
+        }
+        let t = (f(&x) - *y) / df;
+        let dx = t / (Float512::ONE + x * t);
+        x -= dx;
+        if dx.is_zero() || dx.exp() < x.exp() - 200 {
+            break;
+        }
+    }
+    x
+}
+
+// Returns erf⁻¹(y) for 0 < |y| <= ½.
+fn erf_inv(y: &Float512) -> Float512 {
+    // erf⁻¹(y) ≈ ½√π⋅(y + π/12⋅y³)
+    let y2 = y.square();
+    let x0 = (Float512::ONE + Float512::PI * y2 / Float512::from(12)) * *y
+        / FRAC_1_SQRT_PI.mul_pow2(1);
+    halley(x0, y, erf, 1)
+}
+
+// Returns erfc⁻¹(y) for 0 < y <= ½.
+fn erfc_inv_small(y: &Float512) -> Float512 {
+    // With l = -logₑ y, erfc⁻¹(y) ≈ √(l - ½⋅logₑ(π⋅l))
+    let l = -approx_ln(y);
+    let x0 = (l - approx_ln(&(Float512::PI * l)).mul_pow2(-1)).sqrt();
+    halley(x0, y, erfc_pos, -1)
+}
+
+impl f256 {
+    /// Returns the error function of `self`.
+    ///
+    /// erf(x) = 2/√π⋅∫ e^(-t²) dt over (0, x)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// assert_eq!(f256::ZERO.erf(), f256::ZERO);
+    /// assert_eq!(f256::from(-30).erf(), f256::NEG_ONE);
+    /// let x = f256::from(0.5);
+    /// assert_eq!(x.erf() + x.erfc(), f256::ONE);
+    /// ```
+    #[must_use]
+    pub fn erf(&self) -> Self {
+        match self.classify() {
+            FpCategory::Nan | FpCategory::Zero => *self,
+            FpCategory::Infinite => Self::ONE.copysign(*self),
+            _ => {
+                if self.abs() >= ERF_LIMIT {
+                    Self::ONE.copysign(*self)
+                } else {
+                    Self::from(&erf(&Float512::from(self)))
+                }
+            }
+        }
+    }
+
+    /// Returns the complementary error function of `self`, i.e. 1 -
+    /// erf(`self`).
+    ///
+    /// The result is calculated with full relative precision, even for
+    /// large arguments where 1 - erf(`self`) would lose all significant
+    /// digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// assert_eq!(f256::ZERO.erfc(), f256::ONE);
+    /// assert_eq!(f256::INFINITY.erfc(), f256::ZERO);
+    /// let x = f256::from(50);
+    /// assert_eq!(format!("{:.4e}", x.erfc()), "2.0709e-1088");
+    /// ```
+    #[must_use]
+    pub fn erfc(&self) -> Self {
+        match self.classify() {
+            FpCategory::Nan => *self,
+            FpCategory::Zero => Self::ONE,
+            FpCategory::Infinite => {
+                [Self::ZERO, Self::TWO][self.is_sign_negative() as usize]
+            }
+            _ => {
+                let x = Float512::from(&self.abs());
+                if self.is_sign_negative() {
+                    if self.abs() >= ERF_LIMIT {
+                        Self::TWO
+                    } else {
+                        Self::from(&(Float512::ONE + erf(&x)))
+                    }
+                } else if *self >= ERFC_LIMIT {
+                    Self::ZERO
+                } else {
+                    Self::from(&erfc_pos(&x))
+                }
+            }
+        }
+    }
+
+    /// Returns the inverse error function of `self`, i.e. the value x so
+    /// that erf(x) = `self`.
+    ///
+    /// The result is NaN for |`self`| > 1 and ±∞ for `self` = ±1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// let x = f256::from(0.75);
+    /// assert_eq!(x.erf().erf_inv(), x);
+    /// assert_eq!(f256::NEG_ONE.erf_inv(), f256::NEG_INFINITY);
+    /// assert!(f256::TWO.erf_inv().is_nan());
+    /// ```
+    #[must_use]
+    pub fn erf_inv(&self) -> Self {
+        let abs_self = self.abs();
+        if self.is_nan() || abs_self > Self::ONE {
+            return Self::NAN;
+        }
+        if self.eq_zero() {
+            return *self;
+        }
+        if abs_self == Self::ONE {
+            return Self::INFINITY.copysign(*self);
+        }
+        let y = Float512::from(self);
+        let x = if abs_self <= Self::ONE.div2() {
+            erf_inv(&y)
+        } else {
+            // 1 - |y| is exact for ½ <= |y| <= 1.
+            let mut x =
+                erfc_inv_small(&Float512::from(&(Self::ONE - abs_self)));
+            x.copy_sign(&y);
+            x
+        };
+        Self::from(&x)
+    }
+
+    /// Returns the inverse complementary error function of `self`, i.e. the
+    /// value x so that erfc(x) = `self`.
+    ///
+    /// The result is NaN for `self` < 0 or `self` > 2, +∞ for `self` = 0
+    /// and -∞ for `self` = 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// let x = f256::from(25);
+    /// assert_eq!(x.erfc().erfc_inv(), x);
+    /// assert_eq!(f256::ONE.erfc_inv(), f256::ZERO);
+    /// assert_eq!(f256::ZERO.erfc_inv(), f256::INFINITY);
+    /// ```
+    #[must_use]
+    pub fn erfc_inv(&self) -> Self {
+        if self.is_nan() || self.is_sign_negative() && !self.eq_zero() {
+            return Self::NAN;
+        }
+        if self.eq_zero() {
+            return Self::INFINITY;
+        }
+        if *self >= Self::TWO {
+            return [Self::NAN, Self::NEG_INFINITY]
+                [(*self == Self::TWO) as usize];
+        }
+        // All of the following differences are exact.
+        let half = Self::ONE.div2();
+        let x = if *self <= half {
+            erfc_inv_small(&Float512::from(self))
+        } else if *self < Self::ONE + half {
+            let y = Self::ONE - *self;
+            if y.eq_zero() {
+                return Self::ZERO;
+            }
+            erf_inv(&Float512::from(&y))
+        } else {
+            -erfc_inv_small(&Float512::from(&(Self::TWO - *self)))
+        };
+        Self::from(&x)
+    }
+}
+
+#[cfg(test)]
+mod erf_tests {
+    use super::*;
+
+    #[test]
+    fn test_specials() {
+        assert!(f256::NAN.erf().is_nan());
+        assert_eq!(f256::ZERO.erf().to_bits(), f256::ZERO.to_bits());
+        assert_eq!(f256::NEG_ZERO.erf().to_bits(), f256::NEG_ZERO.to_bits());
+        assert_eq!(f256::INFINITY.erf(), f256::ONE);
+        assert_eq!(f256::NEG_INFINITY.erf(), f256::NEG_ONE);
+        assert_eq!(f256::MAX.erf(), f256::ONE);
+        assert_eq!(ERF_LIMIT.erf(), f256::ONE);
+        assert_eq!((-ERF_LIMIT).erf(), f256::NEG_ONE);
+    }
+
+    #[test]
+    fn test_symmetry() {
+        for x in [
+            f256::MIN_GT_ZERO,
+            f256::from(1e-30),
+            f256::from(0.125),
+            f256::from(2.75),
+            f256::from(9.5),
+        ] {
+            assert_eq!((-x).erf(), -x.erf());
+        }
+    }
+
+    #[test]
+    fn test_small() {
+        // erf(x) ≈ 2x/√π for small x
+        let x = f256::MIN_POSITIVE;
+        let y = Float512::from(&x) * FRAC_1_SQRT_PI.mul_pow2(1);
+        assert_eq!(x.erf(), f256::from(&y));
+        let x = f256::MIN_GT_ZERO;
+        assert_eq!(x.erf(), x);
+    }
+
+    #[test]
+    fn test_near_one() {
+        // erfc(13) < 2⁻²⁴⁰
+        assert_eq!(f256::from(13).erf(), f256::ONE);
+        let x = f256::from(11);
+        let y = x.erf();
+        assert!(y < f256::ONE);
+        assert!((f256::ONE - y - x.erfc()).abs() <= f256::EPSILON.div2());
+    }
+}
+
+#[cfg(test)]
+mod erfc_tests {
+    use super::*;
+
+    #[test]
+    fn test_specials() {
+        assert!(f256::NAN.erfc().is_nan());
+        assert_eq!(f256::ZERO.erfc(), f256::ONE);
+        assert_eq!(f256::NEG_ZERO.erfc(), f256::ONE);
+        assert_eq!(f256::INFINITY.erfc().to_bits(), f256::ZERO.to_bits());
+        assert_eq!(f256::NEG_INFINITY.erfc(), f256::TWO);
+        assert_eq!(f256::MAX.erfc().to_bits(), f256::ZERO.to_bits());
+        assert_eq!(f256::MIN.erfc(), f256::TWO);
+        assert_eq!(ERFC_LIMIT.erfc(), f256::ZERO);
+    }
+
+    #[test]
+    fn test_complement() {
+        for x in [
+            f256::from(1e-10),
+            f256::from(0.375),
+            f256::from(-1.25),
+            f256::from(3),
+            f256::from(-7.5),
+        ] {
+            let s = x.erf() + x.erfc();
+            assert!(s.diff_within_n_bits(&f256::ONE, 1), "x = {x}");
+        }
+    }
+
+    #[test]
+    fn test_tail() {
+        // erfc(x) ≈ e^(-x²)/(x⋅√π) for large x
+        // and e^(-x²)/(x⋅√π)⋅(1 - 1/(2x²)) < erfc(x) < e^(-x²)/(x⋅√π)
+        let x = f256::from(300);
+        let y = x.erfc();
+        let z = f256::from(&(d_erf(&Float512::from(&x)).mul_pow2(-1))) / x;
+        assert!(y < z);
+        assert!(y > z * (f256::ONE - x.square().mul2().recip()));
+        // erfc(x) underflows for some 426 < x <= 426.5
+        let x = f256::from(426.5);
+        assert_eq!(x.erfc(), f256::ZERO);
+        assert!((x - f256::ONE.div2()).erfc() > f256::ZERO);
+    }
+}
+
+#[cfg(test)]
+mod erf_inv_tests {
+    use super::*;
+
+    #[test]
+    fn test_specials() {
+        assert!(f256::NAN.erf_inv().is_nan());
+        assert!(f256::TWO.erf_inv().is_nan());
+        assert!((f256::NEG_ONE - f256::EPSILON).erf_inv().is_nan());
+        assert!(f256::INFINITY.erf_inv().is_nan());
+        assert_eq!(f256::ONE.erf_inv(), f256::INFINITY);
+        assert_eq!(f256::NEG_ONE.erf_inv(), f256::NEG_INFINITY);
+        assert_eq!(
+            f256::NEG_ZERO.erf_inv().to_bits(),
+            f256::NEG_ZERO.to_bits()
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for x in [
+            f256::MIN_POSITIVE,
+            f256::from(1e-200),
+            f256::from(-0.0703125),
+            f256::from(0.3125),
+        ] {
+            // erf is injective on f256 for |x| < 0.35, because there
+            // erf'(x) >= 1.
+            assert_eq!(x.erf().erf_inv(), x, "x = {x}");
+        }
+    }
+
+    #[test]
+    fn test_near_one() {
+        let y = f256::ONE - f256::EPSILON.div2();
+        let x = y.erf_inv();
+        assert!(x > f256::from(12) && x < f256::from(13));
+        assert_eq!((-y).erf_inv(), -x);
+        assert_eq!(y.erf_inv(), (f256::EPSILON.div2()).erfc_inv());
+    }
+}
+
+#[cfg(test)]
+mod erfc_inv_tests {
+    use super::*;
+
+    #[test]
+    fn test_specials() {
+        assert!(f256::NAN.erfc_inv().is_nan());
+        assert!(f256::NEG_ONE.erfc_inv().is_nan());
+        assert!((-f256::MIN_GT_ZERO).erfc_inv().is_nan());
+        assert!((f256::TWO + f256::EPSILON.mul2()).erfc_inv().is_nan());
+        assert_eq!(f256::ZERO.erfc_inv(), f256::INFINITY);
+        assert_eq!(f256::NEG_ZERO.erfc_inv(), f256::INFINITY);
+        assert_eq!(f256::TWO.erfc_inv(), f256::NEG_INFINITY);
+        assert_eq!(f256::ONE.erfc_inv(), f256::ZERO);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for x in [
+            f256::from(0.75),
+            f256::from(1.5),
+            f256::from(7),
+            f256::from(26.125),
+            f256::from(400),
+        ] {
+            assert_eq!(x.erfc().erfc_inv(), x, "x = {x}");
+        }
+    }
+
+    #[test]
+    fn test_tiny() {
+        let x = f256::MIN_GT_ZERO.erfc_inv();
+        assert!(x > f256::from(426) && x < f256::from(426.5));
+        assert_eq!(x.erfc(), f256::MIN_GT_ZERO);
+    }
+}
@@ -39,13 +39,27 @@ const LOG2_MAX: f256 = f256 {
     ),
 };
 
+// Largest x with 10ˣ < f256::MAX + ½⋅f256::MAX.ulp()
+// 78913.2071833386864381103688186575000091203682860029814532806963697046488
+const LOG10_MAX: f256 = f256 {
+    bits: U256::new(
+        0x4000f34413509f79fef311f12b35816f,
+        0x922f04d5a618a87a3e69314bcde4d6f9,
+    ),
+};
+
+// x < LOG10_MIN => 10ˣ < ½⋅f256::MIN_GT_ZERO
+const LOG10_MIN: f256 = f256::from_u64(78984).negated();
+
 #[allow(clippy::cast_sign_loss)]
 pub(crate) fn approx_exp(x: &Float512) -> Float512 {
-    // 2⁻²³⁶ <= |x| <= LN_MAX
+    // Precondition: 2⁻²³⁶ <= |x| < 2¹⁸
+    // |x| is not limited to LN_MAX, so the result may exceed the range of
+    // f256; it is returned within the wider exponent range of Float512.
     let mut m = x.abs();
     let mut e = 0_i32;
     // exp(|x|) = exp(m⋅2ᵉ)
-    // |x| <= LN_MAX => |e| <= 18
+    // |x| < 2¹⁸ => e <= 18
     // Assure that m < 1.5 as pre-condition for using fn bkm_e.
     if m > Float512::THREE_HALF {
         let ms = m.signif();
@@ -83,6 +97,17 @@ pub(crate) fn approx_exp(x: &Float512) -> Float512 {
     res
 }
 
+// Returns eʷ-1 for w != 0. If `is_tiny` is true, |w| is small enough for two
+// terms of the Taylor series to give full precision.
+#[inline(always)]
+fn exp_m1(w: &Float512, is_tiny: bool) -> f256 {
+    if is_tiny {
+        // for very small w, eʷ-1 ≅ w+½w²
+        return f256::from(&(*w + w.square().mul_pow2(-1)));
+    }
+    f256::from(&(approx_exp(w) - Float512::ONE))
+}
+
 impl f256 {
     /// Returns e^(self), (the exponential function).
     #[must_use]
@@ -191,6 +216,114 @@ impl f256 {
             }
         }
     }
+
+    /// Returns 10^(self).
+    #[must_use]
+    pub fn exp10(&self) -> Self {
+        // x = 0  or x is subnormal => 10ˣ = 1
+        // x = ∞ => 10ˣ = ∞
+        // x = -∞ => 10ˣ = 0
+        // x is nan => 10ˣ is nan
+        match self.classify() {
+            FpCategory::Zero | FpCategory::Subnormal => Self::ONE,
+            FpCategory::Infinite => {
+                [Self::INFINITY, Self::ZERO][self.sign() as usize]
+            }
+            FpCategory::Nan => Self::NAN,
+            _ => {
+                // self is finite and != 0
+                if self > &LOG10_MAX {
+                    return Self::INFINITY;
+                }
+                if self < &LOG10_MIN {
+                    return Self::ZERO;
+                }
+                if let Ok(n) = i32::try_from(self) {
+                    // 10ⁿ is exact in Float512 for 0 <= n <= 219, so it's
+                    // rounded only once. Otherwise it carries the errors of
+                    // upto 34 Float512 multiplications (plus the reciprocal
+                    // for n < 0), i.e. a relative error below 2⁻⁵⁰⁴, before
+                    // the final rounding.
+                    return Self::from(&Float512::from(10).powi(n));
+                }
+                // 10ˣ = eʷ with w = x⋅logₑ 10
+                let w = Float512::from(self) * Float512::LN_10;
+                if self.abs() <= Self::EPSILON {
+                    // for very small w, eʷ ≅ 1+w+½w²
+                    return Self::from(
+                        &(Float512::ONE + w + w.square().mul_pow2(-1)),
+                    );
+                }
+                Self::from(&approx_exp(&w))
+            }
+        }
+    }
+
+    /// Returns 2^(self) - 1 in a way that is accurate even if the number is
+    /// close to zero.
+    #[must_use]
+    pub fn exp2_m1(&self) -> Self {
+        // x = ±0 => 2ˣ-1 = ±0
+        // x = ∞ => 2ˣ-1 = ∞
+        // x = -∞ => 2ˣ-1 = -1
+        // x is nan => 2ˣ-1 is nan
+        match self.classify() {
+            FpCategory::Zero => *self,
+            FpCategory::Infinite => {
+                [Self::INFINITY, Self::NEG_ONE][self.sign() as usize]
+            }
+            FpCategory::Nan => Self::NAN,
+            _ => {
+                // self is finite and != 0
+                if self.abs() > LOG2_MAX {
+                    return [Self::INFINITY, Self::NEG_ONE]
+                        [self.sign() as usize];
+                }
+                if let Ok(e) = i32::try_from(self) {
+                    return Self::from(
+                        &(Float512::ONE.mul_pow2(e) - Float512::ONE),
+                    );
+                }
+                // 2ˣ-1 = eʷ-1 with w = x⋅logₑ 2
+                let w = Float512::from(self) * Float512::LN_2;
+                exp_m1(&w, self.abs() <= Self::EPSILON)
+            }
+        }
+    }
+
+    /// Returns 10^(self) - 1 in a way that is accurate even if the number
+    /// is close to zero.
+    #[must_use]
+    pub fn exp10_m1(&self) -> Self {
+        // x = ±0 => 10ˣ-1 = ±0
+        // x = ∞ => 10ˣ-1 = ∞
+        // x = -∞ => 10ˣ-1 = -1
+        // x is nan => 10ˣ-1 is nan
+        match self.classify() {
+            FpCategory::Zero => *self,
+            FpCategory::Infinite => {
+                [Self::INFINITY, Self::NEG_ONE][self.sign() as usize]
+            }
+            FpCategory::Nan => Self::NAN,
+            _ => {
+                // self is finite and != 0
+                if self > &LOG10_MAX {
+                    return Self::INFINITY;
+                }
+                if self < &LOG10_MIN {
+                    return Self::NEG_ONE;
+                }
+                if let Ok(n) = i32::try_from(self) {
+                    return Self::from(
+                        &(Float512::from(10).powi(n) - Float512::ONE),
+                    );
+                }
+                // 10ˣ-1 = eʷ-1 with w = x⋅logₑ 10
+                let w = Float512::from(self) * Float512::LN_10;
+                exp_m1(&w, self.abs() <= Self::EPSILON)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -395,3 +528,148 @@ mod exp2_tests {
         assert_eq!(f.exp2(), f256::INFINITY);
     }
 }
+
+#[cfg(test)]
+mod exp10_tests {
+    use super::*;
+
+    #[test]
+    fn test_specials() {
+        assert!(f256::NAN.exp10().is_nan());
+        assert_eq!(f256::INFINITY.exp10(), f256::INFINITY);
+        assert_eq!(f256::NEG_INFINITY.exp10(), f256::ZERO);
+        assert_eq!(f256::ZERO.exp10(), f256::ONE);
+        assert_eq!(f256::NEG_ZERO.exp10(), f256::ONE);
+        assert_eq!(f256::MIN_GT_ZERO.exp10(), f256::ONE);
+    }
+
+    #[test]
+    fn test_integers() {
+        let mut p = f256::ONE;
+        for n in 1..=102 {
+            p *= f256::TEN;
+            assert_eq!(f256::from(n).exp10(), p, "{n}");
+        }
+        assert_eq!(f256::NEG_ONE.exp10(), f256::ONE / f256::TEN);
+        assert_eq!(f256::from(-3).exp10(), f256::ONE / f256::from(1000));
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert!(LOG10_MAX.exp10().is_finite());
+        let f = LOG10_MAX + LOG10_MAX.ulp();
+        assert_eq!(f.exp10(), f256::INFINITY);
+    }
+
+    #[test]
+    fn test_underflow() {
+        assert!(f256::from(-78983).exp10().is_subnormal());
+        let f = LOG10_MIN - LOG10_MIN.ulp();
+        assert_eq!(f.exp10(), f256::ZERO);
+    }
+}
+
+#[cfg(test)]
+mod exp2_m1_tests {
+    use super::*;
+    use crate::{consts::LN_2, ONE_HALF};
+    use core::ops::Neg;
+
+    #[test]
+    fn test_specials() {
+        assert!(f256::NAN.exp2_m1().is_nan());
+        assert_eq!(f256::INFINITY.exp2_m1(), f256::INFINITY);
+        assert_eq!(f256::NEG_INFINITY.exp2_m1(), f256::NEG_ONE);
+        assert_eq!(f256::ZERO.exp2_m1().to_bits(), f256::ZERO.to_bits());
+        assert_eq!(
+            f256::NEG_ZERO.exp2_m1().to_bits(),
+            f256::NEG_ZERO.to_bits()
+        );
+    }
+
+    #[test]
+    fn test_integers() {
+        for n in 1_u32..=100 {
+            let p = f256::from((1_u128 << n) - 1);
+            assert_eq!(f256::from(n).exp2_m1(), p, "{n}");
+        }
+        assert_eq!(f256::NEG_ONE.exp2_m1(), -ONE_HALF);
+        assert_eq!(f256::from(300).exp2_m1(), f256::power_of_two(300));
+        assert_eq!(f256::from(-300).exp2_m1(), f256::NEG_ONE);
+    }
+
+    #[test]
+    fn test_near_zero() {
+        assert_eq!(f256::MIN_GT_ZERO.exp2_m1(), f256::MIN_GT_ZERO);
+        for f in [
+            f256::MIN_POSITIVE,
+            f256::EPSILON.div_pow2(100),
+            f256::EPSILON,
+        ] {
+            let r = f * LN_2;
+            assert!((f.exp2_m1() - r).abs() <= r.ulp(), "{f:?}");
+            let r = f.neg() * LN_2;
+            assert!((f.neg().exp2_m1() - r).abs() <= r.ulp(), "{f:?}");
+        }
+    }
+
+    #[test]
+    fn test_overflow() {
+        let f = LOG2_MAX + LOG2_MAX.ulp();
+        assert_eq!(f.exp2_m1(), f256::INFINITY);
+        assert_eq!(f.neg().exp2_m1(), f256::NEG_ONE);
+    }
+}
+
+#[cfg(test)]
+mod exp10_m1_tests {
+    use super::*;
+    use crate::consts::LN_10;
+    use core::ops::Neg;
+
+    #[test]
+    fn test_specials() {
+        assert!(f256::NAN.exp10_m1().is_nan());
+        assert_eq!(f256::INFINITY.exp10_m1(), f256::INFINITY);
+        assert_eq!(f256::NEG_INFINITY.exp10_m1(), f256::NEG_ONE);
+        assert_eq!(f256::ZERO.exp10_m1().to_bits(), f256::ZERO.to_bits());
+        assert_eq!(
+            f256::NEG_ZERO.exp10_m1().to_bits(),
+            f256::NEG_ZERO.to_bits()
+        );
+    }
+
+    #[test]
+    fn test_integers() {
+        for n in 1_u32..=38 {
+            let p = f256::from(10_u128.pow(n) - 1);
+            assert_eq!(f256::from(n).exp10_m1(), p, "{n}");
+        }
+        let f = -(f256::from(9) / f256::TEN);
+        assert_eq!(f256::NEG_ONE.exp10_m1(), f);
+        assert_eq!(f256::from(-80).exp10_m1(), f256::NEG_ONE);
+    }
+
+    #[test]
+    fn test_near_zero() {
+        assert_eq!(f256::MIN_GT_ZERO.exp10_m1(), f256::MIN_GT_ZERO.mul2());
+        for f in [
+            f256::MIN_POSITIVE,
+            f256::EPSILON.div_pow2(100),
+            f256::EPSILON,
+        ] {
+            let r = f * LN_10;
+            assert!((f.exp10_m1() - r).abs() <= r.ulp(), "{f:?}");
+            let r = f.neg() * LN_10;
+            assert!((f.neg().exp10_m1() - r).abs() <= r.ulp(), "{f:?}");
+        }
+    }
+
+    #[test]
+    fn test_overflow() {
+        let f = LOG10_MAX + LOG10_MAX.ulp();
+        assert_eq!(f.exp10_m1(), f256::INFINITY);
+        let f = LOG10_MIN - LOG10_MIN.ulp();
+        assert_eq!(f.exp10_m1(), f256::NEG_ONE);
+    }
+}
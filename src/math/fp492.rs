@@ -13,7 +13,7 @@ use core::{
     ops::{AddAssign, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
-use super::Float256;
+use super::{Float256, Float512};
 use crate::{
     f256, split_f256_enc, BigUInt, HiLo, EXP_BITS, FRACTION_BITS,
     SIGNIFICAND_BITS, U1024, U256, U512,
@@ -208,6 +208,23 @@ impl From<&Float256> for FP492 {
     }
 }
 
+impl From<&Float512> for FP492 {
+    #[allow(clippy::cast_possible_wrap)]
+    #[allow(clippy::cast_sign_loss)]
+    fn from(value: &Float512) -> Self {
+        debug_assert!(value.exp() <= Self::INT_BITS as i32);
+        let sh = (Self::INT_BITS as i32 - 1 - value.exp()) as u32;
+        if sh >= U512::BITS {
+            return Self::ZERO;
+        }
+        let mut res = Self(value.signif() >> sh);
+        if value.signum() < 0 {
+            res.ineg();
+        }
+        res
+    }
+}
+
 impl From<&FP492> for Float256 {
     #[allow(clippy::cast_possible_wrap)]
     #[allow(clippy::cast_sign_loss)]
@@ -245,6 +262,21 @@ impl From<&FP492> for Float256 {
     }
 }
 
+impl From<&FP492> for Float512 {
+    // Exact conversion, because the significand of a Float512 can hold
+    // all bits of an FP492.
+    #[allow(clippy::cast_possible_wrap)]
+    fn from(value: &FP492) -> Self {
+        let sign = (value.signum() < 0) as u32;
+        let abs = value.abs();
+        Self::from_sign_exp_signif(
+            sign,
+            -(FP492::FRACTION_BITS as i32),
+            &abs.0.as_u128_chunks(),
+        )
+    }
+}
+
 impl From<&f256> for FP492 {
     #[allow(clippy::cast_possible_wrap)]
     #[allow(clippy::cast_sign_loss)]
@@ -506,4 +538,18 @@ mod test_fp492_conv {
         let y = FP492::from(&f256::from(-7));
         assert_eq!(x, y);
     }
+
+    #[test]
+    fn test_into_float512() {
+        let x = f256::from(-7) - f256::from(f64::EPSILON) * f256::TEN;
+        let a = FP492::from(&x);
+        assert_eq!(f256::from(&Float512::from(&a)), x);
+        let mut b = FP492::EPSILON;
+        b.ineg();
+        assert_eq!(
+            Float512::from(&b),
+            -Float512::ONE.mul_pow2(-(FP492::FRACTION_BITS as i32))
+        );
+        assert_eq!(Float512::from(&FP492::ZERO), Float512::ZERO);
+    }
 }
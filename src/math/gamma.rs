@@ -0,0 +1,1250 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use core::num::FpCategory;
+
+use super::{exp::approx_exp, log::approx_ln, Float512, Parity};
+use crate::f256;
+
+// Stirling's series is evaluated for arguments y >= 2⁷. Using the
+// coefficients below, its truncation error is then less than 2⁻⁵²⁰.
+const STIRLING_MIN_EXP: i32 = 7;
+const STIRLING_MIN: f256 = f256::from_u64(1 << STIRLING_MIN_EXP);
+
+// x > 20500 => Γ(x) > f256::MAX
+// x < -20500 => |Γ(x)| < ½⋅f256::MIN_GT_ZERO
+const GAMMA_LIMIT: f256 = f256::from_u64(20500);
+
+// (n-1)! is exactly representable as Float512 for n <= 100.
+const MAX_EXACT_FACTORIAL: i32 = 100;
+
+// HALF_LN_2PI = ◯₅₁₀(½⋅logₑ(2π)) =
+// 0.91893853320467274178032973640561763986139747363778341281715154048276569592726039769474329863595419762200564662463433744636686288184079357215587591522268139
+const HALF_LN_2PI: Float512 = Float512::new(
+    1,
+    -1,
+    &[
+        0x759fc72192fad29a4a5e4800a20c9011,
+        0xe7d847c689a2c5a6ef6351899ed50aae,
+        0x9097cebff007435fc9f55fc82e2ab4dd,
+        0xd82e55ab8da66d2de7b37670bb95d6f9,
+    ],
+);
+
+const N_COEFFS: usize = 63;
+
+// Coefficients B₂ₖ / (2k⋅(2k-1)) of Stirling's series, where B₂ₖ are the
+// Bernoulli numbers.
+const STIRLING_COEFFS: [Float512; N_COEFFS] = [
+    // B₂ / (2⋅1) ≈ 8.33333333333333333333e-2
+    Float512::new(
+        1,
+        -4,
+        &[
+            0x55555555555555555555555555555555,
+            0x55555555555555555555555555555555,
+            0x55555555555555555555555555555555,
+            0x55555555555555555555555555555555,
+        ],
+    ),
+    // B₄ / (4⋅3) ≈ -2.77777777777777777778e-3
+    Float512::new(
+        -1,
+        -9,
+        &[
+            0x5b05b05b05b05b05b05b05b05b05b05b,
+            0x05b05b05b05b05b05b05b05b05b05b05,
+            0xb05b05b05b05b05b05b05b05b05b05b0,
+            0x5b05b05b05b05b05b05b05b05b05b05b,
+        ],
+    ),
+    // B₆ / (6⋅5) ≈ 7.93650793650793650794e-4
+    Float512::new(
+        1,
+        -11,
+        &[
+            0x68068068068068068068068068068068,
+            0x06806806806806806806806806806806,
+            0x80680680680680680680680680680680,
+            0x68068068068068068068068068068068,
+        ],
+    ),
+    // B₈ / (8⋅7) ≈ -5.95238095238095238095e-4
+    Float512::new(
+        -1,
+        -11,
+        &[
+            0x4e04e04e04e04e04e04e04e04e04e04e,
+            0x04e04e04e04e04e04e04e04e04e04e04,
+            0xe04e04e04e04e04e04e04e04e04e04e0,
+            0x4e04e04e04e04e04e04e04e04e04e04e,
+        ],
+    ),
+    // B₁₀ / (10⋅9) ≈ 8.41750841750841750842e-4
+    Float512::new(
+        1,
+        -11,
+        &[
+            0x6e5478ac63fc8d5c3a9ce01b951e2b18,
+            0xff23570ea73806e5478ac63fc8d5c3a9,
+            0xce01b951e2b18ff23570ea73806e5478,
+            0xac63fc8d5c3a9ce01b951e2b18ff2357,
+        ],
+    ),
+    // B₁₂ / (12⋅11) ≈ -1.91752691752691752692e-3
+    Float512::new(
+        -1,
+        -10,
+        &[
+            0x7daac36664f1f207daac36664f1f207d,
+            0xaac36664f1f207daac36664f1f207daa,
+            0xc36664f1f207daac36664f1f207daac3,
+            0x6664f1f207daac36664f1f207daac366,
+        ],
+    ),
+    // B₁₄ / (14⋅13) ≈ 6.41025641025641025641e-3
+    Float512::new(
+        1,
+        -8,
+        &[
+            0x69069069069069069069069069069069,
+            0x06906906906906906906906906906906,
+            0x90690690690690690690690690690690,
+            0x69069069069069069069069069069069,
+        ],
+    ),
+    // B₁₆ / (16⋅15) ≈ -2.95506535947712418301e-2
+    Float512::new(
+        -1,
+        -6,
+        &[
+            0x790a1b2c3d4e5f708192a3b4c5d6e7f9,
+            0x0a1b2c3d4e5f708192a3b4c5d6e7f90a,
+            0x1b2c3d4e5f708192a3b4c5d6e7f90a1b,
+            0x2c3d4e5f708192a3b4c5d6e7f90a1b2c,
+        ],
+    ),
+    // B₁₈ / (18⋅17) ≈ 1.79644372368830573165e-1
+    Float512::new(
+        1,
+        -3,
+        &[
+            0x5bfa58e07819ffe861dbfa58e07819ff,
+            0xe861dbfa58e07819ffe861dbfa58e078,
+            0x19ffe861dbfa58e07819ffe861dbfa58,
+            0xe07819ffe861dbfa58e07819ffe861dc,
+        ],
+    ),
+    // B₂₀ / (20⋅19) ≈ -1.39243221690590111643
+    Float512::new(
+        -1,
+        0,
+        &[
+            0x591d9c04607ce7b6f6e73989661f5303,
+            0xa458a60fa5538111d9c04607ce7b6f6e,
+            0x73989661f5303a458a60fa5538111d9c,
+            0x04607ce7b6f6e73989661f5303a458a6,
+        ],
+    ),
+    // B₂₂ / (22⋅21) ≈ 1.34028640441683919945e+1
+    Float512::new(
+        1,
+        3,
+        &[
+            0x6b3910c8b380169d3d4e44322ce005a7,
+            0x4f53910c8b380169d3d4e44322ce005a,
+            0x74f53910c8b380169d3d4e44322ce005,
+            0xa74f53910c8b380169d3d4e44322ce00,
+        ],
+    ),
+    // B₂₄ / (24⋅23) ≈ -1.56848284626002017306e+2
+    Float512::new(
+        -1,
+        7,
+        &[
+            0x4e6c949733306aad9fb8975cf03e51ce,
+            0xda254949733306aad9fb8975cf03e51c,
+            0xeda254949733306aad9fb8975cf03e51,
+            0xceda254949733306aad9fb8975cf03e5,
+        ],
+    ),
+    // B₂₆ / (26⋅25) ≈ 2.19310333333333333333e+3
+    Float512::new(
+        1,
+        11,
+        &[
+            0x4488d3a06d3a06d3a06d3a06d3a06d3a,
+            0x06d3a06d3a06d3a06d3a06d3a06d3a06,
+            0xd3a06d3a06d3a06d3a06d3a06d3a06d3,
+            0xa06d3a06d3a06d3a06d3a06d3a06d3a0,
+        ],
+    ),
+    // B₂₈ / (28⋅27) ≈ -3.61087712537249893572e+4
+    Float512::new(
+        -1,
+        15,
+        &[
+            0x468662b8712adfacffb776125a47f8d9,
+            0xca6c9717e928421646aa0e6de5e345ae,
+            0xf5e880dc6803502550ccb67af9d4e2f1,
+            0xfc1ec6d3e7d976b9bff63c33b389b045,
+        ],
+    ),
+    // B₃₀ / (30⋅29) ≈ 6.91472268851313067108e+5
+    Float512::new(
+        1,
+        19,
+        &[
+            0x546882269b8468e10cf727ee2234e657,
+            0x6e58034c11a6ac15cb049eaf72b16042,
+            0x34b3d94f71b4e9a7b52bed3dba341ee5,
+            0x41171a7a88c6584269b8468e10cf727f,
+        ],
+    ),
+    // B₃₂ / (32⋅31) ≈ -1.52382215394074161923e+7
+    Float512::new(
+        -1,
+        23,
+        &[
+            0x744226c50b4d5de2030b4d5de2030b4d,
+            0x5de2030b4d5de2030b4d5de2030b4d5d,
+            0xe2030b4d5de2030b4d5de2030b4d5de2,
+            0x030b4d5de2030b4d5de2030b4d5de203,
+        ],
+    ),
+    // B₃₄ / (34⋅33) ≈ 3.82900751391414141414e+8
+    Float512::new(
+        1,
+        28,
+        &[
+            0x5b4a683d90cede62433b79890cede624,
+            0x33b79890cede62433b79890cede62433,
+            0xb79890cede62433b79890cede62433b7,
+            0x9890cede62433b79890cede62433b799,
+        ],
+    ),
+    // B₃₆ / (36⋅35) ≈ -1.0882266035784391089e+10
+    Float512::new(
+        -1,
+        33,
+        &[
+            0x511446767919bb57530126ae24bb0b1a,
+            0x6d44603c9f83d0fc798335bf5290e2e6,
+            0xedd899271e688241fcdf37af187d7e73,
+            0xa112d096c509dc94650028c1f5dafb3c,
+        ],
+    ),
+    // B₃₈ / (38⋅37) ≈ 3.47320283765002252252e+11
+    Float512::new(
+        1,
+        38,
+        &[
+            0x50dde6f27500939a85c40939a85c4093,
+            0x9a85c40939a85c40939a85c40939a85c,
+            0x40939a85c40939a85c40939a85c40939,
+            0xa85c40939a85c40939a85c40939a85c4,
+        ],
+    ),
+    // B₄₀ / (40⋅39) ≈ -1.23696021422692744543e+13
+    Float512::new(
+        -1,
+        43,
+        &[
+            0x5a002def01ea3215121ac0b8a57b2151,
+            0x21ac0b8a57b215121ac0b8a57b215121,
+            0xac0b8a57b215121ac0b8a57b215121ac,
+            0x0b8a57b215121ac0b8a57b215121ac0c,
+        ],
+    ),
+    // B₄₂ / (42⋅41) ≈ 4.88788064793079335076e+14
+    Float512::new(
+        1,
+        48,
+        &[
+            0x6f2335be3c7dd571e1d4f36d757236cc,
+            0x7776564f42b9f68f55604a969d96e7ae,
+            0xbea1274987c8a20b61fe0b2244681b9b,
+            0xc252a9a2ffea563a04d8f4571e1d4f37,
+        ],
+    ),
+    // B₄₄ / (44⋅43) ≈ -2.1320333960919373897e+16
+    Float512::new(
+        -1,
+        54,
+        &[
+            0x4bbebb143bb94de5a0284fa7ec423225,
+            0xb901be2f0a8b30da65eab49b3d471d2e,
+            0x43bfbca173a2bef5d5ff69ccade05a02,
+            0x84fa7ec423225b901be2f0a8b30da65f,
+        ],
+    ),
+    // B₄₆ / (46⋅45) ≈ 1.02177529652570007757e+18
+    Float512::new(
+        1,
+        59,
+        &[
+            0x717099bfad785f6c85b5851a96a799ae,
+            0x41ed32cbe9913537a35d291d026165a2,
+            0x274c37086eb6200909a4930602fc5415,
+            0x24458ec97dd76943bdfe24e07d3c7ad1,
+        ],
+    ),
+    // B₄₈ / (48⋅47) ≈ -5.35754721733002036108e+19
+    Float512::new(
+        -1,
+        65,
+        &[
+            0x5cf04a02c56c480b5a7c97fcc366f751,
+            0x04ec6c40d6a2b8ab0fa853e987a59d46,
+            0x4904c38fd3be1d71b338ad9d6f2571dc,
+            0x151ab5f46c69b30fd13056a02ae43e20,
+        ],
+    ),
+    // B₅₀ / (50⋅49) ≈ 3.06157826370488341504e+21
+    Float512::new(
+        1,
+        71,
+        &[
+            0x52fbf77cf38d63e4019355a6645f9fbe,
+            0x23c7a38ad8432074859ecaf6a8c4506c,
+            0xec35d3fcd5699917e7ef88f1e8e2b610,
+            0xc81d2167b2bdaa31141b3b0d74ff355a,
+        ],
+    ),
+    // B₅₂ / (52⋅51) ≈ -1.89999174263992040503e+23
+    Float512::new(
+        -1,
+        77,
+        &[
+            0x5077c072bcaa04266d32492e3643248d,
+            0x34a6f778465ce75e838b9879e289fc4c,
+            0xcd9bb23cb3d8e0edcf37df51a222f107,
+            0x92092e3643248d34a6f778465ce75e84,
+        ],
+    ),
+    // B₅₄ / (54⋅53) ≈ 1.27633740338288341492e+25
+    Float512::new(
+        1,
+        83,
+        &[
+            0x5475ff246d0beecccbc83b05867012b7,
+            0x63ac3cbdfa413480335d3b886a4118f4,
+            0x192817fff5e63aa83fc56eefbf8edaf5,
+            0x6cad41687f0b3b21cd2c4636234378b7,
+        ],
+    ),
+    // B₅₆ / (56⋅55) ≈ -9.25284717612041630723e+26
+    Float512::new(
+        -1,
+        89,
+        &[
+            0x5fac15219aab7d8b9264ad5ab65f6177,
+            0x9dd0daf789458a3c1204db9be3cb65f6,
+            0x1779dd0daf789458a3c1204db9be3cb6,
+            0x5f61779dd0daf789458a3c1204db9be4,
+        ],
+    ),
+    // B₅₈ / (58⋅57) ≈ 7.21882259518561029784e+28
+    Float512::new(
+        1,
+        95,
+        &[
+            0x74a059b91f363e873bb873388218b6e5,
+            0xa2e075bc7436b4eaf6fdfed5319b43b9,
+            0x41be95dc1ef4dc596b27b5e84cef3a48,
+            0xd2e8fc521c5e4a58a8481009567325e2,
+        ],
+    ),
+    // B₆₀ / (60⋅59) ≈ -6.04518340599585696774e+30
+    Float512::new(
+        -1,
+        102,
+        &[
+            0x4c4d0a8344b39331fc661da7a28c1af0,
+            0x8ba58c64deb053ea5ae39f40e6ae770d,
+            0xd0f030cd55358ae5bc923c8fe6df3087,
+            0x2cefb7a34f93934bc1f7236d615beaf9,
+        ],
+    ),
+    // B₆₂ / (62⋅61) ≈ 5.42067047157009454519e+32
+    Float512::new(
+        1,
+        108,
+        &[
+            0x6ae7646072d667f6ac7df6cae1c80166,
+            0x1ec6a5122f901661ec6a5122f901661e,
+            0xc6a5122f901661ec6a5122f901661ec6,
+            0xa5122f901661ec6a5122f901661ec6a5,
+        ],
+    ),
+    // B₆₄ / (64⋅63) ≈ -5.1929578153140819467e+34
+    Float512::new(
+        -1,
+        115,
+        &[
+            0x50029b6552aeca370972dce139c786c6,
+            0xa6656151100bfbbab6a6656151100bfb,
+            0xbab6a6656151100bfbbab6a665615110,
+            0x0bfbbab6a6656151100bfbbab6a66561,
+        ],
+    ),
+    // B₆₆ / (66⋅65) ≈ 5.30365885511970059665e+36
+    Float512::new(
+        1,
+        121,
+        &[
+            0x7fae524038c6823c029cc802af139676,
+            0x609af2ecd0c61231a0073ac314af8c6c,
+            0xe609af2ecd0c61231a0073ac314af8c6,
+            0xce609af2ecd0c61231a0073ac314af8c,
+        ],
+    ),
+    // B₆₈ / (68⋅67) ≈ -5.76332534816496401389e+38
+    Float512::new(
+        -1,
+        128,
+        &[
+            0x6c6566f47ea12921a8e73296d0d64837,
+            0xc4b86c0104d6fd32b0c7b499dacf58e6,
+            0x45cb86c0104d6fd32b0c7b499dacf58e,
+            0x645cb86c0104d6fd32b0c7b499dacf59,
+        ],
+    ),
+    // B₇₀ / (70⋅69) ≈ 6.65115571484845393752e+40
+    Float512::new(
+        1,
+        135,
+        &[
+            0x61baddfa5e8025fa36930530a7fee9b6,
+            0xbeb2614e628a941e1b048897c6867484,
+            0x6994e1e702592c5f0df08778c4d92954,
+            0xbb4a4f1a2d4755ce22b1cc7b7b4219a1,
+        ],
+    ),
+    // B₇₂ / (72⋅71) ≈ -8.13737835813668053872e+42
+    Float512::new(
+        -1,
+        142,
+        &[
+            0x5d699b10dfd929649cec7478b59bcc9b,
+            0x2cb12a3bba682049979815d954f7b3d8,
+            0x2cbf418cd5b9ece2b7b95de768c74dc1,
+            0x9e35ebd87ed88d9d148c2eb5c2dc97a7,
+        ],
+    ),
+    // B₇₄ / (74⋅73) ≈ 1.05369669533571418038e+45
+    Float512::new(
+        1,
+        149,
+        &[
+            0x5e7fb09071c905a2fa87eb0eb97f4835,
+            0x5d87b30095a02568095a02568095a025,
+            0x68095a02568095a02568095a02568095,
+            0xa02568095a02568095a02568095a0257,
+        ],
+    ),
+    // B₇₆ / (76⋅75) ≈ -1.44181805999622062618e+47
+    Float512::new(
+        -1,
+        156,
+        &[
+            0x650557d8706956ce7c6e3cee4118e7bf,
+            0x51ff20f29185933a7b55c9d77ebf9a0e,
+            0x1bc303de526007482296a44b8c66dae8,
+            0x8fd0ab1f2cd414ef6371185933a7b55d,
+        ],
+    ),
+    // B₇₈ / (78⋅77) ≈ 2.08173565220895654624e+49
+    Float512::new(
+        1,
+        163,
+        &[
+            0x71f35468e7654eb54bc09a7e2fdeb6fa,
+            0x3b3e4aecafd56137f1938d34f422b0e4,
+            0x7ae66c8a0cee697a23fc4f5733d1c2af,
+            0xac8ecbc957f5bea775226fbcd8b455de,
+        ],
+    ),
+    // B₈₀ / (80⋅79) ≈ -3.16702266348866618274e+51
+    Float512::new(
+        -1,
+        171,
+        &[
+            0x43b7b7fead15a196f6dd288b6e7f7fab,
+            0x43fc59c1a0da6c9fcace8fd521699779,
+            0x2624a7294bc82b224cf10c3818943b94,
+            0x520522d0a485de3ffac4ed907a4f05bd,
+        ],
+    ),
+    // B₈₂ / (82⋅81) ≈ 5.07000646121113734318e+53
+    Float512::new(
+        1,
+        178,
+        &[
+            0x54b17eb5a4753454551d491d2c2e4dea,
+            0x47b4e533415f512bcd7b9d5a1b16191b,
+            0x335797b765c1fbb5b8d82ecf93c19a3a,
+            0xc47554ed5ab70e0686298e5961dfaf2c,
+        ],
+    ),
+    // B₈₄ / (84⋅83) ≈ -8.52997282030055188162e+55
+    Float512::new(
+        -1,
+        185,
+        &[
+            0x6f52434e68c89fa9bd104cb5cd548059,
+            0x6fc5ddd76d40d982dd8e6975f27415c7,
+            0x22e76c4e1b06686806c23aa9d6622ecc,
+            0x28d6b1c595c9754d7633b3217772b5e4,
+        ],
+    ),
+    // B₈₆ / (86⋅85) ≈ 1.50641728093405985767e+58
+    Float512::new(
+        1,
+        193,
+        &[
+            0x4ccba9af16d295a21a1944ee560ca626,
+            0x6931dcad22ab92300d35353535353535,
+            0x35353535353535353535353535353535,
+            0x35353535353535353535353535353535,
+        ],
+    ),
+    // B₈₈ / (88⋅87) ≈ -2.78934947038316368713e+60
+    Float512::new(
+        -1,
+        200,
+        &[
+            0x6f179dd3237c88ba5740992a63e36fad,
+            0x4e74575464539e2571f6a35d71c3039b,
+            0x7f674b44d2bae7be447c40477ed59154,
+            0x338c865c068cc8f77a596bbdd907d4ab,
+        ],
+    ),
+    // B₉₀ / (90⋅89) ≈ 5.40935043528604150058e+62
+    Float512::new(
+        1,
+        208,
+        &[
+            0x5427fb2692dc16ff82903bf92ecb7e4f,
+            0xee015073f51ac75256f1b6c11c8093a6,
+            0x54d75ce14945bbc89052ef55edee3b32,
+            0xad7a739442c196339291ad076a1e553f,
+        ],
+    ),
+    // B₉₂ / (92⋅91) ≈ -1.0975337821508519855e+65
+    Float512::new(
+        -1,
+        216,
+        &[
+            0x42b2ec79f1ee52af4a59282f7159825a,
+            0xe65175b49bf2a163f7c6b4fbb250ee97,
+            0x1504609d2ff3ad8719ce35660f91fe92,
+            0x4b2f97bd91a2a255b86bdde3feee3b25,
+        ],
+    ),
+    // B₉₄ / (94⋅93) ≈ 2.32748762026184791735e+67
+    Float512::new(
+        1,
+        223,
+        &[
+            0x6e810b828ccf1d7d52a8fc7e2f489555,
+            0xd41c612969e0c7db9fbac50c5cc9dd21,
+            0xf3277487cc9dd21f3277487cc9dd21f3,
+            0x277487cc9dd21f3277487cc9dd21f327,
+        ],
+    ),
+    // B₉₆ / (96⋅95) ≈ -5.15392916206532139019e+69
+    Float512::new(
+        -1,
+        231,
+        &[
+            0x5f95bde3d3cbcaa1e3c94b0882708eb2,
+            0xedf01697fc7492e8882fa7ca127feb99,
+            0x95105a9a6cf2823362affbc4b2b4bb9d,
+            0x1eccc8438dcda025b56695e32ef7e5e8,
+        ],
+    ),
+    // B₉₈ / (98⋅97) ≈ 1.19062102308902264577e+72
+    Float512::new(
+        1,
+        239,
+        &[
+            0x56414b4ac94855722283860f420ba216,
+            0xbc09f2752a07bfd604b72900ef57401c,
+            0x26b5392ea01c26b5392ea01c26b5392e,
+            0xa01c26b5392ea01c26b5392ea01c26b5,
+        ],
+    ),
+    // B₁₀₀ / (100⋅99) ≈ -2.86689389602966736962e+74
+    Float512::new(
+        -1,
+        247,
+        &[
+            0x5121563c7b955ba2aec0f1606f7de814,
+            0x7993d489c6d3af561b5289a4477a90f6,
+            0x0533b50604fd8e9b40818813c0007bf5,
+            0xdf04db7faedc22d92750311f0b30f337,
+        ],
+    ),
+    // B₁₀₂ / (102⋅101) ≈ 7.18930780233729864393e+76
+    Float512::new(
+        1,
+        255,
+        &[
+            0x4f790523fb110b47f51656028d6b7421,
+            0x6cfd6ee395438dce626e2981fb248367,
+            0x6c2ec311fd7f0742d157d41ae5b1ff7b,
+            0xce4c29bcba297dbae02edc44e79e9381,
+        ],
+    ),
+    // B₁₀₄ / (104⋅103) ≈ -1.87606934305046718479e+79
+    Float512::new(
+        -1,
+        263,
+        &[
+            0x5102a01805f1f4cadd23a71fe674a3d6,
+            0x279b3217fe4e04e6bf8d8d8040d795c1,
+            0xe6ea6eaa810c83c4cfed24565fd8e21f,
+            0x21ce18f120c5fc0df7ed8460e0ff024b,
+        ],
+    ),
+    // B₁₀₆ / (106⋅105) ≈ 5.09049146907510704813e+81
+    Float512::new(
+        1,
+        271,
+        &[
+            0x55dd2bd24b982c41652829d512900063,
+            0x07fb7d1c85f68a08c76156db5fa62916,
+            0xa7d852ce175915dded6bff6c39c13797,
+            0x102ba0a21d45b7a5a56b16184269d545,
+        ],
+    ),
+    // B₁₀₈ / (108⋅107) ≈ -1.43514288284321710937e+84
+    Float512::new(
+        -1,
+        279,
+        &[
+            0x5e8f4beb1ed3037c464f8fed6d8870dc,
+            0xc06bbf3838c38ea9989303917a8d8924,
+            0xac06a3d0b7dd5f6928ffd45e3f9f2459,
+            0xd406b4dd2407cbcd6c2bc0869bbebf39,
+        ],
+    ),
+    // B₁₁₀ / (110⋅109) ≈ 4.20090575066658694072e+86
+    Float512::new(
+        1,
+        287,
+        &[
+            0x6c1f3ccb5752fd523facfc810a7e2f15,
+            0xdef1d8337eb00426490b9c58df2781d3,
+            0xffa88017d30631d3e64ff0507b0394f5,
+            0x64694d45670471528b3d398f8075467d,
+        ],
+    ),
+    // B₁₁₂ / (112⋅111) ≈ -1.27585812722475759084e+89
+    Float512::new(
+        -1,
+        296,
+        &[
+            0x4022e209f980e384cb3c3b39a3590dc8,
+            0x38b8d39464db50e18686cd8d868d2eee,
+            0xb94a73f88f5726ba11068cbb3f59772f,
+            0x01efe711392d61bf0de27464ff417541,
+        ],
+    ),
+    // B₁₁₄ / (114⋅113) ≈ 4.01775684036218188046e+91
+    Float512::new(
+        1,
+        304,
+        &[
+            0x4ee4e8add6eeb6b973035282e8e6ee1b,
+            0x52fe3c2bf17687286a3bc86401cef81e,
+            0xba068c375804df0804e3fcb1114bfbd4,
+            0x20f0804e3fcb1114bfbd420f0804e3fd,
+        ],
+    ),
+    // B₁₁₆ / (116⋅115) ≈ -1.31101363120068345875e+94
+    Float512::new(
+        -1,
+        312,
+        &[
+            0x648f8dc5cbb832ee382e2626ad0a2057,
+            0x7cbd7aafac9361d6fd89debbb830eac8,
+            0x34367618a3d3a91cca67c74615d406cf,
+            0xc3598dd9286de406353846a6b6688874,
+        ],
+    ),
+    // B₁₁₈ / (118⋅117) ≈ 4.42999565369782596377e+96
+    Float512::new(
+        1,
+        321,
+        &[
+            0x425e0c8b0048673d851bdbc823748dee,
+            0xd61db0927b403923a12864080ff71d30,
+            0xaea9c450611abdfa58bab656100bab65,
+            0x6100bab656100bab656100bab656100c,
+        ],
+    ),
+    // B₁₂₀ / (120⋅119) ≈ -1.54921406951735538812e+99
+    Float512::new(
+        -1,
+        329,
+        &[
+            0x5aa9572500c3ea7f2923d190b9d5c124,
+            0x1ad05aee220d7ec70a3f099415ea09b3,
+            0x2ad318f4fd1246b448ce9a44fe8e3c98,
+            0x420556dde496577fd2e36990d7612a8b,
+        ],
+    ),
+    // B₁₂₂ / (122⋅121) ≈ 5.60376485562735197495e+101
+    Float512::new(
+        1,
+        338,
+        &[
+            0x400ce225c240bf22cd6febcb534cb1fd,
+            0xf2d032f674906caf362a6f0fa71e0ab3,
+            0x2d9fee74763e0b354000fdd44685fe96,
+            0xeb8c5772f402d228e7511a17fa5bae31,
+        ],
+    ),
+    // B₁₂₄ / (124⋅123) ≈ -2.09539241485809792706e+104
+    Float512::new(
+        -1,
+        346,
+        &[
+            0x5d8e1233973f1d2d3b3108b85cf79e55,
+            0x08bcd5e3f44bcdef2e4490ce3b57ea3a,
+            0xc7323e3f5881b2b147d35011559c6004,
+            0x70aaf1b559c600470aaf1b559c600471,
+        ],
+    ),
+    // B₁₂₆ / (126⋅125) ≈ 8.09529030056367808401e+106
+    Float512::new(
+        1,
+        355,
+        &[
+            0x4697e75f0425026355cc1ab2c0dff490,
+            0x130439f094ff5bc9dff51daa36e077b5,
+            0x52700eef010c9b63acd71e82c9e8c812,
+            0xda8df75b5fa6dd8ddd2ac462a14d1c33,
+        ],
+    ),
+];
+
+// Returns ∑ B₂ₖ / (2k⋅(2k-1)⋅y²ᵏ⁻¹) for k = 1 … N_COEFFS.
+fn stirling_series(y: &Float512) -> Float512 {
+    let t = y.recip();
+    let t2 = t.square();
+    let mut s = STIRLING_COEFFS[N_COEFFS - 1];
+    for c in STIRLING_COEFFS[..N_COEFFS - 1].iter().rev() {
+        s = s.mul_add(&t2, c);
+    }
+    s * t
+}
+
+// Returns logₑ Γ(y) for y >= 2⁷, using Stirling's series
+// logₑ Γ(y) = (y - ½)⋅logₑ y - y + ½⋅logₑ(2π) + ∑ B₂ₖ / (2k⋅(2k-1)⋅y²ᵏ⁻¹)
+fn ln_gamma_stirling(y: &Float512) -> Float512 {
+    debug_assert!(y.signum() == 1 && y.exp() >= STIRLING_MIN_EXP);
+    (*y - Float512::ONE_HALF) * approx_ln(y) - *y
+        + HALF_LN_2PI
+        + stirling_series(y)
+}
+
+// Returns y = x + n and p = x⋅(x+1)⋅…⋅(x+n-1), where n is the smallest
+// non-negative integer so that y >= 2⁷. Then Γ(x) = Γ(y) / p.
+fn shift(x: &Float512) -> (Float512, Float512) {
+    let mut y = *x;
+    let mut p = Float512::ONE;
+    while y.signum() != 1 || y.exp() < STIRLING_MIN_EXP {
+        p *= y;
+        y += Float512::ONE;
+    }
+    (y, p)
+}
+
+// Returns logₑ Γ(x) for x > 0.
+fn ln_gamma_pos(x: &Float512) -> Float512 {
+    debug_assert!(x.signum() == 1);
+    let (y, p) = shift(x);
+    let mut l = ln_gamma_stirling(&y);
+    if p != Float512::ONE {
+        l -= approx_ln(&p);
+    }
+    l
+}
+
+// Returns logₑ Γ(a + b) - logₑ Γ(a) for a >= b > 0.
+fn ln_gamma_ratio(a: &Float512, b: &Float512) -> Float512 {
+    let s = *a + *b;
+    if a.exp() < STIRLING_MIN_EXP {
+        return ln_gamma_pos(&s) - ln_gamma_pos(a);
+    }
+    // Subtracting the Stirling series for s and a, the difference of the
+    // leading terms is
+    // (s - ½)⋅logₑ s - (a - ½)⋅logₑ a - b
+    // = (s - ½)⋅logₑ(1 + b/a) + b⋅(logₑ a - 1)
+    // which avoids the cancellation for large a.
+    (s - Float512::ONE_HALF) * ln_1p(&(*b / *a))
+        + *b * (approx_ln(a) - Float512::ONE)
+        + (stirling_series(&s) - stirling_series(a))
+}
+
+// Returns logₑ(1 + t) for 0 < t <= 1.
+fn ln_1p(t: &Float512) -> Float512 {
+    if t.exp() >= -32 {
+        return approx_ln(&(Float512::ONE + *t));
+    }
+    // logₑ(1 + t) = t - ½t² + ⅓t³ - …
+    let mut sum = *t;
+    let mut pow = *t;
+    let mut k = 1_i32;
+    loop {
+        k += 1;
+        pow = -(pow * *t);
+        let term = pow / Float512::from(k);
+        if term.exp() < sum.exp() - 512 {
+            return sum;
+        }
+        sum += term;
+    }
+}
+
+// Returns eˣ for |x| < 2¹⁸.
+fn exp(x: &Float512) -> Float512 {
+    if x.is_zero() {
+        return Float512::ONE;
+    }
+    if x.exp() < -170 {
+        // eˣ ≈ 1 + x + ½x², the truncation error is less than 2⁻⁵¹²
+        return Float512::ONE + *x + x.square().mul_pow2(-1);
+    }
+    approx_exp(x)
+}
+
+// Returns |sin(π⋅r)| for 0 < |r| <= ½.
+fn abs_sin_pi(r: &f256) -> Float512 {
+    let a = Float512::from(&r.abs());
+    // |sin(π⋅r)| = sin(π⋅a) = cos(π⋅(½ - a))
+    // Both series are evaluated for arguments <= ¼π.
+    let (t, mut n) = if a.exp() < -2 {
+        (Float512::PI * a, 1_i32)
+    } else {
+        (Float512::PI * (Float512::ONE_HALF - a), 0_i32)
+    };
+    let t2 = t.square();
+    let mut term = if n == 1 { t } else { Float512::ONE };
+    let mut sum = term;
+    loop {
+        term = -(term * t2) / Float512::from((n + 1) * (n + 2));
+        n += 2;
+        if term.is_zero() || term.exp() < sum.exp() - 512 {
+            return sum;
+        }
+        sum += term;
+    }
+}
+
+// Returns true if Γ(x) < 0, i.e. if x < 0 and ⌊x⌋ is odd.
+fn gamma_is_negative(x: &f256) -> bool {
+    x.is_sign_negative()
+        && (x.eq_zero() || x.floor().parity() == Some(Parity::Odd))
+}
+
+impl f256 {
+    /// Returns the gamma function of `self`.
+    ///
+    /// Γ(x) = ∫ tˣ⁻¹⋅e⁻ᵗ dt over (0, ∞) for x > 0, extended to x < 0 by the
+    /// reflection formula Γ(x)⋅Γ(1-x) = π / sin(π⋅x). The result is NaN for
+    /// negative integers and -∞.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// assert_eq!(f256::from(5).gamma(), f256::from(24));
+    /// let f = f256::from(0.5).gamma();
+    /// assert_eq!(f, ::f256::consts::PI.sqrt());
+    /// assert!(f256::from(-3).gamma().is_nan());
+    /// ```
+    #[must_use]
+    pub fn gamma(&self) -> Self {
+        // x is nan or x = -∞ => Γ(x) is nan
+        // x = ∞ => Γ(x) = ∞
+        // x = ±0 => Γ(x) = ±∞
+        match self.classify() {
+            FpCategory::Nan => Self::NAN,
+            FpCategory::Infinite => {
+                [Self::INFINITY, Self::NAN][self.sign() as usize]
+            }
+            FpCategory::Zero => {
+                [Self::INFINITY, Self::NEG_INFINITY][self.sign() as usize]
+            }
+            _ => {
+                // self is finite and != 0
+                if self.is_integer() {
+                    if self.is_sign_negative() {
+                        // Γ has poles at negative integers
+                        return Self::NAN;
+                    }
+                    if let Ok(n) = i32::try_from(self) {
+                        if n <= MAX_EXACT_FACTORIAL {
+                            // Γ(n) = (n-1)!
+                            let mut p = Float512::ONE;
+                            for k in 2..n {
+                                p *= Float512::from(k);
+                            }
+                            return Self::from(&p);
+                        }
+                    }
+                }
+                if self.abs() > GAMMA_LIMIT {
+                    return if self.is_sign_positive() {
+                        Self::INFINITY
+                    } else {
+                        [Self::ZERO, Self::NEG_ZERO]
+                            [gamma_is_negative(self) as usize]
+                    };
+                }
+                let x = Float512::from(self);
+                let mut res = if self < &-STIRLING_MIN {
+                    // Γ(x) = π / (sin(π⋅x)⋅Γ(1-x))
+                    // sin(π⋅x) = ±sin(π⋅r) with r = x - round(x)
+                    let r = *self - self.round();
+                    let g = exp(&ln_gamma_stirling(&(Float512::ONE - x)));
+                    Float512::PI / (abs_sin_pi(&r) * g)
+                } else {
+                    // Γ(x) = Γ(x+n) / (x⋅(x+1)⋅…⋅(x+n-1))
+                    let (y, p) = shift(&x);
+                    (exp(&ln_gamma_stirling(&y)) / p).abs()
+                };
+                if gamma_is_negative(self) {
+                    res.flip_sign();
+                }
+                Self::from(&res)
+            }
+        }
+    }
+
+    /// Returns the natural logarithm of the absolute value of the gamma
+    /// function of `self` and the sign of the gamma function of `self`
+    /// (1 or -1), also known as `lgamma`.
+    ///
+    /// The sign is 1 for NaN, ±∞ and the poles of the gamma function (i.e.
+    /// negative integers), which give a logarithm of ∞.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// assert_eq!(f256::from(3).ln_gamma(), (f256::TWO.ln(), 1));
+    /// let (l, s) = f256::from(-0.5).ln_gamma();
+    /// assert_eq!(s, -1);
+    /// assert!(l.diff_within_n_bits(&f256::from(-0.5).gamma().abs().ln(), 1));
+    /// let (l, s) = f256::MAX.ln_gamma();
+    /// assert_eq!((l, s), (f256::INFINITY, 1));
+    /// ```
+    #[doc(alias = "lgamma")]
+    #[must_use]
+    pub fn ln_gamma(&self) -> (Self, i32) {
+        // x is nan => logₑ |Γ(x)| is nan
+        // x = ±∞ => logₑ |Γ(x)| = ∞
+        // x = ±0 => logₑ |Γ(x)| = ∞, sign ±1
+        match self.classify() {
+            FpCategory::Nan => (Self::NAN, 1),
+            FpCategory::Infinite => (Self::INFINITY, 1),
+            FpCategory::Zero => {
+                (Self::INFINITY, [1, -1][self.sign() as usize])
+            }
+            _ => {
+                // self is finite and != 0
+                if self.is_integer() {
+                    if self.is_sign_negative() {
+                        return (Self::INFINITY, 1);
+                    }
+                    if self == &Self::ONE || self == &Self::TWO {
+                        // Γ(1) = Γ(2) = 1
+                        return (Self::ZERO, 1);
+                    }
+                }
+                let x = Float512::from(self);
+                let l = if self < &-STIRLING_MIN {
+                    // logₑ |Γ(x)| = logₑ π - logₑ |sin(π⋅x)| - logₑ Γ(1-x)
+                    let r = *self - self.round();
+                    approx_ln(&Float512::PI)
+                        - approx_ln(&abs_sin_pi(&r))
+                        - ln_gamma_stirling(&(Float512::ONE - x))
+                } else {
+                    // logₑ |Γ(x)| = logₑ Γ(x+n) - logₑ |x⋅(x+1)⋅…⋅(x+n-1)|
+                    let (y, p) = shift(&x);
+                    let mut l = ln_gamma_stirling(&y);
+                    if p != Float512::ONE {
+                        l -= approx_ln(&p.abs());
+                    }
+                    l
+                };
+                (Self::from(&l), [1, -1][gamma_is_negative(self) as usize])
+            }
+        }
+    }
+
+    /// Returns the beta function of `self` and `other`, i.e.
+    /// B(x, y) = Γ(x)⋅Γ(y) / Γ(x+y).
+    ///
+    /// The result is NaN if one of the arguments is NaN or less than 0, and
+    /// ∞ if one of them is ±0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// let x = f256::from(3);
+    /// let y = f256::from(4);
+    /// assert_eq!(x.beta(&y), f256::ONE / f256::from(60));
+    /// let x = f256::from(1e100);
+    /// assert_eq!(x.beta(&f256::ONE), x.recip());
+    /// ```
+    #[must_use]
+    pub fn beta(&self, other: &Self) -> Self {
+        if self.is_nan()
+            || other.is_nan()
+            || (self.is_sign_negative() && !self.eq_zero())
+            || (other.is_sign_negative() && !other.eq_zero())
+        {
+            return Self::NAN;
+        }
+        let (a, b) = if self >= other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        // a >= b >= 0
+        if b.eq_zero() {
+            return Self::INFINITY;
+        }
+        if a.is_infinite() {
+            return Self::ZERO;
+        }
+        // logₑ B(a, b) = logₑ Γ(b) - (logₑ Γ(a+b) - logₑ Γ(a))
+        let a = Float512::from(a);
+        let b = Float512::from(b);
+        let l = ln_gamma_pos(&b) - ln_gamma_ratio(&a, &b);
+        if l.exp() >= 18 {
+            // |l| >= 2¹⁸ > logₑ(f256::MAX)
+            return [Self::ZERO, Self::INFINITY][(l.signum() == 1) as usize];
+        }
+        Self::from(&exp(&l))
+    }
+}
+
+#[cfg(test)]
+mod gamma_tests {
+    use super::*;
+    use crate::consts::PI;
+
+    #[test]
+    fn test_specials() {
+        assert!(f256::NAN.gamma().is_nan());
+        assert!(f256::NEG_INFINITY.gamma().is_nan());
+        assert_eq!(f256::INFINITY.gamma(), f256::INFINITY);
+        assert_eq!(f256::ZERO.gamma(), f256::INFINITY);
+        assert_eq!(f256::NEG_ZERO.gamma(), f256::NEG_INFINITY);
+        for i in [-1, -2, -7, -128, -20501, i32::MIN] {
+            assert!(f256::from(i).gamma().is_nan());
+        }
+        assert!(f256::MIN.gamma().is_nan());
+    }
+
+    #[test]
+    fn test_factorials() {
+        // n! is exactly representable for n <= 40.
+        let mut f = f256::ONE;
+        for n in 1..=41 {
+            assert_eq!(f256::from(n).gamma(), f, "n = {n}");
+            f *= f256::from(n);
+        }
+        let f = f256::from(101).gamma();
+        assert!(f.diff_within_n_bits(
+            &(f256::from(100).gamma() * f256::from(100)),
+            1
+        ));
+        assert_eq!(f256::ONE.gamma(), f256::ONE);
+        assert_eq!(f256::TWO.gamma(), f256::ONE);
+    }
+
+    #[test]
+    fn test_half_integers() {
+        // Γ(½) = √π, Γ(x+1) = x⋅Γ(x)
+        let half = f256::ONE.div2();
+        let sqrt_pi = PI.sqrt();
+        assert_eq!(half.gamma(), sqrt_pi);
+        assert_eq!((-half).gamma(), -sqrt_pi.mul2());
+        let g = (half + f256::from(3)).gamma();
+        assert!(g.diff_within_n_bits(&(f256::from(1.875) * sqrt_pi), 1));
+    }
+
+    #[test]
+    fn test_sign() {
+        for (x, neg) in [
+            (-0.5, true),
+            (-1.5, false),
+            (-2.5, true),
+            (-126.75, true),
+            (-129.25, false),
+            (-200.5, true),
+        ] {
+            let g = f256::from(x).gamma();
+            assert_eq!(g.is_sign_negative(), neg, "x = {x}");
+        }
+    }
+
+    #[test]
+    fn test_reflection() {
+        // Γ(x)⋅Γ(1-x) = π / sin(π⋅x)
+        for x in [f256::from(-130.375), f256::from(-1000.0625)] {
+            let p = x.gamma() * (f256::ONE - x).gamma();
+            let s = PI / (PI * x).sin();
+            // π⋅x is not exact, so that the sine loses some bits.
+            assert!(p.diff_within_n_bits(&s, 12), "x = {x}");
+        }
+    }
+
+    #[test]
+    fn test_limits() {
+        assert_eq!(GAMMA_LIMIT.gamma(), f256::INFINITY);
+        assert_eq!(f256::MAX.gamma(), f256::INFINITY);
+        assert!(f256::from(20300).gamma().is_finite());
+        assert_eq!(f256::from(20400).gamma(), f256::INFINITY);
+        let x = -GAMMA_LIMIT - f256::ONE.div2();
+        assert!(x.gamma().eq_zero() && x.gamma().is_sign_negative());
+        let x = x - f256::ONE;
+        assert_eq!(x.gamma().to_bits(), f256::ZERO.to_bits());
+        // Γ(x) ≈ 1/x for tiny x
+        let x = f256::MIN_POSITIVE;
+        assert_eq!(x.gamma(), x.recip());
+        assert_eq!(f256::MIN_GT_ZERO.gamma(), f256::INFINITY);
+        assert_eq!((-f256::MIN_GT_ZERO).gamma(), f256::NEG_INFINITY);
+    }
+}
+
+#[cfg(test)]
+mod ln_gamma_tests {
+    use super::*;
+
+    #[test]
+    fn test_specials() {
+        assert!(f256::NAN.ln_gamma().0.is_nan());
+        assert_eq!(f256::INFINITY.ln_gamma(), (f256::INFINITY, 1));
+        assert_eq!(f256::NEG_INFINITY.ln_gamma(), (f256::INFINITY, 1));
+        assert_eq!(f256::ZERO.ln_gamma(), (f256::INFINITY, 1));
+        assert_eq!(f256::NEG_ZERO.ln_gamma(), (f256::INFINITY, -1));
+        assert_eq!(f256::from(-3).ln_gamma(), (f256::INFINITY, 1));
+        assert_eq!(f256::MIN.ln_gamma(), (f256::INFINITY, 1));
+        assert_eq!(f256::MAX.ln_gamma(), (f256::INFINITY, 1));
+    }
+
+    #[test]
+    fn test_zeros() {
+        let (l, s) = f256::ONE.ln_gamma();
+        assert_eq!(l.to_bits(), f256::ZERO.to_bits());
+        assert_eq!(s, 1);
+        assert_eq!(f256::TWO.ln_gamma(), (f256::ZERO, 1));
+        // logₑ Γ(1+x) ≈ -γ⋅x for small x
+        let x = f256::EPSILON;
+        let (l, s) = (f256::ONE + x).ln_gamma();
+        assert_eq!(s, 1);
+        assert!(l.is_sign_negative());
+        let gamma = f256::from(0.5772156649015329);
+        assert!((l / x + gamma).abs() < f256::from(1e-15));
+    }
+
+    #[test]
+    fn test_consistency() {
+        for x in [
+            f256::from(0.001),
+            f256::from(-0.75),
+            f256::from(-5.5),
+            f256::from(17.25),
+            f256::from(-140.5),
+            f256::from(1e4),
+        ] {
+            let g = x.gamma();
+            let (l, s) = x.ln_gamma();
+            assert_eq!(s, [1, -1][g.is_sign_negative() as usize]);
+            assert!(l.diff_within_n_bits(&g.abs().ln(), 1), "x = {x}");
+        }
+    }
+
+    #[test]
+    fn test_large() {
+        // logₑ Γ(x) ≈ x⋅(logₑ x - 1) for large x
+        let x = f256::from(1e300);
+        let (l, s) = x.ln_gamma();
+        assert_eq!(s, 1);
+        assert!(l.diff_within_n_bits(&(x * (x.ln() - f256::ONE)), 4));
+        let x = f256::from(-1e60) - f256::ONE.div2();
+        let (l, s) = x.ln_gamma();
+        assert_eq!(s, -1);
+        assert!(l < -x * x.abs().ln());
+    }
+}
+
+#[cfg(test)]
+mod beta_tests {
+    use super::*;
+    use crate::consts::PI;
+
+    #[test]
+    fn test_specials() {
+        let x = f256::from(2.5);
+        for (a, b) in [
+            (f256::NAN, x),
+            (x, f256::NAN),
+            (f256::NEG_ONE, x),
+            (x, -f256::MIN_GT_ZERO),
+            (f256::NEG_INFINITY, x),
+        ] {
+            assert!(a.beta(&b).is_nan());
+        }
+        assert_eq!(f256::ZERO.beta(&x), f256::INFINITY);
+        assert_eq!(x.beta(&f256::NEG_ZERO), f256::INFINITY);
+        assert_eq!(f256::INFINITY.beta(&x), f256::ZERO);
+        assert_eq!(x.beta(&f256::INFINITY), f256::ZERO);
+    }
+
+    #[test]
+    fn test_values() {
+        let half = f256::ONE.div2();
+        assert_eq!(half.beta(&half), PI);
+        assert_eq!(f256::ONE.beta(&f256::ONE), f256::ONE);
+        // B(a, 1) = 1/a
+        for a in [f256::from(3), f256::from(1e-10), f256::from(12345.5)] {
+            assert_eq!(a.beta(&f256::ONE), a.recip());
+            assert_eq!(f256::ONE.beta(&a), a.recip());
+        }
+        // B(m, n) = (m-1)!⋅(n-1)! / (m+n-1)!
+        let m = f256::from(7);
+        let n = f256::from(300);
+        let b = m.gamma() * n.gamma() / (m + n).gamma();
+        assert!(m.beta(&n).diff_within_n_bits(&b, 2));
+    }
+
+    #[test]
+    fn test_extremes() {
+        let tiny = f256::MIN_POSITIVE;
+        assert_eq!(tiny.beta(&f256::ONE), tiny.recip());
+        assert_eq!(f256::MIN_GT_ZERO.beta(&f256::ONE), f256::INFINITY);
+        let big = f256::from(1e6);
+        assert_eq!(big.beta(&big), f256::ZERO);
+        let x = f256::from(1e200);
+        assert!(x
+            .beta(&f256::TWO)
+            .diff_within_n_bits(&x.square().recip(), 1));
+    }
+}
@@ -0,0 +1,455 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use core::num::FpCategory;
+
+use super::{exp::approx_exp, log::approx_ln, Float512};
+use crate::{abs_bits, exp, f256};
+
+// For |x| < 2⁻¹⁰⁰ the results are calculated from the first two terms of
+// the Taylor series. The truncation error is then < 2⁻⁴⁰⁰⋅|result|.
+const EXP_SERIES: i32 = -100;
+
+// |x| >= 2¹⁸ => |sinh x| > f256::MAX and cosh x > f256::MAX
+const EXP_OVERFLOW: i32 = 18;
+
+// |x| >= 2⁷ => 1 - |tanh x| < 2⁻³⁶⁸, so tanh x rounds to ±1
+const EXP_TANH_ONE: i32 = 7;
+
+impl f256 {
+    /// Returns the hyperbolic sine of `self`.
+    #[must_use]
+    pub fn sinh(&self) -> Self {
+        // x = ±0 or x is subnormal => sinh x = x
+        // x = ±∞ => sinh x = x
+        // x is nan => sinh x is nan
+        match self.classify() {
+            FpCategory::Zero
+            | FpCategory::Subnormal
+            | FpCategory::Infinite => *self,
+            FpCategory::Nan => Self::NAN,
+            _ => {
+                // self is finite and != 0
+                let e = exp(&abs_bits(self));
+                let x = Float512::from(self);
+                if e < EXP_SERIES {
+                    // sinh x ≈ x + ⅙x³
+                    return Self::from(
+                        &(x + x.square() * x / Float512::from(6)),
+                    );
+                }
+                if e >= EXP_OVERFLOW {
+                    return [Self::INFINITY, Self::NEG_INFINITY]
+                        [self.sign() as usize];
+                }
+                // sinh x = ½(eˣ - e⁻ˣ)
+                let t = approx_exp(&x);
+                Self::from(&(t - t.recip()).mul_pow2(-1))
+            }
+        }
+    }
+
+    /// Returns the hyperbolic cosine of `self`.
+    #[must_use]
+    pub fn cosh(&self) -> Self {
+        // x = ±0 or x is subnormal => cosh x = 1
+        // x = ±∞ => cosh x = ∞
+        // x is nan => cosh x is nan
+        match self.classify() {
+            FpCategory::Zero | FpCategory::Subnormal => Self::ONE,
+            FpCategory::Infinite => Self::INFINITY,
+            FpCategory::Nan => Self::NAN,
+            _ => {
+                // self is finite and != 0
+                let e = exp(&abs_bits(self));
+                let x = Float512::from(self);
+                if e < EXP_SERIES {
+                    // cosh x ≈ 1 + ½x² + ¹⁄₂₄x⁴
+                    // The third term is needed to break a tie for x = 2⁻¹¹⁸.
+                    let x2 = x.square();
+                    return Self::from(
+                        &(Float512::ONE
+                            + x2.mul_pow2(-1)
+                            + x2.square() / Float512::from(24)),
+                    );
+                }
+                if e >= EXP_OVERFLOW {
+                    return Self::INFINITY;
+                }
+                // cosh x = ½(eˣ + e⁻ˣ)
+                let t = approx_exp(&x);
+                Self::from(&(t + t.recip()).mul_pow2(-1))
+            }
+        }
+    }
+
+    /// Returns the hyperbolic tangent of `self`.
+    #[must_use]
+    pub fn tanh(&self) -> Self {
+        // x = ±0 or x is subnormal => tanh x = x
+        // x = ±∞ => tanh x = ±1
+        // x is nan => tanh x is nan
+        match self.classify() {
+            FpCategory::Zero | FpCategory::Subnormal => *self,
+            FpCategory::Infinite => {
+                [Self::ONE, Self::NEG_ONE][self.sign() as usize]
+            }
+            FpCategory::Nan => Self::NAN,
+            _ => {
+                // self is finite and != 0
+                let e = exp(&abs_bits(self));
+                let x = Float512::from(self);
+                if e < EXP_SERIES {
+                    // tanh x ≈ x - ⅓x³
+                    return Self::from(
+                        &(x - x.square() * x / Float512::from(3)),
+                    );
+                }
+                if e >= EXP_TANH_ONE {
+                    return [Self::ONE, Self::NEG_ONE][self.sign() as usize];
+                }
+                // tanh |x| = (e²ˣ - 1) / (e²ˣ + 1) = u / (u + 2)
+                // with u = e²ˣ - 1
+                let u = approx_exp(&x.abs().mul_pow2(1)) - Float512::ONE;
+                let mut t = u / (u + Float512::TWO);
+                t.copy_sign(&x);
+                Self::from(&t)
+            }
+        }
+    }
+
+    /// Returns the inverse hyperbolic sine of `self`.
+    #[must_use]
+    pub fn asinh(&self) -> Self {
+        // x = ±0 or x is subnormal => asinh x = x
+        // x = ±∞ => asinh x = x
+        // x is nan => asinh x is nan
+        match self.classify() {
+            FpCategory::Zero
+            | FpCategory::Subnormal
+            | FpCategory::Infinite => *self,
+            FpCategory::Nan => Self::NAN,
+            _ => {
+                // self is finite and != 0
+                let e = exp(&abs_bits(self));
+                let x = Float512::from(self);
+                if e < EXP_SERIES {
+                    // asinh x ≈ x - ⅙x³
+                    return Self::from(
+                        &(x - x.square() * x / Float512::from(6)),
+                    );
+                }
+                // asinh |x| = ln(|x| + √(x² + 1))
+                let a = x.abs();
+                let mut t =
+                    approx_ln(&(a + (a.square() + Float512::ONE).sqrt()));
+                t.copy_sign(&x);
+                Self::from(&t)
+            }
+        }
+    }
+
+    /// Returns the inverse hyperbolic cosine of `self`.
+    #[must_use]
+    pub fn acosh(&self) -> Self {
+        // x < 1 or x is nan => acosh x is nan
+        // x = 1 => acosh x = 0
+        // x = ∞ => acosh x = ∞
+        if self.is_nan() || self < &Self::ONE {
+            return Self::NAN;
+        }
+        if self == &Self::ONE {
+            return Self::ZERO;
+        }
+        if self.is_infinite() {
+            return Self::INFINITY;
+        }
+        // acosh x = ln(x + √(x² - 1)) = ln(x + √((x - 1)⋅(x + 1)))
+        // x - 1 is exact for x close to 1, so computing x² - 1 this way
+        // avoids the cancellation in x² - 1.
+        let x = Float512::from(self);
+        let d = (x - Float512::ONE) * (x + Float512::ONE);
+        Self::from(&approx_ln(&(x + d.sqrt())))
+    }
+
+    /// Returns the inverse hyperbolic tangent of `self`.
+    #[must_use]
+    pub fn atanh(&self) -> Self {
+        // |x| > 1 or x is nan => atanh x is nan
+        // x = ±1 => atanh x = ±∞
+        // x = ±0 or x is subnormal => atanh x = x
+        match self.classify() {
+            FpCategory::Zero | FpCategory::Subnormal => *self,
+            FpCategory::Infinite | FpCategory::Nan => Self::NAN,
+            _ => {
+                // self is finite and != 0
+                let self_abs = self.abs();
+                if self_abs > Self::ONE {
+                    return Self::NAN;
+                }
+                if self_abs == Self::ONE {
+                    return [Self::INFINITY, Self::NEG_INFINITY]
+                        [self.sign() as usize];
+                }
+                let e = exp(&abs_bits(self));
+                let x = Float512::from(self);
+                if e < EXP_SERIES {
+                    // atanh x ≈ x + ⅓x³
+                    return Self::from(
+                        &(x + x.square() * x / Float512::from(3)),
+                    );
+                }
+                // atanh |x| = ½⋅ln((1 + |x|) / (1 - |x|))
+                let a = x.abs();
+                let mut t =
+                    approx_ln(&((Float512::ONE + a) / (Float512::ONE - a)))
+                        .mul_pow2(-1);
+                t.copy_sign(&x);
+                Self::from(&t)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod sinh_cosh_tests {
+    use super::*;
+
+    #[test]
+    fn test_specials() {
+        assert!(f256::NAN.sinh().is_nan());
+        assert!(f256::NAN.cosh().is_nan());
+        assert_eq!(f256::INFINITY.sinh(), f256::INFINITY);
+        assert_eq!(f256::NEG_INFINITY.sinh(), f256::NEG_INFINITY);
+        assert_eq!(f256::INFINITY.cosh(), f256::INFINITY);
+        assert_eq!(f256::NEG_INFINITY.cosh(), f256::INFINITY);
+        assert_eq!(f256::ZERO.sinh(), f256::ZERO);
+        assert!(f256::NEG_ZERO.sinh().is_sign_negative());
+        assert_eq!(f256::ZERO.cosh(), f256::ONE);
+        assert_eq!(f256::NEG_ZERO.cosh(), f256::ONE);
+    }
+
+    #[test]
+    fn test_subnormal() {
+        let f = f256::MIN_GT_ZERO;
+        assert_eq!(f.sinh(), f);
+        assert_eq!((-f).sinh(), -f);
+        assert_eq!(f.cosh(), f256::ONE);
+        let f = f256::MIN_POSITIVE - f256::MIN_GT_ZERO;
+        assert!(f.is_subnormal());
+        assert_eq!(f.sinh(), f);
+        assert_eq!(f.cosh(), f256::ONE);
+    }
+
+    #[test]
+    fn test_near_zero() {
+        let f = f256::EPSILON.div_pow2(2);
+        assert_eq!(f.sinh(), f);
+        assert_eq!((-f).sinh(), -f);
+        assert_eq!(f.cosh(), f256::ONE);
+        // sinh x = x + ⅙x³ + …
+        let f = f256::power_of_two(-117);
+        assert_eq!(f.sinh(), f + f.ulp());
+        let f = f256::power_of_two(-118);
+        assert_eq!(f.sinh(), f);
+        // cosh x = 1 + ½x² + …
+        let f = f256::power_of_two(-117);
+        assert_eq!(f.cosh(), f256::ONE + f256::EPSILON.mul2());
+        let f = f256::power_of_two(-118);
+        assert_eq!(f.cosh(), f256::ONE + f256::EPSILON);
+        let f = f256::power_of_two(-119);
+        assert_eq!(f.cosh(), f256::ONE);
+    }
+
+    #[test]
+    fn test_symmetry() {
+        for f in [
+            f256::from(0.001),
+            f256::from(0.75),
+            f256::ONE,
+            f256::from(7.5),
+            f256::from(1000),
+        ] {
+            assert_eq!((-f).sinh(), -f.sinh());
+            assert_eq!((-f).cosh(), f.cosh());
+        }
+    }
+
+    #[test]
+    fn test_identity() {
+        for f in [f256::from(0.5), f256::from(3), f256::from(21.25)] {
+            let s = f.sinh();
+            let c = f.cosh();
+            assert!((c + s).diff_within_n_bits(&f.exp(), 1));
+        }
+    }
+
+    #[test]
+    fn test_overflow() {
+        let f = f256::from(181705);
+        assert!(f.sinh().is_finite());
+        assert!(f.cosh().is_finite());
+        let f = f256::from(181706);
+        assert_eq!(f.sinh(), f256::INFINITY);
+        assert_eq!((-f).sinh(), f256::NEG_INFINITY);
+        assert_eq!(f.cosh(), f256::INFINITY);
+        assert_eq!((-f).cosh(), f256::INFINITY);
+        assert_eq!(f256::MAX.sinh(), f256::INFINITY);
+        assert_eq!(f256::MIN.sinh(), f256::NEG_INFINITY);
+        assert_eq!(f256::MIN.cosh(), f256::INFINITY);
+    }
+}
+
+#[cfg(test)]
+mod tanh_tests {
+    use super::*;
+
+    #[test]
+    fn test_specials() {
+        assert!(f256::NAN.tanh().is_nan());
+        assert_eq!(f256::INFINITY.tanh(), f256::ONE);
+        assert_eq!(f256::NEG_INFINITY.tanh(), f256::NEG_ONE);
+        assert_eq!(f256::ZERO.tanh(), f256::ZERO);
+        assert!(f256::NEG_ZERO.tanh().is_sign_negative());
+    }
+
+    #[test]
+    fn test_near_zero() {
+        let f = f256::MIN_GT_ZERO;
+        assert_eq!(f.tanh(), f);
+        let f = f256::EPSILON.div_pow2(2);
+        assert_eq!(f.tanh(), f);
+        assert_eq!((-f).tanh(), -f);
+        // tanh x = x - ⅓x³ + …
+        let f = f256::power_of_two(-118);
+        assert_eq!(f.tanh(), f - f.ulp().div2());
+    }
+
+    #[test]
+    fn test_near_one() {
+        let f = f256::from(82);
+        assert!(f.tanh() < f256::ONE);
+        assert_eq!(f256::from(84).tanh(), f256::ONE);
+        assert_eq!(f256::from(-84).tanh(), f256::NEG_ONE);
+        assert_eq!(f256::MAX.tanh(), f256::ONE);
+        assert_eq!(f256::MIN.tanh(), f256::NEG_ONE);
+    }
+}
+
+#[cfg(test)]
+mod asinh_tests {
+    use super::*;
+
+    #[test]
+    fn test_specials() {
+        assert!(f256::NAN.asinh().is_nan());
+        assert_eq!(f256::INFINITY.asinh(), f256::INFINITY);
+        assert_eq!(f256::NEG_INFINITY.asinh(), f256::NEG_INFINITY);
+        assert_eq!(f256::ZERO.asinh(), f256::ZERO);
+        assert!(f256::NEG_ZERO.asinh().is_sign_negative());
+    }
+
+    #[test]
+    fn test_near_zero() {
+        let f = f256::MIN_GT_ZERO;
+        assert_eq!(f.asinh(), f);
+        let f = f256::EPSILON.div_pow2(2);
+        assert_eq!(f.asinh(), f);
+        assert_eq!((-f).asinh(), -f);
+    }
+
+    #[test]
+    fn test_inverse() {
+        for f in [
+            f256::from(1e-20),
+            f256::from(-0.375),
+            f256::ONE,
+            f256::from(12345.678),
+        ] {
+            assert!(f.sinh().asinh().diff_within_n_bits(&f, 1));
+        }
+    }
+
+    #[test]
+    fn test_extremes() {
+        // asinh x ≈ ln 2x for large x
+        let f = f256::MAX;
+        assert!(f.asinh().diff_within_n_bits(&(f.ln() + f256::TWO.ln()), 1));
+        assert_eq!(f256::MIN.asinh(), -f.asinh());
+    }
+}
+
+#[cfg(test)]
+mod acosh_tests {
+    use super::*;
+
+    #[test]
+    fn test_specials() {
+        assert!(f256::NAN.acosh().is_nan());
+        assert!(f256::NEG_INFINITY.acosh().is_nan());
+        assert_eq!(f256::INFINITY.acosh(), f256::INFINITY);
+        assert!(f256::ZERO.acosh().is_nan());
+        assert!((f256::ONE - f256::EPSILON).acosh().is_nan());
+        assert_eq!(f256::ONE.acosh(), f256::ZERO);
+    }
+
+    #[test]
+    fn test_near_one() {
+        // acosh(1 + d) ≈ √(2d) for small d
+        let f = f256::ONE + f256::EPSILON;
+        let d = f256::EPSILON.mul2().sqrt();
+        assert!(f.acosh().diff_within_n_bits(&d, 1));
+    }
+
+    #[test]
+    fn test_inverse() {
+        for f in [f256::from(1.5), f256::from(12345.678)] {
+            assert!(f.cosh().acosh().diff_within_n_bits(&f, 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod atanh_tests {
+    use super::*;
+
+    #[test]
+    fn test_specials() {
+        assert!(f256::NAN.atanh().is_nan());
+        assert!(f256::INFINITY.atanh().is_nan());
+        assert!(f256::NEG_INFINITY.atanh().is_nan());
+        assert!((f256::ONE + f256::EPSILON).atanh().is_nan());
+        assert!(f256::from(-2).atanh().is_nan());
+        assert_eq!(f256::ONE.atanh(), f256::INFINITY);
+        assert_eq!(f256::NEG_ONE.atanh(), f256::NEG_INFINITY);
+        assert_eq!(f256::ZERO.atanh(), f256::ZERO);
+        assert!(f256::NEG_ZERO.atanh().is_sign_negative());
+    }
+
+    #[test]
+    fn test_near_zero() {
+        let f = f256::MIN_GT_ZERO;
+        assert_eq!(f.atanh(), f);
+        let f = f256::EPSILON.div_pow2(2);
+        assert_eq!(f.atanh(), f);
+        assert_eq!((-f).atanh(), -f);
+        // atanh x = x + ⅓x³ + …
+        let f = f256::power_of_two(-117);
+        assert_eq!(f.atanh(), f + f.ulp());
+        let f = f256::power_of_two(-118);
+        assert_eq!(f.atanh(), f);
+    }
+
+    #[test]
+    fn test_inverse() {
+        for f in [f256::from(1e-20), f256::from(-0.375), f256::from(0.75)] {
+            assert!(f.tanh().atanh().diff_within_n_bits(&f, 1));
+        }
+    }
+}
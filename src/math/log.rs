@@ -10,7 +10,7 @@
 use core::{cmp::Ordering, num::FpCategory};
 
 use super::{bkm::bkm_l, Float256, Float512};
-use crate::{exp, f256, norm_signif_exp, signif, BigUInt};
+use crate::{abs_bits, exp, f256, norm_signif_exp, signif, BigUInt};
 
 pub(crate) fn approx_ln(f: &Float512) -> Float512 {
     // f = m⋅2ᵉ
@@ -26,6 +26,29 @@ fn ln(x: &f256) -> Float512 {
     approx_ln(&Float512::from(x))
 }
 
+// Returns ln(1+x) for finite x > -1 and x != 0.
+pub(crate) fn approx_ln_1p(x: &f256) -> Float512 {
+    debug_assert!(x.is_finite() && !x.eq_zero() && x > &f256::NEG_ONE);
+    let f = Float512::from(x);
+    // x = m⋅2⁻ⁿ⋅2ᵉ with n = 236 and 0 < m⋅2⁻ⁿ < 2
+    match exp(&abs_bits(x)) {
+        ..=-121 => {
+            // |x| < 2⁻¹²⁰ => ln (1+x) ≈ x-½x²+⅓x³ (the remaining terms are
+            // below 2⁻³⁶⁰⋅|x|)
+            let f2 = f.square();
+            f - f2.mul_pow2(-1) + f2 * f / Float512::from(3)
+        }
+        -120..=492 => {
+            // 2⁻¹²⁰ <= |x| < 2⁴⁹³
+            approx_ln(&(f + Float512::ONE))
+        }
+        _ => {
+            // x >= 2⁴⁹³ => ln (1+x) ≈ ln x
+            approx_ln(&f)
+        }
+    }
+}
+
 impl f256 {
     /// Returns the logarithm of the number with respect to an arbitrary base.
     ///
@@ -110,34 +133,7 @@ impl f256 {
         if self < &Self::NEG_ONE || self.is_nan() {
             return Self::NAN;
         }
-        // x = m⋅2⁻ⁿ⋅2ᵉ with n = 236 and 0 < m⋅2⁻ⁿ < 2
-        let e = exp(&self.bits);
-        match e {
-            ..=-257 => {
-                // x < 2⁻²⁵⁶ => ln (1+x) ≈ x-½x² ≈ x
-                *self
-            }
-            // -256..=0 => {
-            -256..=492 => {
-                // 2⁻²⁵⁶ <= x < 2
-                let mut f = Float512::from(self);
-                f += &Float512::ONE;
-                Self::from(&approx_ln(&f))
-            }
-            // 1..=492 => {
-            //     // 2 <= x < 2⁴⁹³
-            //     let m = signif(&self.bits);
-            //     let mut m = Float512::new(m.hi.0, m.lo.0, 0_u128, 0_u128);
-            //     let mut t = Float512::ONE;
-            //     t.ishr(e as u32);
-            //     m += &t;
-            //     Self::from(&approx_ln(&m, e))
-            // }
-            _ => {
-                // x >= 2⁴⁹³ => ln (1+x) ≈ ln x
-                self.ln()
-            }
-        }
+        Self::from(&approx_ln_1p(self))
     }
 
     //noinspection DuplicatedCode
@@ -179,6 +175,41 @@ impl f256 {
             }
         }
     }
+
+    /// Returns log₂(1+n) more accurately than if the operations were
+    /// performed separately.
+    #[must_use]
+    pub fn log2_1p(&self) -> Self {
+        // log₂ (1+x) = ln (1+x) ⋅ log₂ e
+        scaled_ln_1p(self, &Float512::LOG2_E)
+    }
+
+    /// Returns log₁₀(1+n) more accurately than if the operations were
+    /// performed separately.
+    #[must_use]
+    pub fn log10_1p(&self) -> Self {
+        // log₁₀ (1+x) = ln (1+x) ⋅ log₁₀ e
+        scaled_ln_1p(self, &Float512::LOG10_E)
+    }
+}
+
+// Returns ln(1+x) ⋅ c for c > 0.
+fn scaled_ln_1p(x: &f256, c: &Float512) -> f256 {
+    // |x| = 0 or x = ∞ => ln (1+x) ⋅ c = x
+    if x.eq_zero() || x == &f256::INFINITY {
+        return *x;
+    }
+    // x = -1 => ln (1+x) ⋅ c = -∞
+    if x == &f256::NEG_ONE {
+        return f256::NEG_INFINITY;
+    }
+    // x < -1 or x is nan => ln (1+x) ⋅ c is nan
+    if x < &f256::NEG_ONE || x.is_nan() {
+        return f256::NAN;
+    }
+    let mut t = approx_ln_1p(x);
+    t *= c;
+    f256::from(&t)
 }
 
 //noinspection DuplicatedCode
@@ -655,6 +686,16 @@ mod ln_1p_tests {
         let x = f256::from(2_f64.powi(-237));
         assert_eq!(x.ln_1p(), x);
     }
+
+    #[test]
+    fn test_ln_1p_neg_values() {
+        assert_eq!(f256::from(-0.5).ln_1p(), -f256::from(&Float512::LN_2));
+        let x = f256::from(-0.75);
+        assert_eq!(x.ln_1p(), (f256::ONE + x).ln());
+        let x = -f256::EPSILON.div_pow2(300);
+        assert_eq!(x.ln_1p(), x);
+        assert_eq!(f256::MIN_GT_ZERO.neg().ln_1p(), f256::MIN_GT_ZERO.neg());
+    }
 }
 
 #[cfg(test)]
@@ -704,3 +745,103 @@ mod log10_tests {
         assert_eq!(f256::ONE.log10(), f256::ZERO);
     }
 }
+
+#[cfg(test)]
+mod log2_1p_tests {
+    use core::ops::Neg;
+
+    use super::*;
+
+    #[test]
+    fn test_undefined() {
+        assert!(f256::TWO.neg().log2_1p().is_nan());
+        assert!(f256::NEG_INFINITY.log2_1p().is_nan());
+        assert!(f256::NAN.log2_1p().is_nan());
+    }
+
+    #[test]
+    fn test_specials() {
+        assert_eq!(f256::NEG_ONE.log2_1p(), f256::NEG_INFINITY);
+        assert_eq!(f256::ZERO.log2_1p().to_bits(), f256::ZERO.to_bits());
+        assert_eq!(
+            f256::NEG_ZERO.log2_1p().to_bits(),
+            f256::NEG_ZERO.to_bits()
+        );
+        assert_eq!(f256::INFINITY.log2_1p(), f256::INFINITY);
+    }
+
+    #[test]
+    fn test_exact() {
+        assert_eq!(f256::from(-0.5).log2_1p(), f256::NEG_ONE);
+        assert_eq!(f256::from(-0.75).log2_1p(), -f256::TWO);
+        for k in 1..=236 {
+            let x = f256::power_of_two(k) - f256::ONE;
+            assert_eq!(x.log2_1p(), f256::from(k), "{k}");
+        }
+    }
+
+    #[test]
+    fn test_near_zero() {
+        assert_eq!(f256::MIN_GT_ZERO.log2_1p(), f256::MIN_GT_ZERO);
+        let log2_e = f256::from(&Float512::LOG2_E);
+        for f in [
+            f256::MIN_POSITIVE,
+            f256::EPSILON.div_pow2(100),
+            f256::EPSILON,
+        ] {
+            let r = f * log2_e;
+            assert!((f.log2_1p() - r).abs() <= r.ulp(), "{f:?}");
+            assert!((f.neg().log2_1p() + r).abs() <= r.ulp(), "{f:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod log10_1p_tests {
+    use core::ops::Neg;
+
+    use super::*;
+
+    #[test]
+    fn test_undefined() {
+        assert!(f256::TWO.neg().log10_1p().is_nan());
+        assert!(f256::NEG_INFINITY.log10_1p().is_nan());
+        assert!(f256::NAN.log10_1p().is_nan());
+    }
+
+    #[test]
+    fn test_specials() {
+        assert_eq!(f256::NEG_ONE.log10_1p(), f256::NEG_INFINITY);
+        assert_eq!(f256::ZERO.log10_1p().to_bits(), f256::ZERO.to_bits());
+        assert_eq!(
+            f256::NEG_ZERO.log10_1p().to_bits(),
+            f256::NEG_ZERO.to_bits()
+        );
+        assert_eq!(f256::INFINITY.log10_1p(), f256::INFINITY);
+    }
+
+    #[test]
+    fn test_exact() {
+        let mut p = f256::ONE;
+        for k in 1..=70 {
+            p *= f256::TEN;
+            let x = p - f256::ONE;
+            assert_eq!(x.log10_1p(), f256::from(k), "{k}");
+        }
+    }
+
+    #[test]
+    fn test_near_zero() {
+        assert_eq!(f256::MIN_GT_ZERO.log10_1p(), f256::ZERO);
+        let log10_e = f256::from(&Float512::LOG10_E);
+        for f in [
+            f256::MIN_POSITIVE,
+            f256::EPSILON.div_pow2(100),
+            f256::EPSILON,
+        ] {
+            let r = f * log10_e;
+            assert!((f.log10_1p() - r).abs() <= r.ulp(), "{f:?}");
+            assert!((f.neg().log10_1p() + r).abs() <= r.ulp(), "{f:?}");
+        }
+    }
+}
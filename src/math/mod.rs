@@ -11,13 +11,18 @@ mod big_float;
 mod bkm;
 mod cbrt;
 mod circular_fns;
+mod erf;
 mod exp;
 mod fp492;
+mod gamma;
+mod hyperbolic_fns;
 mod log;
 mod pow;
+mod rootn;
 pub(crate) mod sqrt;
 
-use big_float::{Float, Float256, Float512};
+use big_float::Float;
+pub(crate) use big_float::{Float256, Float512};
 use fp492::FP492;
 
 use super::{BigUInt, HiLo, Parity, U256, U512};
@@ -11,10 +11,11 @@ use core::num::FpCategory;
 
 use super::bkm::{bkm_e, bkm_l};
 use super::exp::approx_exp;
-use super::log::approx_ln;
+use super::log::{approx_ln, approx_ln_1p};
 use super::{BigUInt, Float512, HiLo, Parity};
 use crate::{
     abs_bits, exp, f256, norm_signif_exp, EMAX, EMIN, FRACTION_BITS,
+    SIGNIFICAND_BITS,
 };
 
 enum Lim {
@@ -144,6 +145,58 @@ fn powf(x: &f256, y: &f256) -> f256 {
     }
 }
 
+// Calculate |x|ⁿ by repeated squaring in Float512, for |n| > 1. The
+// relative error is bounded by |n|⋅2⁻⁵⁰⁸, so it is negligible even for
+// |n| close to 2¹²⁷.
+fn pown(x: &f256, n: i128) -> f256 {
+    debug_assert!(x.is_finite() && !x.eq_zero());
+    debug_assert!(n.unsigned_abs() > 1);
+    // Exponent limit beyond which the result is infinite or zero
+    const LIM: u32 = EMAX as u32 + SIGNIFICAND_BITS + 2;
+    let mut base = Float512::from(&x.abs());
+    if n < 0 {
+        base = base.recip();
+    }
+    let mut m = n.unsigned_abs();
+    let mut p = Float512::ONE;
+    loop {
+        if m & 1 == 1 {
+            p *= base;
+        }
+        m >>= 1;
+        if m == 0 {
+            break;
+        }
+        base = base.square();
+        // The highest bit of m is still to be processed, so p gets
+        // multiplied by base at least once more. As all factors are
+        // powers of |x|, their exponents have the same sign.
+        if base.exp().unsigned_abs() > LIM {
+            return [f256::INFINITY, f256::ZERO][(base.exp() < 0) as usize];
+        }
+    }
+    f256::from(&p)
+}
+
+// Compute (1+x)ⁿ
+#[inline(always)]
+#[allow(clippy::cast_possible_wrap)]
+fn compound(x: &f256, n: i64) -> f256 {
+    debug_assert!(x.is_finite() && !x.eq_zero() && x > &f256::NEG_ONE);
+    debug_assert!(n != 0 && n != 1);
+    // (1+x)ⁿ = eʷ with w = n⋅logₑ(1+x)
+    let w = Float512::from(&f256::from(n)) * approx_ln_1p(x);
+    // |w| > 181869 => eʷ > f256::MAX or eʷ < ½⋅f256::MIN_GT_ZERO
+    if w.abs() > Float512::from(181869) {
+        return [f256::INFINITY, f256::ZERO][(w.signum() < 0) as usize];
+    }
+    if w.exp() < -(SIGNIFICAND_BITS as i32) {
+        // for very small w, eʷ ≅ 1+w+½w²
+        return f256::from(&(Float512::ONE + w + w.square().mul_pow2(-1)));
+    }
+    f256::from(&approx_exp(&w))
+}
+
 impl f256 {
     /// Raises a number to an integer power.
     #[must_use]
@@ -253,6 +306,108 @@ impl f256 {
         // self is finite and != 0, exp is finite and ∉ [-1, 0, 1]
         powf(self, exp)
     }
+
+    /// Raises a number to an integer power, which may be given as any
+    /// integer type convertible to `i128`.
+    ///
+    /// In contrast to [`f256::powi`], zero results and infinite results keep
+    /// the sign of `self` for odd `n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// let n = 1_i64 << 40;
+    /// let f = f256::ONE + f256::EPSILON;
+    /// assert_eq!(f.pown(n), f256::ONE + f256::EPSILON * f256::from(n));
+    /// assert_eq!(f256::TWO.pown(-1000_i128), f256::from(2_f64.powi(-1000)));
+    /// assert_eq!(f256::NEG_ZERO.pown(-3), f256::NEG_INFINITY);
+    /// ```
+    #[must_use]
+    pub fn pown<N: Into<i128>>(&self, n: N) -> Self {
+        let n: i128 = n.into();
+        // x⁰ = 1 for any x, incl. NaN
+        // 1ⁿ = 1 for any n
+        if n == 0 || *self == Self::ONE {
+            return Self::ONE;
+        }
+        // x¹ = x for any x, incl. NaN
+        if n == 1 {
+            return *self;
+        }
+        // x⁻¹ = 1/x for any x, incl. NaN (note: 1/NaN = NaN)
+        if n == -1 {
+            return self.recip();
+        }
+        // x < 0 => x = -1⋅|x| => xⁿ = (-1)ⁿ⋅|x|ⁿ
+        let s = (self.sign() * u32::from(n & 1 != 0)) as usize;
+        if self.is_special() {
+            // NaNⁿ = NaN for n != 0
+            if self.is_nan() {
+                return Self::NAN;
+            }
+            // (±0)ⁿ = ±0 for n > 0 and (±0)ⁿ = ±∞ for n < 0
+            // (±∞)ⁿ = ±∞ for n > 0 and (±∞)ⁿ = ±0 for n < 0
+            // where the sign is negative only for negative self and odd n
+            let res = match (self.eq_zero(), n < 0) {
+                (true, false) | (false, true) => Self::ZERO,
+                _ => Self::INFINITY,
+            };
+            return [res, -res][s];
+        }
+        // self is finite and != 0, n ∉ [-1…1]
+        let res = pown(self, n);
+        [res, -res][s]
+    }
+
+    /// Returns (1+self)ⁿ, i.e. the growth factor of compounding the rate
+    /// `self` over n periods, computed more accurately than if the
+    /// operations were performed separately.
+    ///
+    /// * `compound(x, 0)` is 1 for x >= -1 and for NaN
+    /// * `compound(x, n)` is NaN for x < -1
+    /// * `compound(-1, n)` is +0 for n > 0 and +∞ for n < 0
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// let f = f256::from(0.25);
+    /// assert_eq!(f.compound(3), f256::from(1.953125));
+    /// assert_eq!(f256::ONE.compound(-2), f256::from(0.25));
+    /// assert_eq!(f256::NEG_ONE.compound(-1), f256::INFINITY);
+    /// ```
+    #[must_use]
+    pub fn compound(&self, n: i64) -> Self {
+        // x < -1 => (1+x)ⁿ is NaN
+        if self < &Self::NEG_ONE {
+            return Self::NAN;
+        }
+        // (1+x)⁰ = 1 for x >= -1, incl. NaN
+        // (1+0)ⁿ = 1 for any n
+        if n == 0 || self.eq_zero() {
+            return Self::ONE;
+        }
+        // (1+x)¹ = 1+x for any x, incl. NaN
+        if n == 1 {
+            return *self + Self::ONE;
+        }
+        if self.is_nan() {
+            return Self::NAN;
+        }
+        // (1+x)ⁿ = 0 for x = -1 and n > 0
+        // (1+x)ⁿ = ∞ for x = -1 and n < 0
+        if self == &Self::NEG_ONE {
+            return [Self::ZERO, Self::INFINITY][(n < 0) as usize];
+        }
+        // (1+x)ⁿ = ∞ for x = ∞ and n > 0
+        // (1+x)ⁿ = 0 for x = ∞ and n < 0
+        if self.is_infinite() {
+            return [Self::INFINITY, Self::ZERO][(n < 0) as usize];
+        }
+        // self is finite, != 0 and > -1, n ∉ [0, 1]
+        compound(self, n)
+    }
 }
 
 #[cfg(test)]
@@ -668,3 +823,151 @@ mod powf_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod pown_tests {
+    use super::*;
+    use crate::ONE_HALF;
+
+    #[test]
+    fn test_specials() {
+        for f in [f256::NAN, f256::ZERO, f256::NEG_INFINITY, f256::MIN] {
+            assert_eq!(f.pown(0), f256::ONE);
+            assert_eq!(f.pown(0_i128), f256::ONE);
+        }
+        for n in [i128::MIN, -3, -1, 1, 2, i128::MAX] {
+            assert!(f256::NAN.pown(n).is_nan());
+            assert_eq!(f256::ONE.pown(n), f256::ONE);
+        }
+        let zeros = [f256::ZERO, f256::NEG_ZERO];
+        let infs = [f256::INFINITY, f256::NEG_INFINITY];
+        for n in [1_i128, 2, 3, 4, i128::MAX] {
+            let odd = usize::from(n & 1 == 1);
+            for s in 0..=1 {
+                let t = s * odd;
+                assert_eq!(zeros[s].pown(n).to_bits(), zeros[t].to_bits());
+                assert_eq!(zeros[s].pown(-n), infs[t]);
+                assert_eq!(infs[s].pown(n), infs[t]);
+                assert_eq!(infs[s].pown(-n).to_bits(), zeros[t].to_bits());
+            }
+        }
+        assert_eq!(f256::NEG_ZERO.pown(i128::MIN), f256::INFINITY);
+        assert_eq!(f256::NEG_INFINITY.pown(i128::MIN).to_bits(), (0, 0));
+    }
+
+    #[test]
+    fn test_int_types() {
+        let f = f256::from(-3);
+        let p = f256::from(-243);
+        assert_eq!(f.pown(5_i8), p);
+        assert_eq!(f.pown(5_u16), p);
+        assert_eq!(f.pown(5_i32), p);
+        assert_eq!(f.pown(5_u64), p);
+        assert_eq!(f.pown(5_i64), p);
+        assert_eq!(f.pown(5_i128), p);
+        assert_eq!(f.pown(-5_i64), p.recip());
+    }
+
+    #[test]
+    fn test_exact() {
+        for n in (-262378_i64..=262143).step_by(97) {
+            let p = f256::power_of_two(n as i32);
+            assert_eq!(f256::TWO.pown(n), p, "{n}");
+            assert_eq!(ONE_HALF.pown(-n), p, "{n}");
+        }
+        assert_eq!((-f256::TWO).pown(-262377), -f256::power_of_two(-262377));
+        assert_eq!(f256::from(7).pown(90), f256::from(7).powi(90));
+    }
+
+    #[test]
+    fn test_huge_n() {
+        let n = 1_i64 << 40;
+        let d = f256::EPSILON * f256::from(n);
+        let f = f256::ONE + f256::EPSILON;
+        assert_eq!(f.pown(n), f256::ONE + d);
+        let f = f256::ONE - f256::EPSILON;
+        assert_eq!(f.pown(n), f256::ONE - d);
+        assert_eq!((-f).pown(n + 1), f256::EPSILON - f256::ONE + d);
+        assert_eq!(f256::TWO.pown(i128::MAX), f256::INFINITY);
+        assert_eq!((-f256::TWO).pown(i128::MAX), f256::NEG_INFINITY);
+        assert_eq!((-f256::TWO).pown(i128::MIN).to_bits(), (0, 0));
+        assert_eq!(ONE_HALF.pown(i64::MIN), f256::INFINITY);
+    }
+
+    #[test]
+    fn test_overflow_underflow() {
+        assert_eq!(f256::TWO.pown(262144), f256::INFINITY);
+        assert_eq!((-f256::TWO).pown(262145), f256::NEG_INFINITY);
+        assert_eq!(f256::TWO.pown(-262379).to_bits(), (0, 0));
+        assert_eq!(
+            (-f256::TWO).pown(-262379).to_bits(),
+            f256::NEG_ZERO.to_bits()
+        );
+        assert_eq!(f256::MAX.pown(2), f256::INFINITY);
+        assert_eq!(f256::MIN_GT_ZERO.pown(i128::MAX).to_bits(), (0, 0));
+        assert_eq!(f256::MIN_GT_ZERO.pown(-2), f256::INFINITY);
+        assert_eq!(f256::MIN_POSITIVE.pown(-1), f256::MIN_POSITIVE.recip());
+    }
+}
+
+#[cfg(test)]
+mod compound_tests {
+    use super::*;
+    use crate::ONE_HALF;
+
+    #[test]
+    fn test_specials() {
+        for n in [i64::MIN, -7, -1, 1, 2, i64::MAX] {
+            assert!(f256::NAN.compound(n).is_nan());
+            assert!(f256::NEG_INFINITY.compound(n).is_nan());
+            assert!((-f256::TWO).compound(n).is_nan());
+            assert_eq!(f256::ZERO.compound(n), f256::ONE);
+            assert_eq!(f256::NEG_ZERO.compound(n), f256::ONE);
+        }
+        for f in [f256::NAN, f256::NEG_ONE, f256::INFINITY, f256::MAX] {
+            assert_eq!(f.compound(0), f256::ONE);
+        }
+        assert!((-f256::TWO).compound(0).is_nan());
+        for n in [1, 2, 3, i64::MAX] {
+            assert_eq!(f256::NEG_ONE.compound(n).to_bits(), (0, 0));
+            assert_eq!(f256::INFINITY.compound(n), f256::INFINITY);
+            assert_eq!(f256::NEG_ONE.compound(-n), f256::INFINITY);
+            assert_eq!(f256::INFINITY.compound(-n).to_bits(), (0, 0));
+        }
+        assert_eq!(f256::NEG_ONE.compound(i64::MIN), f256::INFINITY);
+    }
+
+    #[test]
+    fn test_exact() {
+        for n in -300_i64..=300 {
+            let p = f256::power_of_two(n as i32);
+            assert_eq!(f256::ONE.compound(n), p, "{n}");
+            assert_eq!((-ONE_HALF).compound(n), p.recip(), "{n}");
+        }
+        assert_eq!(ONE_HALF.compound(2), f256::from(2.25));
+        let f = f256::from(0.25).compound(-2);
+        assert_eq!(f, f256::from(16) / f256::from(25));
+        let f = f256::EPSILON;
+        assert_eq!(f.compound(1), f256::ONE + f);
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(f256::ONE.compound(262144), f256::INFINITY);
+        assert_eq!(f256::ONE.compound(-262380), f256::ZERO);
+        assert_eq!(f256::ONE.compound(-262378), f256::MIN_GT_ZERO);
+        assert_eq!(ONE_HALF.compound(i64::MAX), f256::INFINITY);
+        assert_eq!(ONE_HALF.compound(i64::MIN), f256::ZERO);
+    }
+
+    #[test]
+    fn test_near_zero() {
+        // (1+x)ⁿ ≈ 1 + n⋅x for tiny x
+        let x = f256::EPSILON.div_pow2(20);
+        assert_eq!(x.compound(1 << 20), f256::ONE + f256::EPSILON);
+        assert_eq!((-x).compound(1 << 20), f256::ONE - f256::EPSILON);
+        let x = f256::MIN_GT_ZERO;
+        assert_eq!(x.compound(i64::MAX), f256::ONE);
+        assert_eq!(x.compound(i64::MIN), f256::ONE);
+    }
+}
@@ -0,0 +1,162 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use super::exp::approx_exp;
+use super::log::approx_ln;
+use super::Float512;
+use crate::{abs_bits, f256, SIGNIFICAND_BITS};
+
+impl f256 {
+    /// Returns the n-th root of `self`, i.e. x^(1/n).
+    ///
+    /// * `rootn(x, 0)` is NaN
+    /// * `rootn(x, n)` is NaN for x < 0 and even n
+    /// * `rootn(±0, n)` is ±0 for n > 0 and ±∞ for n < 0
+    /// * `rootn(±∞, n)` is ±∞ for n > 0 and ±0 for n < 0
+    ///
+    /// where the sign of a zero or infinite result is negative only for
+    /// negative `self` and odd n.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::f256;
+    /// assert_eq!(f256::from(-3125).rootn(5), f256::from(-5));
+    /// assert_eq!(f256::from(0.0625).rootn(-4), f256::TWO);
+    /// assert!(f256::from(-16).rootn(4).is_nan());
+    /// assert_eq!(f256::NEG_ZERO.rootn(-3), f256::NEG_INFINITY);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn rootn(&self, n: i64) -> Self {
+        if n == 0 || self.is_nan() {
+            return Self::NAN;
+        }
+        let n_is_odd = n & 1 == 1;
+        // x < 0 and n even => ⁿ√x is NaN
+        if self.is_sign_negative() && !self.eq_zero() && !n_is_odd {
+            return Self::NAN;
+        }
+        // x < 0 and n odd => ⁿ√x = -ⁿ√|x|
+        let s = (self.sign() * n_is_odd as u32) as usize;
+        if self.is_special() {
+            // self is ±0 or ±∞
+            let res = match (self.eq_zero(), n < 0) {
+                (true, false) | (false, true) => Self::ZERO,
+                _ => Self::INFINITY,
+            };
+            return [res, -res][s];
+        }
+        match n {
+            1 => return *self,
+            -1 => return self.recip(),
+            2 => return self.sqrt(),
+            -2 => return self.rsqrt(),
+            _ => {}
+        }
+        if abs_bits(self) == Self::ONE.bits {
+            return *self;
+        }
+        // self is finite and ∉ {-1, 0, 1}, |n| > 2
+        // ⁿ√|x| = eʷ with w = logₑ(|x|)/n
+        let w = approx_ln(&Float512::from(&self.abs()))
+            / Float512::from(&Self::from(n));
+        let r = if w.exp() < -(SIGNIFICAND_BITS as i32) {
+            // for very small w, eʷ ≅ 1+w+½w²
+            Float512::ONE + w + w.square().mul_pow2(-1)
+        } else {
+            approx_exp(&w)
+        };
+        let res = Self::from(&r);
+        [res, -res][s]
+    }
+}
+
+#[cfg(test)]
+mod rootn_tests {
+    use super::*;
+
+    #[test]
+    fn test_nan() {
+        for f in [f256::NAN, f256::ONE, f256::ZERO, f256::INFINITY] {
+            assert!(f.rootn(0).is_nan());
+        }
+        for n in [i64::MIN, -4, -1, 1, 2, 7, i64::MAX] {
+            assert!(f256::NAN.rootn(n).is_nan());
+        }
+        for n in [i64::MIN, -4, -2, 2, 4, 1 << 40] {
+            assert!(f256::NEG_ONE.rootn(n).is_nan());
+            assert!(f256::NEG_INFINITY.rootn(n).is_nan());
+            assert!(f256::MIN.rootn(n).is_nan());
+            assert!((-f256::MIN_GT_ZERO).rootn(n).is_nan());
+        }
+    }
+
+    #[test]
+    fn test_zero_inf() {
+        let zeros = [f256::ZERO, f256::NEG_ZERO];
+        let infs = [f256::INFINITY, f256::NEG_INFINITY];
+        for n in [1_i64, 2, 3, 4, i64::MAX] {
+            let odd = usize::from(n & 1 == 1);
+            for s in 0..=1 {
+                let (z, i) = (zeros[s], infs[s]);
+                let t = s * odd;
+                assert_eq!(z.rootn(n).to_bits(), zeros[t].to_bits());
+                assert_eq!(z.rootn(-n), infs[t]);
+                if s == 0 || odd == 1 {
+                    assert_eq!(i.rootn(n), infs[t]);
+                    assert_eq!(i.rootn(-n).to_bits(), zeros[t].to_bits());
+                }
+            }
+        }
+        assert_eq!(f256::NEG_ZERO.rootn(i64::MIN), f256::INFINITY);
+    }
+
+    #[test]
+    fn test_one() {
+        for n in [i64::MIN, -3, -1, 1, 2, 5, i64::MAX] {
+            assert_eq!(f256::ONE.rootn(n), f256::ONE);
+        }
+        for n in [i64::MIN + 1, -3, -1, 1, 5, i64::MAX] {
+            assert_eq!(f256::NEG_ONE.rootn(n), f256::NEG_ONE);
+        }
+    }
+
+    #[test]
+    fn test_exact() {
+        for k in 1_i32..=60 {
+            let n = i64::from(k);
+            for b in [2_i64, 3, 7, 10] {
+                let x = f256::from(b);
+                let p = x.powi(k);
+                if p.ulp() > f256::ONE {
+                    break;
+                }
+                assert_eq!(p.rootn(n), x, "{b}^{n}");
+                assert_eq!(p.rootn(-n), x.recip(), "{b}^{n}");
+                if n & 1 == 1 {
+                    assert_eq!((-p).rootn(n), -x, "{b}^{n}");
+                }
+            }
+        }
+        let f = f256::power_of_two(-262377);
+        assert_eq!(f.rootn(3), f256::power_of_two(-87459));
+        assert_eq!(f.rootn(-262377), f256::TWO);
+    }
+
+    #[test]
+    fn test_near_one() {
+        let f = f256::ONE + f256::EPSILON;
+        assert_eq!(f.rootn(3), f256::ONE);
+        assert_eq!(f.rootn(i64::MAX), f256::ONE);
+        let f = f256::ONE - f256::EPSILON;
+        assert_eq!(f.rootn(-3), f256::ONE);
+        assert_eq!(f.rootn(i64::MIN), f256::ONE);
+    }
+}
@@ -7,18 +7,21 @@
 // $Source$
 // $Revision$
 
+use super::Float512;
 use crate::big_uint::{UInt, U128};
 use crate::{
-    abs_bits, exp_bits, f256, fraction, norm_signif_exp, BigUInt,
-    BinEncSpecial, HiLo, EMIN, EXP_BIAS, EXP_BITS, EXP_MAX, FRACTION_BITS,
-    HI_FRACTION_BIAS, HI_FRACTION_BITS, SIGNIFICAND_BITS, U256, U512,
+    abs_bits, exp_bits, f256, fraction, norm_signif_exp, split_f256_enc,
+    BigUInt, BinEncSpecial, HiLo, EMIN, EXP_BIAS, EXP_BITS, EXP_MAX,
+    FRACTION_BITS, HI_FRACTION_BIAS, HI_FRACTION_BITS, SIGNIFICAND_BITS,
+    U256, U512,
 };
+use crate::{ExceptionFlags, RoundingMode};
 use core::ops::{Add, Shr};
 
 #[allow(clippy::integer_division)]
 #[allow(clippy::cast_possible_wrap)]
 #[allow(clippy::cast_sign_loss)]
-pub(crate) fn square_root(signif: &U256, exp: i32) -> (i32, U256) {
+pub(crate) fn square_root(signif: &U256, exp: i32) -> (i32, U256, bool) {
     debug_assert!(signif.hi.0.leading_zeros() <= EXP_BITS);
     debug_assert!(signif.hi.0.leading_zeros() >= 2);
     let n = signif.msb();
@@ -102,7 +105,7 @@ pub(crate) fn square_root(signif: &U256, exp: i32) -> (i32, U256) {
             };
         }
     }
-    (p, q >> (n - FRACTION_BITS))
+    (p, q >> (n - FRACTION_BITS), r.is_zero())
 }
 
 impl f256 {
@@ -120,29 +123,171 @@ impl f256 {
     /// assert_eq!(f256::NEG_ZERO.sqrt(), f256::NEG_ZERO);
     /// ```
     #[must_use]
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        self.sqrt_rounded(RoundingMode::NearestTiesEven)
+    }
+
+    /// Returns the square root of `self`, rounded according to the rounding
+    /// mode `rnd`.
+    ///
+    /// Returns NaN if `self` is a negative number other than `-0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, RoundingMode};
+    /// let f = f256::TWO;
+    /// let lo = f.sqrt_rounded(RoundingMode::TowardNegative);
+    /// let hi = f.sqrt_rounded(RoundingMode::TowardPositive);
+    /// assert_eq!(hi, lo.next_up());
+    /// assert!(lo * lo < f && hi * hi > f);
+    /// let f = f256::from(1822500);
+    /// assert_eq!(f.sqrt_rounded(RoundingMode::TowardZero), f256::from(1350));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn sqrt_rounded(self, rnd: RoundingMode) -> Self {
+        self.sqrt_with_flags(rnd).0
+    }
+
+    /// Returns the square root of `self`, rounded according to the rounding
+    /// mode `rnd`, together with the exception flags raised by the
+    /// operation.
+    ///
+    /// Returns NaN and signals an invalid operation if `self` is a negative
+    /// number other than `-0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ::f256::{f256, ExceptionFlags, RoundingMode};
+    /// let rnd = RoundingMode::NearestTiesEven;
+    /// let (z, flags) = f256::from(1822500).sqrt_with_flags(rnd);
+    /// assert_eq!(z, f256::from(1350));
+    /// assert!(flags.is_empty());
+    /// let (z, flags) = f256::TWO.sqrt_with_flags(rnd);
+    /// assert_eq!(flags, ExceptionFlags::INEXACT);
+    /// let (z, flags) = f256::NEG_ONE.sqrt_with_flags(rnd);
+    /// assert!(z.is_nan());
+    /// assert_eq!(flags, ExceptionFlags::INVALID);
+    /// ```
+    #[must_use]
     #[allow(clippy::cast_possible_wrap)]
     #[allow(clippy::cast_sign_loss)]
-    pub fn sqrt(self) -> Self {
+    pub fn sqrt_with_flags(
+        self,
+        rnd: RoundingMode,
+    ) -> (Self, ExceptionFlags) {
         let bin_enc = self.bits;
         // Check whether `self` is negative or ∈ {-0, +0, +∞, NAN}.
         if bin_enc > Self::NEG_ZERO.bits {
-            // `self` < 0
-            return Self::NAN;
+            // `self` < 0 or a NaN with the sign bit set.
+            return if self.is_nan() {
+                (Self::NAN, ExceptionFlags::NONE)
+            } else {
+                (Self::NAN, ExceptionFlags::INVALID)
+            };
         }
         if bin_enc.is_special() {
             // `self` either not a number, infinite or equal to zero.
-            return self;
+            return (self, ExceptionFlags::NONE);
         }
 
         // `self` is (sub-)normal and positive
         let (signif, exp) = norm_signif_exp(&bin_enc);
-        let (p, mut q) = square_root(&signif, exp);
+        let (mut p, mut q, is_exact) = square_root(&signif, exp);
         // Final reconstruction and rounding.
         // The sqare root of a floating point number can't be an exact
         // midpoint between two consecutive floating point numbers, so there
         // is no need to care about ties.
-        q = q + (q.lo.0 & 1_u128);
-        Self::new(0, p, q >> 1)
+        let rnd_bits = ((q.lo.0 & 1) as u32) << 1 | !is_exact as u32;
+        let mut flags = ExceptionFlags::NONE;
+        // The square root of a finite positive `f256` is neither tiny nor
+        // huge.
+        flags.set_inexact(rnd_bits, false);
+        q >>= 1;
+        if rnd.round_up(0, q.lo.is_odd(), rnd_bits, 0b10) {
+            q.incr();
+            // Rounding up may overflow the significand.
+            if (q.hi.0 >> HI_FRACTION_BITS) > 1 {
+                q >>= 1;
+                p += 1;
+            }
+        }
+        (Self::new(0, p, q), flags)
+    }
+
+    /// Returns the reciprocal of the square root of `self`, i.e. 1/√x.
+    ///
+    /// Returns NaN if `self` is a negative number other than `-0.0`, ±∞ if
+    /// `self` is ±0 and +0 if `self` is +∞.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f256::f256;
+    /// let f = f256::from(0.0625);
+    /// assert_eq!(f.rsqrt(), f256::from(4));
+    /// assert!(f256::NEG_ONE.rsqrt().is_nan());
+    /// assert_eq!(f256::NEG_ZERO.rsqrt(), f256::NEG_INFINITY);
+    /// ```
+    #[allow(clippy::cast_possible_wrap)]
+    #[allow(clippy::cast_sign_loss)]
+    #[must_use]
+    pub fn rsqrt(self) -> Self {
+        let bin_enc = self.bits;
+        // Check whether `self` is negative or ∈ {-0, +0, +∞, NAN}.
+        if bin_enc > Self::NEG_ZERO.bits {
+            // `self` < 0 or a NaN with the sign bit set.
+            return Self::NAN;
+        }
+        if bin_enc.is_special() {
+            // `self` either not a number, infinite or equal to zero.
+            return if self.is_nan() {
+                Self::NAN
+            } else if self.eq_zero() {
+                [Self::INFINITY, Self::NEG_INFINITY][self.sign() as usize]
+            } else {
+                Self::ZERO
+            };
+        }
+        // `self` is (sub-)normal and positive
+        // The approximation a of 1/√x is computed with more than twice the
+        // precision of an f256, but rounded twice, so the exact result may
+        // lie on the other side of the midpoint m between the two f256
+        // values enclosing a. Therefore the side is determined exactly:
+        // 1/√x > m <=> m²⋅x < 1.
+        let a = Float512::from(&self).sqrt().recip();
+        let r = Self::from(&a);
+        let (lo, hi) = if a < Float512::from(&r) {
+            (r.next_down(), r)
+        } else {
+            (r, r.next_up())
+        };
+        // m = c⋅2ᵗ
+        let (_, t_lo, c_lo) = split_f256_enc(&lo);
+        let (_, t_hi, c_hi) = split_f256_enc(&hi);
+        let c = c_lo + (c_hi << (t_hi - t_lo) as u32);
+        let t = t_lo - 1;
+        // x = c_x⋅2ᵗˣ => m²⋅x = c²⋅c_x⋅2ᵏ with k = 2⋅t + tₓ
+        let (_, t_x, c_x) = split_f256_enc(&self);
+        let (sq_lo, sq_hi) = c.widening_mul(&c);
+        let (p_lo, p_hi) = U512 {
+            hi: sq_hi,
+            lo: sq_lo,
+        }
+        .widening_mul(&U512 {
+            hi: U256::ZERO,
+            lo: c_x,
+        });
+        let msb = if p_hi.is_zero() {
+            p_lo.msb() as i32
+        } else {
+            512 + p_hi.msb() as i32
+        };
+        // m²⋅x < 1 <=> msb + k < 0
+        [lo, hi][(msb + 2 * t + t_x < 0) as usize]
     }
 }
 
@@ -314,4 +459,36 @@ mod sqrt_tests {
         assert_eq!(r * r, f);
         assert_eq!(f.sqrt(), r);
     }
+
+    #[test]
+    fn test_rsqrt_specials() {
+        assert!(f256::NAN.rsqrt().is_nan());
+        assert!(f256::NEG_ONE.rsqrt().is_nan());
+        assert!(f256::NEG_INFINITY.rsqrt().is_nan());
+        assert!((-f256::MIN_GT_ZERO).rsqrt().is_nan());
+        assert_eq!(f256::ZERO.rsqrt(), f256::INFINITY);
+        assert_eq!(f256::NEG_ZERO.rsqrt(), f256::NEG_INFINITY);
+        assert_eq!(f256::INFINITY.rsqrt(), f256::ZERO);
+        assert!(f256::INFINITY.rsqrt().is_sign_positive());
+    }
+
+    #[test]
+    fn test_rsqrt_powers_of_four() {
+        for e in [-131036, -1000, -2, 0, 2, 1000, 131070] {
+            let f = f256::power_of_two(e);
+            assert_eq!(f.rsqrt(), f256::power_of_two(-e / 2));
+        }
+        assert_eq!(f256::MIN_GT_ZERO.rsqrt(), f256::power_of_two(131189));
+    }
+
+    #[test]
+    fn test_rsqrt_extremes() {
+        // MAX = 2²⁶²¹⁴⁴⋅(1-2⁻²³⁷)
+        let r = f256::MAX.rsqrt();
+        assert_eq!(r, f256::power_of_two(-131072));
+        let f = f256::ONE.next_up();
+        assert_eq!(f.rsqrt(), f256::ONE.next_down());
+        let f = f256::ONE.next_down();
+        assert_eq!(f.rsqrt(), f256::ONE);
+    }
 }
@@ -7,11 +7,14 @@
 // $Source$
 // $Revision$
 
-use core::{ops::Add, str::FromStr};
+use core::{num::FpCategory, ops::Add, str::FromStr};
 
-use num_traits::{Num, One, Zero};
+use num_traits::{
+    Bounded, Float, FloatConst, FromPrimitive, Inv, MulAdd, MulAddAssign,
+    Num, One, Pow, Signed, ToPrimitive, Zero,
+};
 
-use crate::f256;
+use crate::{consts, f256, ParseF256Error};
 
 impl Zero for f256
 where
@@ -73,12 +76,19 @@ impl Num for f256 {
         str: &str,
         radix: u32,
     ) -> Result<Self, Self::FromStrRadixErr> {
-        if radix != 10 {
-            // The internals of ParseFloatError are not public.
-            // The following hack is used to return ParseFloatError::Invalid.
-            return Err(f64::from_str("_").unwrap_err());
+        match radix {
+            10 => Self::from_str(str),
+            // Hexadecimal significand without prefix, optionally followed
+            // by a binary exponent, e.g. "1.8p+3".
+            16 => Self::from_hex_str(str, false)
+                .map_err(ParseF256Error::to_parse_float_error),
+            _ => {
+                // The internals of ParseFloatError are not public.
+                // The following hack is used to return
+                // ParseFloatError::Invalid.
+                Err(f64::from_str("_").unwrap_err())
+            }
         }
-        Self::from_str(str)
     }
 }
 
@@ -93,9 +103,788 @@ mod from_str_radix_tests {
         assert_eq!(f.as_sign_exp_signif(), (1, -1, (0, 35)));
     }
 
+    #[test]
+    fn test_from_str_radix_16() {
+        let f = f256::from_str_radix("-1.8p+3", 16).unwrap();
+        assert_eq!(f, f256::from(-12));
+        let f = f256::from_str_radix("5.4", 16).unwrap();
+        assert_eq!(f, f256::from(5.25));
+        assert!(f256::from_str_radix("0x1p0", 16).is_err());
+    }
+
     #[test]
     fn test_err_invalid_radix() {
-        let res = f256::from_str_radix("5.4", 16);
+        let res = f256::from_str_radix("5.4", 8);
         assert!(res.is_err());
     }
 }
+
+impl Bounded for f256 {
+    /// Returns the smallest finite number, Self::MIN.
+    #[inline(always)]
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    /// Returns the largest finite number, Self::MAX.
+    #[inline(always)]
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
+#[cfg(test)]
+mod bounded_tests {
+    use super::*;
+
+    #[test]
+    fn test_bounded() {
+        assert_eq!(<f256 as Bounded>::min_value(), f256::MIN);
+        assert_eq!(<f256 as Bounded>::max_value(), f256::MAX);
+    }
+}
+
+impl Signed for f256 {
+    #[inline(always)]
+    fn abs(&self) -> Self {
+        Self::abs(self)
+    }
+
+    /// Returns the positive difference of `self` and `other`, i.e. zero if
+    /// `self` <= `other`, otherwise `self` - `other`.
+    #[inline]
+    fn abs_sub(&self, other: &Self) -> Self {
+        if *self <= *other {
+            Self::ZERO
+        } else {
+            *self - *other
+        }
+    }
+
+    #[inline(always)]
+    fn signum(&self) -> Self {
+        Self::signum(*self)
+    }
+
+    /// Returns true if `self` is positive, including +0 and +∞.
+    #[inline(always)]
+    fn is_positive(&self) -> bool {
+        self.is_sign_positive()
+    }
+
+    /// Returns true if `self` is negative, including -0 and -∞.
+    #[inline(always)]
+    fn is_negative(&self) -> bool {
+        self.is_sign_negative()
+    }
+}
+
+#[cfg(test)]
+mod signed_tests {
+    use super::*;
+
+    #[test]
+    fn test_signed() {
+        let x = f256::from(-7);
+        assert_eq!(Signed::abs(&x), f256::from(7));
+        assert_eq!(Signed::signum(&x), f256::NEG_ONE);
+        assert!(Signed::is_negative(&x));
+        assert!(!Signed::is_positive(&x));
+        assert_eq!(Signed::abs_sub(&x, &f256::ONE), f256::ZERO);
+        assert_eq!(Signed::abs_sub(&f256::ONE, &x), f256::from(8));
+    }
+}
+
+impl ToPrimitive for f256 {
+    /// Converts `self` to an i64, truncating the fractional part, or returns
+    /// None if the truncated value is out of the range of i64 or `self` is
+    /// NaN.
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(&self.trunc()).ok()
+    }
+
+    /// Converts `self` to an u64, truncating the fractional part, or returns
+    /// None if the truncated value is out of the range of u64 or `self` is
+    /// NaN.
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(&self.trunc()).ok()
+    }
+
+    /// Converts `self` to an i128, truncating the fractional part, or
+    /// returns None if the truncated value is out of the range of i128 or
+    /// `self` is NaN.
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(&self.trunc()).ok()
+    }
+
+    /// Converts `self` to an u128, truncating the fractional part, or
+    /// returns None if the truncated value is out of the range of u128 or
+    /// `self` is NaN.
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(&self.trunc()).ok()
+    }
+
+    /// Converts `self` to the nearest f32.
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(Self::to_f32(self))
+    }
+
+    /// Converts `self` to the nearest f64.
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(Self::to_f64(self))
+    }
+}
+
+#[cfg(test)]
+mod to_primitive_tests {
+    use super::*;
+
+    #[test]
+    fn test_to_int() {
+        let x = f256::from(-17.75);
+        assert_eq!(x.to_i64(), Some(-17));
+        assert_eq!(x.to_i8(), Some(-17));
+        assert_eq!(x.to_u64(), None);
+        assert_eq!((-f256::ONE.div2()).to_u32(), Some(0));
+        assert_eq!(f256::from(u128::MAX).to_u128(), Some(u128::MAX));
+        assert_eq!(f256::from(u128::MAX).to_i128(), None);
+        assert_eq!(f256::from(i128::MIN).to_i128(), Some(i128::MIN));
+        assert_eq!(f256::NAN.to_i32(), None);
+        assert_eq!(f256::INFINITY.to_u8(), None);
+    }
+
+    #[test]
+    fn test_to_float() {
+        assert_eq!(ToPrimitive::to_f64(&f256::from(0.1)), Some(0.1_f64));
+        assert_eq!(ToPrimitive::to_f32(&f256::MAX), Some(f32::INFINITY));
+        assert!(ToPrimitive::to_f64(&f256::NAN).unwrap().is_nan());
+    }
+}
+
+impl FromPrimitive for f256 {
+    #[inline(always)]
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    #[inline(always)]
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    #[inline(always)]
+    fn from_i128(n: i128) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    #[inline(always)]
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    #[inline(always)]
+    fn from_f32(n: f32) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    #[inline(always)]
+    fn from_f64(n: f64) -> Option<Self> {
+        Some(Self::from(n))
+    }
+}
+
+#[cfg(test)]
+mod from_primitive_tests {
+    use super::*;
+
+    #[test]
+    fn test_from_primitive() {
+        assert_eq!(f256::from_i8(-5), Some(f256::from(-5)));
+        assert_eq!(f256::from_u16(u16::MAX), Some(f256::from(u16::MAX)));
+        assert_eq!(
+            <f256 as FromPrimitive>::from_u128(u128::MAX),
+            Some(f256::from(u128::MAX))
+        );
+        assert_eq!(f256::from_f32(0.1_f32), Some(f256::from(0.1_f32)));
+        assert!(f256::from_f64(f64::NAN).unwrap().is_nan());
+    }
+}
+
+impl num_traits::NumCast for f256 {
+    /// Converts `n` to f256.
+    ///
+    /// Integers are converted exactly if they fit into an i128 or an u128.
+    /// Other types are converted via `f64`, because `ToPrimitive` does not
+    /// provide a lossless conversion for them.
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        let f = n.to_f64()?;
+        // Integers beyond 2⁵³ may not be representable as f64, but an
+        // integral f64 is exactly representable as i128 or u128, if in
+        // range.
+        if f.is_finite() && f.trunc() == f && f.abs() >= 9007199254740992.0 {
+            if let Some(i) = n.to_i128() {
+                return Some(<Self as From<i128>>::from(i));
+            }
+            if let Some(u) = n.to_u128() {
+                return Some(<Self as From<u128>>::from(u));
+            }
+        }
+        Some(<Self as From<f64>>::from(f))
+    }
+}
+
+#[cfg(test)]
+mod num_cast_tests {
+    use super::*;
+
+    #[test]
+    fn test_num_cast() {
+        let n = u128::MAX - 1;
+        assert_eq!(
+            <f256 as num_traits::NumCast>::from(n),
+            Some(f256::from(n))
+        );
+        let n = i64::MIN + 1;
+        assert_eq!(
+            <f256 as num_traits::NumCast>::from(n),
+            Some(f256::from(n))
+        );
+        assert_eq!(
+            <f256 as num_traits::NumCast>::from(-0.75_f32),
+            Some(f256::from(-0.75))
+        );
+        let f = 2.5e300_f64;
+        assert_eq!(
+            <f256 as num_traits::NumCast>::from(f),
+            Some(f256::from(f))
+        );
+        let f = f256::ONE / f256::from(3);
+        assert_eq!(
+            <f256 as num_traits::NumCast>::from(f),
+            Some(f256::from(f.to_f64()))
+        );
+        let x: Option<u16> = num_traits::NumCast::from(f256::from(300.7));
+        assert_eq!(x, Some(300));
+    }
+}
+
+impl Inv for f256 {
+    type Output = Self;
+
+    /// Returns 1 / `self`.
+    #[inline(always)]
+    fn inv(self) -> Self::Output {
+        Self::recip(self)
+    }
+}
+
+impl MulAdd for f256 {
+    type Output = Self;
+
+    /// Returns `self` * `a` + `b` with only one rounding error.
+    #[inline(always)]
+    fn mul_add(self, a: Self, b: Self) -> Self::Output {
+        Self::mul_add(self, a, b)
+    }
+}
+
+impl MulAddAssign for f256 {
+    #[inline(always)]
+    fn mul_add_assign(&mut self, a: Self, b: Self) {
+        *self = Self::mul_add(*self, a, b);
+    }
+}
+
+impl Pow<i32> for f256 {
+    type Output = Self;
+
+    /// Returns `self` raised to the integer power `n`.
+    #[inline(always)]
+    fn pow(self, n: i32) -> Self::Output {
+        Self::powi(&self, n)
+    }
+}
+
+impl Pow<Self> for f256 {
+    type Output = Self;
+
+    /// Returns `self` raised to the power `exp`.
+    #[inline(always)]
+    fn pow(self, exp: Self) -> Self::Output {
+        Self::powf(&self, &exp)
+    }
+}
+
+impl Pow<&Self> for f256 {
+    type Output = Self;
+
+    /// Returns `self` raised to the power `exp`.
+    #[inline(always)]
+    fn pow(self, exp: &Self) -> Self::Output {
+        Self::powf(&self, exp)
+    }
+}
+
+#[cfg(test)]
+mod misc_ops_tests {
+    use super::*;
+
+    #[test]
+    fn test_inv() {
+        assert_eq!(f256::from(4).inv(), f256::from(0.25));
+        assert_eq!(f256::NEG_ZERO.inv(), f256::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_mul_add() {
+        let x = f256::ONE + f256::EPSILON;
+        let y = f256::ONE - f256::EPSILON;
+        let z = MulAdd::mul_add(x, y, f256::NEG_ONE);
+        assert_eq!(z, -f256::EPSILON * f256::EPSILON);
+        let mut z = x;
+        z.mul_add_assign(y, f256::NEG_ONE);
+        assert_eq!(z, -f256::EPSILON * f256::EPSILON);
+    }
+
+    #[test]
+    fn test_pow() {
+        let x = f256::from(3);
+        assert_eq!(x.pow(4_i32), f256::from(81));
+        assert_eq!(x.pow(f256::TWO), f256::from(9));
+        assert_eq!(f256::from(16).pow(&f256::from(0.5)), f256::from(4));
+    }
+}
+
+macro_rules! impl_float_const {
+    ($($name:ident),*) => {
+        impl FloatConst for f256 {
+            $(
+            #[inline(always)]
+            fn $name() -> Self {
+                consts::$name
+            }
+            )*
+        }
+    }
+}
+
+impl_float_const!(
+    E,
+    FRAC_1_PI,
+    FRAC_1_SQRT_2,
+    FRAC_2_PI,
+    FRAC_2_SQRT_PI,
+    FRAC_PI_2,
+    FRAC_PI_3,
+    FRAC_PI_4,
+    FRAC_PI_6,
+    FRAC_PI_8,
+    LN_10,
+    LN_2,
+    LOG10_E,
+    LOG2_E,
+    PI,
+    SQRT_2,
+    TAU,
+    LOG10_2,
+    LOG2_10
+);
+
+#[cfg(test)]
+mod float_const_tests {
+    use super::*;
+
+    #[test]
+    fn test_float_const() {
+        assert_eq!(<f256 as FloatConst>::PI(), consts::PI);
+        assert_eq!(<f256 as FloatConst>::TAU(), consts::TAU);
+        assert_eq!(<f256 as FloatConst>::LOG2_10(), consts::LOG2_10);
+        assert_eq!(
+            <f256 as FloatConst>::FRAC_1_SQRT_2(),
+            consts::FRAC_1_SQRT_2
+        );
+    }
+}
+
+impl Float for f256 {
+    #[inline(always)]
+    fn nan() -> Self {
+        Self::NAN
+    }
+
+    #[inline(always)]
+    fn infinity() -> Self {
+        Self::INFINITY
+    }
+
+    #[inline(always)]
+    fn neg_infinity() -> Self {
+        Self::NEG_INFINITY
+    }
+
+    #[inline(always)]
+    fn neg_zero() -> Self {
+        Self::NEG_ZERO
+    }
+
+    #[inline(always)]
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    #[inline(always)]
+    fn min_positive_value() -> Self {
+        Self::MIN_POSITIVE
+    }
+
+    #[inline(always)]
+    fn epsilon() -> Self {
+        Self::EPSILON
+    }
+
+    #[inline(always)]
+    fn max_value() -> Self {
+        Self::MAX
+    }
+
+    #[inline(always)]
+    fn is_nan(self) -> bool {
+        Self::is_nan(self)
+    }
+
+    #[inline(always)]
+    fn is_infinite(self) -> bool {
+        Self::is_infinite(self)
+    }
+
+    #[inline(always)]
+    fn is_finite(self) -> bool {
+        Self::is_finite(self)
+    }
+
+    #[inline(always)]
+    fn is_normal(self) -> bool {
+        Self::is_normal(self)
+    }
+
+    #[inline(always)]
+    fn is_subnormal(self) -> bool {
+        Self::is_subnormal(self)
+    }
+
+    #[inline(always)]
+    fn classify(self) -> FpCategory {
+        Self::classify(&self)
+    }
+
+    #[inline(always)]
+    fn floor(self) -> Self {
+        Self::floor(&self)
+    }
+
+    #[inline(always)]
+    fn ceil(self) -> Self {
+        Self::ceil(&self)
+    }
+
+    #[inline(always)]
+    fn round(self) -> Self {
+        Self::round(&self)
+    }
+
+    #[inline(always)]
+    fn trunc(self) -> Self {
+        Self::trunc(&self)
+    }
+
+    #[inline(always)]
+    fn fract(self) -> Self {
+        Self::fract(&self)
+    }
+
+    #[inline(always)]
+    fn abs(self) -> Self {
+        Self::abs(&self)
+    }
+
+    #[inline(always)]
+    fn signum(self) -> Self {
+        Self::signum(self)
+    }
+
+    #[inline(always)]
+    fn is_sign_positive(self) -> bool {
+        Self::is_sign_positive(self)
+    }
+
+    #[inline(always)]
+    fn is_sign_negative(self) -> bool {
+        Self::is_sign_negative(self)
+    }
+
+    #[inline(always)]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Self::mul_add(self, a, b)
+    }
+
+    #[inline(always)]
+    fn recip(self) -> Self {
+        Self::recip(self)
+    }
+
+    #[inline(always)]
+    fn powi(self, n: i32) -> Self {
+        Self::powi(&self, n)
+    }
+
+    #[inline(always)]
+    fn powf(self, n: Self) -> Self {
+        Self::powf(&self, &n)
+    }
+
+    #[inline(always)]
+    fn sqrt(self) -> Self {
+        Self::sqrt(self)
+    }
+
+    #[inline(always)]
+    fn exp(self) -> Self {
+        Self::exp(&self)
+    }
+
+    #[inline(always)]
+    fn exp2(self) -> Self {
+        Self::exp2(&self)
+    }
+
+    #[inline(always)]
+    fn ln(self) -> Self {
+        Self::ln(&self)
+    }
+
+    #[inline(always)]
+    fn log(self, base: Self) -> Self {
+        Self::log(&self, &base)
+    }
+
+    #[inline(always)]
+    fn log2(self) -> Self {
+        Self::log2(&self)
+    }
+
+    #[inline(always)]
+    fn log10(self) -> Self {
+        Self::log10(&self)
+    }
+
+    #[inline(always)]
+    fn to_degrees(self) -> Self {
+        Self::to_degrees(self)
+    }
+
+    #[inline(always)]
+    fn to_radians(self) -> Self {
+        Self::to_radians(self)
+    }
+
+    #[inline(always)]
+    fn max(self, other: Self) -> Self {
+        Self::max(self, other)
+    }
+
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        Self::min(self, other)
+    }
+
+    #[inline(always)]
+    fn clamp(self, min: Self, max: Self) -> Self {
+        Self::clamp(self, min, max)
+    }
+
+    #[inline(always)]
+    fn abs_sub(self, other: Self) -> Self {
+        Signed::abs_sub(&self, &other)
+    }
+
+    #[inline(always)]
+    fn cbrt(self) -> Self {
+        Self::cbrt(self)
+    }
+
+    #[inline(always)]
+    fn hypot(self, other: Self) -> Self {
+        Self::hypot(self, other)
+    }
+
+    #[inline(always)]
+    fn sin(self) -> Self {
+        Self::sin(&self)
+    }
+
+    #[inline(always)]
+    fn cos(self) -> Self {
+        Self::cos(&self)
+    }
+
+    #[inline(always)]
+    fn tan(self) -> Self {
+        Self::tan(&self)
+    }
+
+    #[inline(always)]
+    fn asin(self) -> Self {
+        Self::asin(&self)
+    }
+
+    #[inline(always)]
+    fn acos(self) -> Self {
+        Self::acos(&self)
+    }
+
+    #[inline(always)]
+    fn atan(self) -> Self {
+        Self::atan(&self)
+    }
+
+    #[inline(always)]
+    fn atan2(self, other: Self) -> Self {
+        Self::atan2(&self, &other)
+    }
+
+    #[inline(always)]
+    fn sin_cos(self) -> (Self, Self) {
+        Self::sin_cos(&self)
+    }
+
+    #[inline(always)]
+    fn exp_m1(self) -> Self {
+        Self::exp_m1(&self)
+    }
+
+    #[inline(always)]
+    fn ln_1p(self) -> Self {
+        Self::ln_1p(&self)
+    }
+
+    #[inline(always)]
+    fn sinh(self) -> Self {
+        Self::sinh(&self)
+    }
+
+    #[inline(always)]
+    fn cosh(self) -> Self {
+        Self::cosh(&self)
+    }
+
+    #[inline(always)]
+    fn tanh(self) -> Self {
+        Self::tanh(&self)
+    }
+
+    #[inline(always)]
+    fn asinh(self) -> Self {
+        Self::asinh(&self)
+    }
+
+    #[inline(always)]
+    fn acosh(self) -> Self {
+        Self::acosh(&self)
+    }
+
+    #[inline(always)]
+    fn atanh(self) -> Self {
+        Self::atanh(&self)
+    }
+
+    /// Returns the mantissa, base 2 exponent and sign of `self` rounded to
+    /// the nearest f64, because the significand of an f256 does not fit
+    /// into an u64 and its exponent does not fit into an i16.
+    #[inline]
+    fn integer_decode(self) -> (u64, i16, i8) {
+        Float::integer_decode(Self::to_f64(&self))
+    }
+
+    #[inline(always)]
+    fn copysign(self, sign: Self) -> Self {
+        Self::copysign(self, sign)
+    }
+}
+
+#[cfg(test)]
+mod float_tests {
+    use super::*;
+
+    // Generic function, to make sure that the trait methods are called.
+    fn hypot_via_sqrt<T: Float>(x: T, y: T) -> T {
+        (x * x + y * y).sqrt()
+    }
+
+    fn sum_of_powers<T: Float>(x: T, n: i32) -> T {
+        (1..=n).fold(T::zero(), |acc, i| acc + x.powi(i))
+    }
+
+    #[test]
+    fn test_generic() {
+        let x = f256::from(3);
+        let y = f256::from(4);
+        assert_eq!(hypot_via_sqrt(x, y), f256::from(5));
+        assert_eq!(sum_of_powers(f256::TWO, 4), f256::from(30));
+    }
+
+    #[test]
+    fn test_consts() {
+        assert!(<f256 as Float>::nan().is_nan());
+        assert_eq!(<f256 as Float>::epsilon(), f256::EPSILON);
+        assert_eq!(<f256 as Float>::min_value(), f256::MIN);
+        assert_eq!(<f256 as Float>::max_value(), f256::MAX);
+        assert_eq!(<f256 as Float>::min_positive_value(), f256::MIN_POSITIVE);
+        assert_eq!(
+            <f256 as Float>::neg_zero().to_bits(),
+            f256::NEG_ZERO.to_bits()
+        );
+    }
+
+    #[test]
+    fn test_delegation_unary() {
+        let x = f256::from(-2.5);
+        assert_eq!(Float::abs(x), f256::from(2.5));
+        assert_eq!(Float::floor(x), f256::from(-3));
+        assert_eq!(Float::ceil(x), f256::from(-2));
+        assert_eq!(Float::round(x), f256::from(-3));
+        assert_eq!(Float::trunc(x), f256::from(-2));
+        assert_eq!(Float::fract(x), f256::from(-0.5));
+        assert_eq!(Float::classify(x), FpCategory::Normal);
+        assert!(Float::is_subnormal(f256::MIN_GT_ZERO));
+    }
+
+    #[test]
+    fn test_delegation_binary() {
+        let x = f256::from(-2.5);
+        assert_eq!(
+            Float::powf(f256::from(8), f256::ONE / f256::from(3)),
+            f256::powf(&f256::from(8), &(f256::ONE / f256::from(3)))
+        );
+        assert_eq!(Float::log(f256::from(8), f256::TWO), f256::from(3));
+        assert_eq!(Float::abs_sub(f256::ONE, x), f256::from(3.5));
+        assert_eq!(Float::copysign(f256::ONE, x), f256::NEG_ONE);
+        assert_eq!(Float::to_degrees(consts::PI), f256::from(180));
+        assert_eq!(Float::atan2(f256::ONE, f256::ONE), consts::FRAC_PI_4);
+        assert_eq!(Float::max(x, f256::NAN), x);
+    }
+
+    #[test]
+    fn test_integer_decode() {
+        assert_eq!(
+            Float::integer_decode(f256::from(-0.75)),
+            Float::integer_decode(-0.75_f64)
+        );
+        assert_eq!(Float::integer_decode(f256::ONE).1, -52);
+    }
+}
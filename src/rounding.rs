@@ -0,0 +1,154 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use crate::{f256, HI_SIGN_MASK, INF_HI, MAX_HI, U256};
+
+/// Rounding-direction attributes as defined in IEEE 754-2019, section 4.3.
+///
+/// They determine how the exact result of an operation is mapped to a
+/// representable `f256` value.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum RoundingMode {
+    /// Round to the nearest representable value; if the exact result is
+    /// midway between two representable values, round to the one with an
+    /// even least significant bit. This is the default mode used by all
+    /// operators.
+    #[default]
+    NearestTiesEven,
+    /// Round toward +∞, i.e. to the smallest representable value not less
+    /// than the exact result.
+    TowardPositive,
+    /// Round toward -∞, i.e. to the largest representable value not greater
+    /// than the exact result.
+    TowardNegative,
+    /// Round toward zero, i.e. to the representable value closest to and not
+    /// greater in magnitude than the exact result.
+    TowardZero,
+}
+
+impl RoundingMode {
+    /// Returns `true` if a truncated significand has to be incremented in
+    /// order to get the result rounded according to `self`.
+    ///
+    /// `rnd_bits` holds the bits truncated from the significand, compressed
+    /// into round bit(s) and a sticky bit, so that `rnd_bits` == 0 means the
+    /// result is exact and `rnd_bits` == `tie` means it is exactly half way
+    /// between two representable values.
+    #[inline(always)]
+    pub(crate) const fn round_up(
+        self,
+        sign_bits_hi: u128,
+        is_odd: bool,
+        rnd_bits: u32,
+        tie: u32,
+    ) -> bool {
+        match self {
+            Self::NearestTiesEven => {
+                rnd_bits > tie || (rnd_bits == tie && is_odd)
+            }
+            Self::TowardPositive => sign_bits_hi == 0 && rnd_bits != 0,
+            Self::TowardNegative => sign_bits_hi != 0 && rnd_bits != 0,
+            Self::TowardZero => false,
+        }
+    }
+
+    /// Returns the result of an operation with given sign, which overflows
+    /// the range of finite `f256` values: ±∞ when rounding away from zero,
+    /// otherwise ±MAX.
+    #[inline(always)]
+    pub(crate) const fn overflow(self, sign_bits_hi: u128) -> f256 {
+        let away_from_zero = match self {
+            Self::NearestTiesEven => true,
+            Self::TowardPositive => sign_bits_hi == 0,
+            Self::TowardNegative => sign_bits_hi != 0,
+            Self::TowardZero => false,
+        };
+        if away_from_zero {
+            f256 {
+                bits: U256::new(sign_bits_hi | INF_HI, 0),
+            }
+        } else {
+            f256 {
+                bits: U256::new(sign_bits_hi | MAX_HI, u128::MAX),
+            }
+        }
+    }
+
+    /// Returns the sign of an exact zero sum of operands with opposite signs
+    /// (or of two zeros with opposite signs): -0 when rounding toward -∞,
+    /// otherwise +0.
+    #[inline(always)]
+    pub(crate) const fn zero_sum_sign_bits_hi(self) -> u128 {
+        match self {
+            Self::TowardNegative => HI_SIGN_MASK,
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod rounding_mode_tests {
+    use super::*;
+
+    #[test]
+    fn test_round_up() {
+        for (sign, odd, rnd_bits, res) in [
+            (0, false, 0b00, [false, false, false, false]),
+            (0, false, 0b01, [false, true, false, false]),
+            (0, false, 0b10, [false, true, false, false]),
+            (0, true, 0b10, [true, true, false, false]),
+            (0, false, 0b11, [true, true, false, false]),
+            (HI_SIGN_MASK, false, 0b01, [false, false, true, false]),
+            (HI_SIGN_MASK, true, 0b10, [true, false, true, false]),
+            (HI_SIGN_MASK, true, 0b00, [false, false, false, false]),
+        ] {
+            for (mode, exp) in [
+                RoundingMode::NearestTiesEven,
+                RoundingMode::TowardPositive,
+                RoundingMode::TowardNegative,
+                RoundingMode::TowardZero,
+            ]
+            .iter()
+            .zip(res)
+            {
+                assert_eq!(
+                    mode.round_up(sign, odd, rnd_bits, 0b10),
+                    exp,
+                    "{mode:?} {sign} {odd} {rnd_bits}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_overflow() {
+        let pos = 0_u128;
+        let neg = HI_SIGN_MASK;
+        assert_eq!(
+            RoundingMode::NearestTiesEven.overflow(pos),
+            f256::INFINITY
+        );
+        assert_eq!(
+            RoundingMode::NearestTiesEven.overflow(neg),
+            f256::NEG_INFINITY
+        );
+        assert_eq!(
+            RoundingMode::TowardPositive.overflow(pos),
+            f256::INFINITY
+        );
+        assert_eq!(RoundingMode::TowardPositive.overflow(neg), f256::MIN);
+        assert_eq!(RoundingMode::TowardNegative.overflow(pos), f256::MAX);
+        assert_eq!(
+            RoundingMode::TowardNegative.overflow(neg),
+            f256::NEG_INFINITY
+        );
+        assert_eq!(RoundingMode::TowardZero.overflow(pos), f256::MAX);
+        assert_eq!(RoundingMode::TowardZero.overflow(neg), f256::MIN);
+    }
+}
@@ -0,0 +1,198 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use core::{fmt, str::FromStr};
+
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::f256;
+
+/// Number of bytes in the binary representation of an `f256`.
+const N_BYTES: usize = 32;
+
+impl Serialize for f256 {
+    /// Serializes `self` as the shortest decimal string which round-trips
+    /// (like "1.5e-3") for human-readable formats and as the 32 bytes of its
+    /// little-endian memory representation for binary formats.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&format_args!("{self:e}"))
+        } else {
+            serializer.serialize_bytes(&self.to_le_bytes())
+        }
+    }
+}
+
+struct F256Visitor;
+
+impl F256Visitor {
+    fn from_bytes<E: de::Error>(v: &[u8]) -> Result<f256, E> {
+        <[u8; N_BYTES]>::try_from(v)
+            .map(f256::from_le_bytes)
+            .map_err(|_| E::invalid_length(v.len(), &"32 bytes"))
+    }
+}
+
+impl<'de> Visitor<'de> for F256Visitor {
+    type Value = f256;
+
+    fn expecting(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+        form.write_str("a number literal or 32 bytes representing an f256")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        f256::from_str(v)
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Self::from_bytes(v)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = [0_u8; N_BYTES];
+        for (i, b) in bytes.iter_mut().enumerate() {
+            *b = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(de::Error::invalid_length(N_BYTES + 1, &self));
+        }
+        Ok(f256::from_le_bytes(bytes))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(f256::from(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(f256::from(v))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        Ok(f256::from(v))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        Ok(f256::from(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(f256::from(v))
+    }
+}
+
+impl<'de> Deserialize<'de> for f256 {
+    /// Deserializes an `f256` from a decimal or hexadecimal number literal
+    /// or from the 32 bytes of its little-endian memory representation,
+    /// regardless of the format being human-readable or binary. Besides
+    /// that, native numbers are accepted in self-describing formats.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(F256Visitor)
+        } else {
+            deserializer.deserialize_bytes(F256Visitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod serde_tests {
+    use alloc::vec;
+
+    use serde_test::{
+        assert_de_tokens, assert_de_tokens_error, assert_tokens, Compact,
+        Configure, Readable, Token,
+    };
+
+    use super::*;
+    use crate::consts::PI;
+
+    #[test]
+    fn test_readable() {
+        assert_tokens(&f256::from(12.5).readable(), &[Token::Str("1.25e1")]);
+        assert_tokens(&f256::NEG_ZERO.readable(), &[Token::Str("-0e0")]);
+        assert_tokens(
+            &PI.readable(),
+            &[Token::Str(
+                "3.14159265358979323846264338327950288419716939937510582097\
+                 49445923078164e0",
+            )],
+        );
+        assert_tokens(&f256::INFINITY.readable(), &[Token::Str("inf")]);
+        assert_tokens(
+            &f256::MAX.readable(),
+            &[Token::Str(
+                "1.61132571748576047361957211845200501064402387454966951747\
+                 637125049607183e78913",
+            )],
+        );
+    }
+
+    #[test]
+    fn test_compact() {
+        const PI_BYTES: [u8; N_BYTES] = PI.to_le_bytes();
+        assert_tokens(&PI.compact(), &[Token::Bytes(&PI_BYTES)]);
+        assert_de_tokens(&PI.compact(), &[Token::ByteBuf(&PI_BYTES)]);
+        const TINY_BYTES: [u8; N_BYTES] = f256::MIN_GT_ZERO.to_le_bytes();
+        assert_tokens(
+            &f256::MIN_GT_ZERO.compact(),
+            &[Token::Bytes(&TINY_BYTES)],
+        );
+    }
+
+    #[test]
+    fn test_accept_both() {
+        const NEG_ONE_BYTES: [u8; N_BYTES] = f256::NEG_ONE.to_le_bytes();
+        let f = f256::NEG_ONE;
+        assert_de_tokens(&f.readable(), &[Token::Bytes(&NEG_ONE_BYTES)]);
+        assert_de_tokens(&f.compact(), &[Token::Str("-1e0")]);
+        assert_de_tokens(&f.compact(), &[Token::Str("-0x1p+0")]);
+        let mut tokens = vec![Token::Seq { len: Some(N_BYTES) }];
+        tokens.extend(NEG_ONE_BYTES.iter().map(|b| Token::U8(*b)));
+        tokens.push(Token::SeqEnd);
+        assert_de_tokens(&f.readable(), &tokens);
+    }
+
+    #[test]
+    fn test_native_numbers() {
+        assert_de_tokens(
+            &f256::from(-0.375).readable(),
+            &[Token::F64(-0.375)],
+        );
+        assert_de_tokens(&f256::from(7).readable(), &[Token::U8(7)]);
+        assert_de_tokens(&f256::from(-7).readable(), &[Token::I32(-7)]);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_de_tokens_error::<Readable<f256>>(
+            &[Token::Str("1.2.3")],
+            "invalid value: string \"1.2.3\", expected a number literal or \
+             32 bytes representing an f256",
+        );
+        assert_de_tokens_error::<Compact<f256>>(
+            &[Token::Bytes(&[0_u8; 31])],
+            "invalid length 31, expected 32 bytes",
+        );
+    }
+}
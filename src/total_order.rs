@@ -0,0 +1,250 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use crate::f256;
+
+/// Wrapper around an `f256` which is totally ordered according to
+/// [`f256::total_cmp`].
+///
+/// Unlike `f256`, `TotalF256` implements [`Eq`], [`Ord`] and [`Hash`], so it
+/// can be used as key in a `BTreeMap` or `HashMap` and slices of it can be
+/// sorted with `sort`.
+///
+/// Two values are equal if and only if their bit representations are equal.
+/// Thus, negative and positive zero are different, while a NaN is equal to
+/// itself (but not to a NaN with a different sign).
+///
+/// # Examples
+///
+/// ```
+/// # use ::f256::{f256, TotalF256};
+/// let mut v = [
+///     TotalF256(f256::from(3)),
+///     TotalF256(f256::NAN),
+///     TotalF256(f256::ZERO),
+///     TotalF256(f256::NEG_ZERO),
+///     TotalF256(f256::from(-7)),
+/// ];
+/// v.sort();
+/// assert_eq!(v[0], TotalF256(f256::from(-7)));
+/// assert_eq!(v[1].0.to_bits(), f256::NEG_ZERO.to_bits());
+/// assert_eq!(v[2].0.to_bits(), f256::ZERO.to_bits());
+/// assert_eq!(v[3], TotalF256(f256::from(3)));
+/// assert!(v[4].0.is_nan());
+/// ```
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct TotalF256(pub f256);
+
+impl PartialEq for TotalF256 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+impl Eq for TotalF256 {}
+
+impl PartialOrd for TotalF256 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TotalF256 {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for TotalF256 {
+    // Consistent with `eq`, because equal values have equal bits.
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl From<f256> for TotalF256 {
+    #[inline]
+    fn from(f: f256) -> Self {
+        Self(f)
+    }
+}
+
+impl From<TotalF256> for f256 {
+    #[inline]
+    fn from(t: TotalF256) -> Self {
+        t.0
+    }
+}
+
+impl fmt::Debug for TotalF256 {
+    fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+        form.debug_tuple("TotalF256").field(&self.0).finish()
+    }
+}
+
+impl fmt::Display for TotalF256 {
+    fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, form)
+    }
+}
+
+#[cfg(test)]
+mod total_f256_tests {
+    use alloc::{
+        collections::{BTreeMap, BTreeSet},
+        format,
+        vec::Vec,
+    };
+
+    use super::*;
+
+    // Hasher simply collecting the bytes written to it.
+    #[derive(Default)]
+    struct ByteHasher(Vec<u8>);
+
+    impl Hasher for ByteHasher {
+        fn finish(&self) -> u64 {
+            0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.extend_from_slice(bytes);
+        }
+    }
+
+    fn hash_bytes(t: TotalF256) -> Vec<u8> {
+        let mut hasher = ByteHasher::default();
+        t.hash(&mut hasher);
+        hasher.0
+    }
+
+    #[test]
+    fn test_eq() {
+        let t = TotalF256(f256::from(5));
+        assert_eq!(t, TotalF256::from(f256::from(5)));
+        assert_ne!(TotalF256(f256::ZERO), TotalF256(f256::NEG_ZERO));
+        assert_eq!(TotalF256(f256::NAN), TotalF256(f256::NAN));
+        assert_ne!(TotalF256(f256::NAN), TotalF256(-f256::NAN));
+        assert_eq!(f256::from(TotalF256(f256::TEN)), f256::TEN);
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut v: Vec<TotalF256> = [
+            f256::NAN,
+            f256::ONE,
+            f256::MIN_GT_ZERO,
+            f256::ZERO,
+            f256::INFINITY,
+            -f256::MIN_GT_ZERO,
+            f256::NEG_ZERO,
+            f256::NEG_INFINITY,
+            -f256::NAN,
+            f256::from(-2),
+            f256::NEG_ONE,
+            f256::MAX,
+            f256::MIN,
+        ]
+        .into_iter()
+        .map(TotalF256)
+        .collect();
+        v.sort();
+        let bits: Vec<_> = v.iter().map(|t| t.0.to_bits()).collect();
+        let expected: Vec<_> = [
+            -f256::NAN,
+            f256::NEG_INFINITY,
+            f256::MIN,
+            f256::from(-2),
+            f256::NEG_ONE,
+            -f256::MIN_GT_ZERO,
+            f256::NEG_ZERO,
+            f256::ZERO,
+            f256::MIN_GT_ZERO,
+            f256::ONE,
+            f256::MAX,
+            f256::INFINITY,
+            f256::NAN,
+        ]
+        .iter()
+        .map(f256::to_bits)
+        .collect();
+        assert_eq!(bits, expected);
+    }
+
+    #[test]
+    fn test_hash() {
+        let t = TotalF256(f256::from(0.25));
+        assert_eq!(hash_bytes(t), hash_bytes(TotalF256(f256::from(0.25))));
+        assert_eq!(
+            hash_bytes(TotalF256(f256::NAN)),
+            hash_bytes(TotalF256(f256::NAN))
+        );
+        assert_ne!(
+            hash_bytes(TotalF256(f256::ZERO)),
+            hash_bytes(TotalF256(f256::NEG_ZERO))
+        );
+    }
+
+    #[test]
+    fn test_btree() {
+        let mut map = BTreeMap::new();
+        map.insert(TotalF256(f256::NAN), 2);
+        map.insert(TotalF256(f256::ZERO), 0);
+        map.insert(TotalF256(f256::NEG_ZERO), 1);
+        map.insert(TotalF256(f256::ZERO), 3);
+        assert_eq!(map.len(), 3);
+        assert_eq!(map[&TotalF256(f256::ZERO)], 3);
+        assert_eq!(map[&TotalF256(f256::NAN)], 2);
+        let keys: Vec<_> = map.keys().map(|t| t.0.to_bits()).collect();
+        assert_eq!(
+            keys,
+            [f256::NEG_ZERO, f256::ZERO, f256::NAN].map(|f| f.to_bits())
+        );
+        let set: BTreeSet<TotalF256> = [3, -1, 2, -1, 3]
+            .into_iter()
+            .map(|i| f256::from(i).into())
+            .collect();
+        assert_eq!(set.first(), Some(&TotalF256(f256::NEG_ONE)));
+        assert_eq!(set.len(), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hash_map() {
+        use std::collections::HashMap;
+
+        let mut cache = HashMap::new();
+        cache.insert(TotalF256(f256::ZERO), 0);
+        cache.insert(TotalF256(f256::NEG_ZERO), 1);
+        cache.insert(TotalF256(f256::NAN), 2);
+        cache.insert(TotalF256(f256::ZERO), 3);
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache[&TotalF256(f256::ZERO)], 3);
+        assert_eq!(cache[&TotalF256(f256::NEG_ZERO)], 1);
+        assert_eq!(cache[&TotalF256(f256::NAN)], 2);
+    }
+
+    #[test]
+    fn test_fmt() {
+        let t = TotalF256(f256::from(-1.5));
+        assert_eq!(format!("{t}"), "-1.5");
+        assert_eq!(format!("{t:?}"), format!("TotalF256({:?})", t.0));
+    }
+}
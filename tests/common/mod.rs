@@ -0,0 +1,54 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+// Fixtures shared by the integration tests.
+
+use f256::f256;
+
+pub const MIN_EXP: i32 = f256::MIN_EXP - 1;
+
+// Simple deterministic pseudo-random generator (xorshift64*).
+pub struct Rng(pub u64);
+
+impl Rng {
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    pub fn next_u128(&mut self) -> u128 {
+        ((self.next_u64() as u128) << 64) | self.next_u64() as u128
+    }
+
+    // Returns a random i32 in [lo, hi].
+    pub fn next_in(&mut self, lo: i32, hi: i32) -> i32 {
+        lo + (self.next_u64() % (hi - lo + 1) as u64) as i32
+    }
+
+    // Returns a random finite f256 with an exponent in [lo, hi] and a
+    // significand of 1 to 237 random bits.
+    pub fn next_f256(&mut self, lo: i32, hi: i32) -> f256 {
+        let s = (self.next_u64() & 1) as u32;
+        let n_bits = self.next_in(1, 237) as u32;
+        let c = if n_bits > 128 {
+            let hi = self.next_u128() >> (256 - n_bits);
+            (hi | (1 << (n_bits - 129)), self.next_u128())
+        } else {
+            (
+                0,
+                (self.next_u128() >> (128 - n_bits)) | (1 << (n_bits - 1)),
+            )
+        };
+        let e = self.next_in(lo, hi);
+        let t = (e - n_bits as i32 + 1).max(MIN_EXP - 236);
+        f256::from_sign_exp_signif(s, t, c)
+    }
+}
@@ -71,4 +71,19 @@ mod random_bin_math_fns_tests {
     fn test_log() {
         run_tests(f256::log, "test_log.txt");
     }
+
+    #[test]
+    fn test_beta() {
+        run_tests(f256::beta, "test_beta.txt");
+    }
+
+    #[test]
+    fn test_atan2pi() {
+        run_tests(f256::atan2pi, "test_atan2pi.txt");
+    }
+
+    #[test]
+    fn test_atan2d() {
+        run_tests(f256::atan2d, "test_atan2d.txt");
+    }
 }
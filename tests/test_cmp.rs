@@ -98,4 +98,49 @@ mod partial_ord_tests {
         assert!(f256::INFINITY > f256::NEG_INFINITY);
         assert!(f256::NEG_INFINITY < f256::INFINITY);
     }
+
+    #[test]
+    fn test_negatives() {
+        let m2 = f256::from(-2);
+        assert!(m2 < f256::NEG_ONE);
+        assert!(f256::NEG_ONE > m2);
+        assert!(f256::NEG_INFINITY < f256::MIN);
+        assert!(f256::MIN < m2);
+        assert!(-f256::MIN_GT_ZERO < f256::NEG_ZERO);
+        assert!(-f256::MIN_GT_ZERO > -f256::MIN_POSITIVE);
+        assert_eq!(m2.max(f256::NEG_ONE), f256::NEG_ONE);
+        assert_eq!(m2.min(f256::NEG_ONE), m2);
+    }
+}
+
+#[cfg(test)]
+mod total_cmp_tests {
+    use f256::f256;
+
+    #[test]
+    fn test_total_cmp() {
+        let values = [
+            -f256::NAN,
+            f256::NEG_INFINITY,
+            f256::MIN,
+            f256::from(-2),
+            f256::NEG_ONE,
+            -f256::MIN_POSITIVE,
+            -f256::MIN_GT_ZERO,
+            f256::NEG_ZERO,
+            f256::ZERO,
+            f256::MIN_GT_ZERO,
+            f256::MIN_POSITIVE,
+            f256::ONE,
+            f256::TWO,
+            f256::MAX,
+            f256::INFINITY,
+            f256::NAN,
+        ];
+        for (i, x) in values.iter().enumerate() {
+            for (j, y) in values.iter().enumerate() {
+                assert_eq!(x.total_cmp(y), i.cmp(&j), "{x:?} <=> {y:?}");
+            }
+        }
+    }
 }
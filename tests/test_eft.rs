@@ -0,0 +1,176 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+mod common;
+
+#[cfg(test)]
+mod error_free_transformation_tests {
+    use ::f256::{f256, BigFloat512};
+
+    use crate::common::{Rng, MIN_EXP};
+
+    const N_SAMPLES: usize = 5000;
+
+    fn big(f: &f256) -> BigFloat512 {
+        BigFloat512::try_from(f).unwrap()
+    }
+
+    fn assert_exact_sum(x: &f256, y: &f256, hi: &f256, lo: &f256) {
+        // The exponents of x and y differ by less than 273, so that their
+        // exact sum fits into a BigFloat512.
+        assert_eq!(*hi, *x + *y, "x = {x:?}, y = {y:?}");
+        assert!(lo.abs() <= hi.ulp().div2(), "x = {x:?}, y = {y:?}");
+        assert_eq!(
+            big(x) + big(y),
+            big(hi) + big(lo),
+            "x = {x:?}, y = {y:?}"
+        );
+    }
+
+    #[test]
+    fn test_two_sum_exact() {
+        let mut rng = Rng(0x0123_4567_89ab_cdef);
+        for _ in 0..N_SAMPLES {
+            let x = rng.next_f256(-20, 20);
+            let y = rng.next_f256(-20, 20);
+            let (hi, lo) = x.two_sum(y);
+            assert_exact_sum(&x, &y, &hi, &lo);
+            let (hi, lo) = y.two_sum(x);
+            assert_exact_sum(&y, &x, &hi, &lo);
+        }
+    }
+
+    #[test]
+    fn test_two_sum_extreme_exps() {
+        let mut rng = Rng(0x5eed_0000_f256_0001);
+        for (lo_exp, hi_exp) in [
+            (MIN_EXP - 236, MIN_EXP + 36),
+            (f256::MAX_EXP - 260, f256::MAX_EXP - 3),
+        ] {
+            for _ in 0..N_SAMPLES {
+                let x = rng.next_f256(lo_exp, hi_exp);
+                let y = rng.next_f256(lo_exp, hi_exp);
+                let (hi, lo) = x.two_sum(y);
+                assert_exact_sum(&x, &y, &hi, &lo);
+            }
+        }
+    }
+
+    #[test]
+    fn test_two_sum_subnormal() {
+        // Sums of subnormal values are exact.
+        let x = f256::MIN_GT_ZERO.mul_pow2(77);
+        let y = -f256::MIN_GT_ZERO;
+        assert_eq!(x.two_sum(y), (x + y, f256::ZERO));
+        let x = f256::MIN_POSITIVE;
+        assert_eq!(x.two_sum(y), (x + y, f256::ZERO));
+    }
+
+    #[test]
+    fn test_two_sum_cancellation() {
+        let x = f256::ONE + f256::EPSILON;
+        let (hi, lo) = x.two_sum(-f256::ONE);
+        assert_eq!(hi, f256::EPSILON);
+        assert!(lo.eq_zero());
+        let (hi, lo) = x.two_sum(-x);
+        assert!(hi.eq_zero());
+        assert!(lo.eq_zero());
+    }
+
+    #[test]
+    fn test_two_sum_non_finite() {
+        let (hi, lo) = f256::MAX.two_sum(f256::MAX);
+        assert_eq!(hi, f256::INFINITY);
+        assert!(lo.is_nan());
+        let (hi, lo) = f256::NEG_INFINITY.two_sum(f256::ONE);
+        assert_eq!(hi, f256::NEG_INFINITY);
+        assert!(lo.is_nan());
+        let (hi, lo) = f256::ONE.two_sum(f256::NAN);
+        assert!(hi.is_nan());
+        assert!(lo.is_nan());
+    }
+
+    #[test]
+    fn test_fast_two_sum_exact() {
+        let mut rng = Rng(0xfeed_face_cafe_beef);
+        for _ in 0..N_SAMPLES {
+            let x = rng.next_f256(-20, 20);
+            let y = rng.next_f256(-20, 20);
+            let (x, y) = if x.abs() >= y.abs() { (x, y) } else { (y, x) };
+            let (hi, lo) = x.fast_two_sum(y);
+            assert_exact_sum(&x, &y, &hi, &lo);
+            assert_eq!((hi, lo), x.two_sum(y));
+        }
+    }
+
+    #[test]
+    fn test_fast_two_sum_precondition() {
+        // Fast2Sum needs exp(x) >= exp(y), it does not care about the
+        // significands.
+        let x = f256::from(1.25);
+        let y = f256::from(-1.75) + f256::EPSILON;
+        let (hi, lo) = x.fast_two_sum(y);
+        assert_exact_sum(&x, &y, &hi, &lo);
+    }
+
+    #[test]
+    fn test_two_prod_exact() {
+        let mut rng = Rng(0x0f0f_1234_abcd_9876);
+        for _ in 0..N_SAMPLES {
+            let x = rng.next_f256(-5000, 5000);
+            let y = rng.next_f256(-5000, 5000);
+            let (hi, lo) = x.two_prod(y);
+            assert_eq!(hi, x * y, "x = {x:?}, y = {y:?}");
+            assert!(lo.abs() <= hi.ulp().div2(), "x = {x:?}, y = {y:?}");
+            // The exact product has at most 474 bits, so that it fits into
+            // a BigFloat512.
+            assert_eq!(
+                big(&x) * big(&y),
+                big(&hi) + big(&lo),
+                "x = {x:?}, y = {y:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_two_prod_near_underflow_limit() {
+        // 2²³⁷ × MIN_POSITIVE is the lower limit for an exact result.
+        let mut rng = Rng(0x1357_9bdf_2468_ace0);
+        let limit = f256::MIN_POSITIVE.mul_pow2(237);
+        for _ in 0..N_SAMPLES {
+            let x = rng.next_f256(-300, 100);
+            let y = rng.next_f256(MIN_EXP + 200, MIN_EXP + 650);
+            let (hi, lo) = x.two_prod(y);
+            assert_eq!(hi, x * y, "x = {x:?}, y = {y:?}");
+            if hi.abs() >= limit {
+                assert_eq!(
+                    big(&x) * big(&y),
+                    big(&hi) + big(&lo),
+                    "x = {x:?}, y = {y:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_two_prod_special() {
+        assert_eq!(
+            f256::TWO.two_prod(f256::from(3)),
+            (f256::from(6), f256::ZERO)
+        );
+        let (hi, lo) = f256::ZERO.two_prod(f256::NEG_ONE);
+        assert!(hi.eq_zero() && hi.is_sign_negative());
+        assert!(lo.eq_zero());
+        let (hi, _) = f256::MAX.two_prod(f256::TWO);
+        assert_eq!(hi, f256::INFINITY);
+        let (hi, lo) = f256::NAN.two_prod(f256::ONE);
+        assert!(hi.is_nan());
+        assert!(lo.is_nan());
+    }
+}
@@ -161,6 +161,45 @@ mod div_euclid_tests {
         assert_eq!(f.div_euclid(d), f256::from(-4));
         assert_eq!(f.div_euclid(d.square()), -d);
     }
+
+    #[test]
+    fn test_div_euclid_quotient_rounded_up_by_division() {
+        // 9 < 1 / y < 10, but 1 / y may be rounded to 10.
+        let y = (f256::ONE / f256::TEN).next_up();
+        assert_eq!(f256::ONE.div_euclid(y), f256::from(9));
+        assert_eq!(f256::NEG_ONE.div_euclid(y), f256::from(-10));
+    }
+
+    #[test]
+    fn test_div_euclid_large_quotient() {
+        // x / y = 2²³⁸ + 2 + 2⁻²³⁶ + ..., which is rounded to 2²³⁸ + 4,
+        // while ⌊x / y⌋ = 2²³⁸ + 2 is rounded to 2²³⁸ (ties to even).
+        let x = f256::TWO.powi(240);
+        let y = f256::from(4).next_down();
+        assert_eq!(x.div_euclid(y), f256::TWO.powi(238));
+        // ⌊-x / y⌋ = -(2²³⁸ + 3) is rounded to -(2²³⁸ + 4).
+        assert_eq!(
+            (-x).div_euclid(y),
+            -(f256::TWO.powi(238) + f256::from(4))
+        );
+        // ⌊2³⁰⁰ / 3⌋ = (2³⁰⁰ - 1) / 3 = Σ 2²ⁱ for i in 0..150 is rounded to
+        // Σ 2²ⁱ for i in 31..150.
+        let mut q = f256::ZERO;
+        for i in 31..150 {
+            q += f256::TWO.powi(2 * i);
+        }
+        let x = f256::TWO.powi(300);
+        let y = f256::from(3);
+        assert_eq!(x.div_euclid(y), q);
+        assert_eq!((-x).div_euclid(-y), q);
+        assert_eq!(x.div_euclid(-y), -q);
+        assert_eq!((-x).div_euclid(y), -q);
+        assert_eq!(f256::MAX.div_euclid(f256::MIN_GT_ZERO), f256::INFINITY);
+        assert_eq!(
+            f256::MAX.div_euclid(-f256::MIN_GT_ZERO),
+            f256::NEG_INFINITY
+        );
+    }
 }
 
 #[cfg(test)]
@@ -306,6 +345,17 @@ mod rem_euclid_tests {
         assert_eq!(f.rem_euclid(d), f256::from(1.5));
         assert_eq!(f.rem_euclid(d.square()), f256::from(1.5));
     }
+
+    #[test]
+    fn test_rem_euclid_large_quotient() {
+        // 2³⁰⁰ = 3 × (2³⁰⁰ - 1) / 3 + 1
+        let x = f256::TWO.powi(300);
+        let y = f256::from(3);
+        assert_eq!(x.rem_euclid(y), f256::ONE);
+        assert_eq!((-x).rem_euclid(y), f256::TWO);
+        assert_eq!(x.rem_euclid(-y), -f256::TWO);
+        assert_eq!((-x).rem_euclid(-y), f256::NEG_ONE);
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,294 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+#[cfg(test)]
+mod exception_flags_tests {
+    use ::f256::{f256, ExceptionFlags, RoundingMode};
+
+    const NONE: ExceptionFlags = ExceptionFlags::NONE;
+    const INVALID: ExceptionFlags = ExceptionFlags::INVALID;
+    const DIV_BY_ZERO: ExceptionFlags = ExceptionFlags::DIV_BY_ZERO;
+    const INEXACT: ExceptionFlags = ExceptionFlags::INEXACT;
+    const OVERFLOW: ExceptionFlags = ExceptionFlags::OVERFLOW;
+    const UNDERFLOW: ExceptionFlags = ExceptionFlags::UNDERFLOW;
+
+    const RNE: RoundingMode = RoundingMode::NearestTiesEven;
+    const TZ: RoundingMode = RoundingMode::TowardZero;
+
+    #[test]
+    fn test_nan_operands() {
+        // A NaN operand gives a NaN result without signaling an exception.
+        let nan = f256::NAN;
+        let one = f256::ONE;
+        for (z, flags) in [
+            nan.add_with_flags(one, RNE),
+            one.sub_with_flags(nan, RNE),
+            nan.mul_with_flags(f256::INFINITY, RNE),
+            f256::ZERO.div_with_flags(nan, RNE),
+            nan.rem_with_flags(f256::ZERO),
+            nan.sqrt_with_flags(RNE),
+            (-nan).sqrt_with_flags(RNE),
+            one.mul_add_with_flags(nan, one, RNE),
+            f256::INFINITY.mul_add_with_flags(f256::ZERO, nan, RNE),
+        ] {
+            assert!(z.is_nan());
+            assert_eq!(flags, NONE);
+        }
+        assert_eq!(nan.to_f64_with_flags().1, NONE);
+        assert_eq!(nan.to_f32_with_flags().1, NONE);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let inf = f256::INFINITY;
+        let zero = f256::ZERO;
+        let one = f256::ONE;
+        for (z, flags) in [
+            inf.add_with_flags(-inf, RNE),
+            inf.sub_with_flags(inf, TZ),
+            zero.mul_with_flags(-inf, RNE),
+            inf.mul_with_flags(zero, RNE),
+            zero.div_with_flags(-zero, RNE),
+            inf.div_with_flags(-inf, RNE),
+            one.rem_with_flags(zero),
+            inf.rem_with_flags(one),
+            f256::NEG_ONE.sqrt_with_flags(RNE),
+            f256::NEG_INFINITY.sqrt_with_flags(RNE),
+            (-f256::MIN_GT_ZERO).sqrt_with_flags(RNE),
+            inf.mul_add_with_flags(zero, one, RNE),
+            zero.mul_add_with_flags(inf, zero, RNE),
+            zero.mul_add_with_flags(inf, inf, RNE),
+            inf.mul_add_with_flags(one, -inf, RNE),
+            one.mul_add_with_flags(inf, -inf, RNE),
+        ] {
+            assert!(z.is_nan());
+            assert_eq!(flags, INVALID);
+        }
+    }
+
+    #[test]
+    fn test_div_by_zero() {
+        let (z, flags) = f256::ONE.div_with_flags(f256::ZERO, RNE);
+        assert_eq!(z, f256::INFINITY);
+        assert_eq!(flags, DIV_BY_ZERO);
+        let (z, flags) = f256::MIN_GT_ZERO.div_with_flags(f256::NEG_ZERO, TZ);
+        assert_eq!(z, f256::NEG_INFINITY);
+        assert_eq!(flags, DIV_BY_ZERO);
+        // An infinite dividend gives an exact infinite result.
+        let (z, flags) = f256::INFINITY.div_with_flags(f256::ZERO, RNE);
+        assert_eq!(z, f256::INFINITY);
+        assert_eq!(flags, NONE);
+    }
+
+    #[test]
+    fn test_exact_specials() {
+        let inf = f256::INFINITY;
+        let zero = f256::ZERO;
+        let two = f256::TWO;
+        for (z, flags) in [
+            inf.add_with_flags(two, RNE),
+            inf.add_with_flags(inf, RNE),
+            zero.add_with_flags(-zero, RNE),
+            two.sub_with_flags(two, RNE),
+            inf.mul_with_flags(-two, RNE),
+            zero.mul_with_flags(f256::MAX, RNE),
+            two.div_with_flags(inf, RNE),
+            zero.div_with_flags(two, RNE),
+            two.rem_with_flags(inf),
+            inf.sqrt_with_flags(RNE),
+            f256::NEG_ZERO.sqrt_with_flags(RNE),
+            two.mul_add_with_flags(two, inf, RNE),
+            inf.mul_add_with_flags(two, inf, RNE),
+            zero.mul_add_with_flags(two, -zero, RNE),
+            two.mul_add_with_flags(two, zero, RNE),
+        ] {
+            assert!(!z.is_nan());
+            assert_eq!(flags, NONE);
+        }
+    }
+
+    #[test]
+    fn test_overflow() {
+        let two = f256::TWO;
+        let (z, flags) = f256::MAX.add_with_flags(f256::MAX, RNE);
+        assert_eq!(z, f256::INFINITY);
+        assert_eq!(flags, OVERFLOW | INEXACT);
+        let (z, flags) = f256::MAX.mul_with_flags(-two, TZ);
+        assert_eq!(z, f256::MIN);
+        assert_eq!(flags, OVERFLOW | INEXACT);
+        let (z, flags) = f256::MAX.div_with_flags(f256::ONE.div2(), TZ);
+        assert_eq!(z, f256::MAX);
+        assert_eq!(flags, OVERFLOW | INEXACT);
+        let (z, flags) = f256::MAX.mul_add_with_flags(two, f256::MIN, RNE);
+        assert_eq!(z, f256::MAX);
+        assert_eq!(flags, NONE);
+        let (z, flags) = f256::MAX.mul_add_with_flags(two, f256::ONE, RNE);
+        assert_eq!(z, f256::INFINITY);
+        assert_eq!(flags, OVERFLOW | INEXACT);
+        let (z, flags) = f256::MAX.mul_add_with_flags(two, f256::ZERO, TZ);
+        assert_eq!(z, f256::MAX);
+        assert_eq!(flags, OVERFLOW | INEXACT);
+        // Overflow caused by rounding up.
+        let x = f256::MAX - f256::MAX.ulp().div2();
+        let (z, flags) =
+            f256::MAX.add_with_flags(f256::MAX.ulp().div2(), RNE);
+        assert_eq!(z, f256::INFINITY);
+        assert_eq!(flags, OVERFLOW | INEXACT);
+        let (z, flags) = f256::MAX.mul_with_flags(f256::ONE.next_up(), RNE);
+        assert_eq!(z, f256::INFINITY);
+        assert_eq!(flags, OVERFLOW | INEXACT);
+        let (z, flags) = x.add_with_flags(x.ulp().div2(), TZ);
+        assert_eq!(z, x);
+        assert_eq!(flags, INEXACT);
+    }
+
+    #[test]
+    fn test_underflow() {
+        let tiny = f256::MIN_GT_ZERO;
+        let half = f256::ONE.div2();
+        // Subnormal, but exact results do not signal underflow.
+        let (z, flags) = tiny.mul_with_flags(f256::TWO, RNE);
+        assert_eq!(z, tiny + tiny);
+        assert_eq!(flags, NONE);
+        let (z, flags) = f256::MIN_POSITIVE.sub_with_flags(tiny, RNE);
+        assert!(z.is_subnormal());
+        assert_eq!(flags, NONE);
+        let (z, flags) = tiny.mul_with_flags(half, RNE);
+        assert_eq!(z, f256::ZERO);
+        assert_eq!(flags, UNDERFLOW | INEXACT);
+        let (z, flags) = tiny.mul_with_flags(f256::from(1.5), RNE);
+        assert_eq!(z, tiny + tiny);
+        assert_eq!(flags, UNDERFLOW | INEXACT);
+        let (z, flags) = tiny.div_with_flags(f256::MAX, TZ);
+        assert_eq!(z, f256::ZERO);
+        assert_eq!(flags, UNDERFLOW | INEXACT);
+        let (z, flags) = tiny.div_with_flags(f256::from(3), RNE);
+        assert_eq!(z, f256::ZERO);
+        assert_eq!(flags, UNDERFLOW | INEXACT);
+        let x = f256::ONE.div_pow2(131200);
+        let (z, flags) = x.mul_add_with_flags(x, tiny, RNE);
+        assert_eq!(z, tiny);
+        assert_eq!(flags, UNDERFLOW | INEXACT);
+        let (z, flags) = tiny.mul_add_with_flags(half, f256::ZERO, RNE);
+        assert_eq!(z, f256::ZERO);
+        assert_eq!(flags, UNDERFLOW | INEXACT);
+        // Tininess is detected before rounding.
+        let x = f256::MIN_POSITIVE - tiny;
+        let (z, flags) = x.mul_with_flags(f256::ONE.next_up(), RNE);
+        assert_eq!(z, f256::MIN_POSITIVE);
+        assert_eq!(flags, UNDERFLOW | INEXACT);
+    }
+
+    #[test]
+    fn test_to_float() {
+        let (f, flags) = f256::from(1.5_f64).to_f64_with_flags();
+        assert_eq!(f, 1.5_f64);
+        assert_eq!(flags, NONE);
+        let (f, flags) = f256::from(f64::MAX).to_f64_with_flags();
+        assert_eq!(f, f64::MAX);
+        assert_eq!(flags, NONE);
+        let (f, flags) = f256::from(f64::MAX).to_f32_with_flags();
+        assert_eq!(f, f32::INFINITY);
+        assert_eq!(flags, OVERFLOW | INEXACT);
+        let (f, flags) = (f256::ONE + f256::EPSILON).to_f64_with_flags();
+        assert_eq!(f, 1_f64);
+        assert_eq!(flags, INEXACT);
+        // Rounding up to infinity.
+        // f64::MAX + ½⋅ulp(f64::MAX), i.e. a tie, with f64::MAX being odd
+        let x = f256::from(f64::MAX) + f256::from(2_f64.powi(970));
+        let (f, flags) = x.to_f64_with_flags();
+        assert_eq!(f, f64::INFINITY);
+        assert_eq!(flags, OVERFLOW | INEXACT);
+        // Subnormal results.
+        let x = f256::from(f64::from_bits(1));
+        let (f, flags) = x.to_f64_with_flags();
+        assert_eq!(f, f64::from_bits(1));
+        assert_eq!(flags, NONE);
+        let (f, flags) = (x * f256::from(1.25)).to_f64_with_flags();
+        assert_eq!(f, f64::from_bits(1));
+        assert_eq!(flags, UNDERFLOW | INEXACT);
+        let (f, flags) = (-f256::MIN_GT_ZERO).to_f64_with_flags();
+        assert_eq!(f, -0_f64);
+        assert_eq!(flags, UNDERFLOW | INEXACT);
+    }
+
+    #[test]
+    fn test_parse() {
+        for (lit, z) in [
+            ("1.5", f256::from(1.5)),
+            ("-0", f256::NEG_ZERO),
+            (
+                "123456789012345678901234567890",
+                f256::from(123456789012345678901234567890_u128),
+            ),
+            ("1e102", f256::from(10).powi(102)),
+            ("inf", f256::INFINITY),
+        ] {
+            let (f, flags) = f256::parse_with_flags(lit).unwrap();
+            assert_eq!(f, z, "{lit}");
+            assert_eq!(flags, NONE, "{lit}");
+        }
+        let (f, flags) = f256::parse_with_flags("nan").unwrap();
+        assert!(f.is_nan());
+        assert_eq!(flags, NONE);
+        for lit in ["0.1", "1e103", "-1e-78912"] {
+            let (_, flags) = f256::parse_with_flags(lit).unwrap();
+            assert_eq!(flags, INEXACT, "{lit}");
+        }
+        // 2⁻²³⁶ has 165 significant decimal digits.
+        let eps = "9.0556790788267123675091192908877917806825311981391381895\
+                   82614889935501318592845114739531451960958099453952439296\
+                   87782965588273287327325533624389208853244781494140625e-72";
+        let (f, flags) = f256::parse_with_flags(eps).unwrap();
+        assert_eq!(f, f256::EPSILON);
+        assert_eq!(flags, NONE);
+        let lit = eps.replace("e-72", "1e-72");
+        let (f, flags) = f256::parse_with_flags(&lit).unwrap();
+        assert_eq!(f, f256::EPSILON);
+        assert_eq!(flags, INEXACT);
+        // Overflow and underflow.
+        let (f, flags) = f256::parse_with_flags("-1e80000").unwrap();
+        assert_eq!(f, f256::NEG_INFINITY);
+        assert_eq!(flags, OVERFLOW | INEXACT);
+        let (f, flags) = f256::parse_with_flags("1e-78913").unwrap();
+        assert!(f.is_subnormal());
+        assert_eq!(flags, UNDERFLOW | INEXACT);
+        let (f, flags) = f256::parse_with_flags("1e-80000").unwrap();
+        assert_eq!(f, f256::ZERO);
+        assert_eq!(flags, UNDERFLOW | INEXACT);
+        assert!(f256::parse_with_flags("1.5.3").is_err());
+    }
+
+    #[test]
+    fn test_parse_hex() {
+        let zeroes = "0".repeat(58);
+        let ones = "f".repeat(59);
+        for (lit, z, expected) in [
+            ("0x1.8", f256::from(1.5), NONE),
+            ("-0x1p-262378", -f256::MIN_GT_ZERO, NONE),
+            (&format!("0x1.{zeroes}08"), f256::ONE, INEXACT),
+            (
+                &format!("0x1.{ones}8p+262143"),
+                f256::INFINITY,
+                OVERFLOW | INEXACT,
+            ),
+            ("0x1p+262144", f256::INFINITY, OVERFLOW | INEXACT),
+            (
+                "0x1.8p-262378",
+                f256::MIN_GT_ZERO.mul2(),
+                UNDERFLOW | INEXACT,
+            ),
+            ("-0x1p-262379", f256::NEG_ZERO, UNDERFLOW | INEXACT),
+        ] {
+            let (f, flags) = f256::parse_with_flags(lit).unwrap();
+            assert_eq!(f, z, "{lit}");
+            assert_eq!(flags, expected, "{lit}");
+        }
+    }
+}
@@ -336,4 +336,55 @@ mod mul_add_tests {
         let z = f.mul_add(-f, f256::ZERO);
         assert_eq!(z.total_cmp(&f256::NEG_ZERO), Ordering::Equal);
     }
+
+    #[test]
+    fn test_prod_too_small_addend_subnormal() {
+        let x = f256::ONE.div_pow2(131200);
+        let a = f256::MIN_GT_ZERO;
+        assert_eq!(x.mul_add(x, a), a);
+        assert_eq!(x.mul_add(-x, a), a);
+        let a = f256::MIN_POSITIVE - f256::MIN_GT_ZERO;
+        assert!(a.is_subnormal());
+        assert_eq!(x.mul_add(x, a), a);
+        assert_eq!(x.mul_add(-x, -a), -a);
+    }
+
+    #[test]
+    fn test_prod_subnormal_addend_subnormal() {
+        // The product is subnormal and slightly greater than its rounded
+        // value, which cancels it nearly.
+        let x = (f256::ONE + f256::EPSILON).div_pow2(14);
+        let y = f256::MIN_POSITIVE + f256::MIN_GT_ZERO;
+        let p = x * y;
+        assert!(p.is_subnormal());
+        let z = x.mul_add(y, -p);
+        assert_eq!(z.total_cmp(&f256::ZERO), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_prod_subnormal_addend_normal() {
+        // The product is subnormal, the addend is normal and the result is
+        // subnormal again.
+        let y = f256::MIN_POSITIVE;
+        for (m, n) in [(1, 5), (3, 6), (255, 8)] {
+            let x = f256::from(m).div_pow2(n);
+            let p = y * x;
+            let z = x.mul_add(y, -y);
+            assert!(z.is_subnormal());
+            assert_eq!(z, p - y);
+            assert_eq!(x.mul_add(-y, y), y - p);
+        }
+    }
+
+    #[test]
+    fn test_multiplicand_subnormal() {
+        // x * y = -2²⁸⋅MIN_POSITIVE, a is less than 2 ulps of the result,
+        // but more than 1½ ulps.
+        let x = f256::ONE.mul_pow2(264);
+        let y = -f256::MIN_GT_ZERO;
+        let a = f256::MIN_GT_ZERO * f256::from((1_u32 << 27) + (1 << 26) - 1);
+        let p = f256::MIN_POSITIVE.mul_pow2(28);
+        assert_eq!(x.mul_add(y, a), -p.next_down());
+        assert_eq!(y.mul_add(x, a), -p.next_down());
+    }
 }
@@ -83,6 +83,15 @@ mod random_math_fn_tests {
         run_tests(cbrt, 0, "test_cbrt.txt");
     }
 
+    fn rsqrt(x: &f256) -> f256 {
+        x.rsqrt()
+    }
+
+    #[test]
+    fn test_rsqrt() {
+        run_tests(rsqrt, 0, "test_rsqrt.txt");
+    }
+
     #[test]
     fn test_sin_lt_2pi() {
         run_tests(f256::sin, 0, "test_sin_lt_2pi.txt");
@@ -153,6 +162,36 @@ mod random_math_fn_tests {
         run_tests(f256::acos, 0, "test_acos.txt");
     }
 
+    #[test]
+    fn test_asinpi() {
+        run_tests(f256::asinpi, 0, "test_asinpi.txt");
+    }
+
+    #[test]
+    fn test_acospi() {
+        run_tests(f256::acospi, 0, "test_acospi.txt");
+    }
+
+    #[test]
+    fn test_atanpi() {
+        run_tests(f256::atanpi, 0, "test_atanpi.txt");
+    }
+
+    #[test]
+    fn test_asind() {
+        run_tests(f256::asind, 0, "test_asind.txt");
+    }
+
+    #[test]
+    fn test_acosd() {
+        run_tests(f256::acosd, 0, "test_acosd.txt");
+    }
+
+    #[test]
+    fn test_atand() {
+        run_tests(f256::atand, 0, "test_atand.txt");
+    }
+
     #[test]
     fn test_ln_normal() {
         run_tests(f256::ln, 0, "test_ln_normal.txt");
@@ -188,6 +227,16 @@ mod random_math_fn_tests {
         run_tests(f256::ln_1p, 0, "test_ln_1p.txt");
     }
 
+    #[test]
+    fn test_log2_1p() {
+        run_tests(f256::log2_1p, 0, "test_log2_1p.txt");
+    }
+
+    #[test]
+    fn test_log10_1p() {
+        run_tests(f256::log10_1p, 0, "test_log10_1p.txt");
+    }
+
     #[test]
     fn test_exp() {
         run_tests(f256::exp, 0, "test_exp.txt");
@@ -202,4 +251,83 @@ mod random_math_fn_tests {
     fn test_exp2() {
         run_tests(f256::exp2, 0, "test_exp2.txt");
     }
+
+    #[test]
+    fn test_exp10() {
+        run_tests(f256::exp10, 0, "test_exp10.txt");
+    }
+
+    #[test]
+    fn test_exp2_m1() {
+        run_tests(f256::exp2_m1, 0, "test_exp2_m1.txt");
+    }
+
+    #[test]
+    fn test_exp10_m1() {
+        run_tests(f256::exp10_m1, 0, "test_exp10_m1.txt");
+    }
+
+    #[test]
+    fn test_sinh() {
+        run_tests(f256::sinh, 0, "test_sinh.txt");
+    }
+
+    #[test]
+    fn test_cosh() {
+        run_tests(f256::cosh, 0, "test_cosh.txt");
+    }
+
+    #[test]
+    fn test_tanh() {
+        run_tests(f256::tanh, 0, "test_tanh.txt");
+    }
+
+    #[test]
+    fn test_asinh() {
+        run_tests(f256::asinh, 0, "test_asinh.txt");
+    }
+
+    #[test]
+    fn test_acosh() {
+        run_tests(f256::acosh, 0, "test_acosh.txt");
+    }
+
+    #[test]
+    fn test_atanh() {
+        run_tests(f256::atanh, 0, "test_atanh.txt");
+    }
+
+    #[test]
+    fn test_erf() {
+        run_tests(f256::erf, 0, "test_erf.txt");
+    }
+
+    #[test]
+    fn test_erfc() {
+        run_tests(f256::erfc, 0, "test_erfc.txt");
+    }
+
+    #[test]
+    fn test_erf_inv() {
+        run_tests(f256::erf_inv, 0, "test_erf_inv.txt");
+    }
+
+    #[test]
+    fn test_erfc_inv() {
+        run_tests(f256::erfc_inv, 0, "test_erfc_inv.txt");
+    }
+
+    #[test]
+    fn test_gamma() {
+        run_tests(f256::gamma, 0, "test_gamma.txt");
+    }
+
+    fn ln_gamma(x: &f256) -> f256 {
+        x.ln_gamma().0
+    }
+
+    #[test]
+    fn test_ln_gamma() {
+        run_tests(ln_gamma, 0, "test_ln_gamma.txt");
+    }
 }
@@ -184,3 +184,101 @@ mod rem_tests {
         assert_eq!(xx % yy, zz);
     }
 }
+
+#[cfg(test)]
+mod remainder_tests {
+    use f256::f256;
+
+    #[test]
+    fn test_special() {
+        for (x, y) in [
+            (f256::NAN, f256::ONE),
+            (f256::ONE, f256::NAN),
+            (f256::INFINITY, f256::ONE),
+            (f256::NEG_INFINITY, f256::TWO),
+            (f256::ONE, f256::ZERO),
+            (f256::ZERO, f256::NEG_ZERO),
+        ] {
+            assert!(x.remainder(y).is_nan());
+            let (r, q) = x.remquo(y);
+            assert!(r.is_nan());
+            assert_eq!(q, 0);
+        }
+        assert_eq!(f256::ONE.remquo(f256::INFINITY), (f256::ONE, 0));
+        assert_eq!(f256::NEG_ONE.remquo(f256::INFINITY), (f256::NEG_ONE, 0));
+        let r = f256::NEG_ZERO.remainder(f256::TWO);
+        assert!(r.eq_zero() && r.is_sign_negative());
+    }
+
+    #[test]
+    fn test_normal() {
+        let three = f256::from(3);
+        for (x, r, q) in [
+            (5.0, -1.0, 2),
+            (4.0, 1.0, 1),
+            (1.5, 1.5, 0),
+            (-1.5, -1.5, 0),
+            (4.5, -1.5, 2),
+            (7.5, 1.5, 2),
+            (-7.5, -1.5, -2),
+            (10.5, -1.5, 4),
+            (-10.5, 1.5, -4),
+        ] {
+            let x = f256::from(x);
+            assert_eq!(x.remquo(three), (f256::from(r), q), "{x}");
+            assert_eq!(x.remquo(-three), (f256::from(r), -q), "{x}");
+        }
+    }
+
+    #[test]
+    fn test_zero_result() {
+        let y = f256::from(0.5);
+        for x in [f256::from(3), f256::from(-3)] {
+            let (r, q) = x.remquo(y);
+            assert!(r.eq_zero());
+            assert_eq!(r.is_sign_negative(), x.is_sign_negative());
+            assert_eq!(q, if x.is_sign_negative() { -6 } else { 6 });
+        }
+    }
+
+    #[test]
+    fn test_large_quotient_ties() {
+        // x = 3 × 2⁹⁹ × (2¹³⁶ + 1) is an odd multiple of y / 2 = 3 × 2⁹⁹,
+        // so x / y = 2¹³⁵ + ½ => n = 2¹³⁵.
+        let y = f256::from(3) * f256::TWO.powi(100);
+        let h = f256::from(3) * f256::TWO.powi(99);
+        let x = h * (f256::TWO.powi(136) + f256::ONE);
+        assert_eq!(x.remquo(y), (h, 0));
+        // x / y = 2¹³⁵ + 1½ => n = 2¹³⁵ + 2.
+        let x = h * (f256::TWO.powi(136) + f256::from(3));
+        assert_eq!(x.remquo(y), (-h, 2));
+        assert_eq!((-x).remquo(y), (h, -2));
+    }
+
+    #[test]
+    fn test_subnormal_divisor() {
+        // 2²⁶²³⁷⁸ = 3 × (2²⁶²³⁷⁸ - 1) / 3 + 1
+        let y = f256::MIN_GT_ZERO * f256::from(3);
+        assert!(y.is_subnormal());
+        let (r, q) = f256::ONE.remquo(y);
+        assert_eq!(r, f256::MIN_GT_ZERO);
+        assert_eq!(q, 0x5555555555555555);
+        let (r, q) = f256::ONE.remquo(f256::MIN_GT_ZERO);
+        assert_eq!((r, q), (f256::ZERO, 0));
+    }
+
+    #[test]
+    fn test_huge_quotient() {
+        let y = f256::ONE.next_up();
+        let (r, _) = f256::MAX.remquo(y);
+        assert!(r.abs() <= y / f256::TWO);
+        assert_eq!(r, {
+            let r = f256::MAX % y;
+            if r + r > y {
+                r - y
+            } else {
+                r
+            }
+        });
+    }
+}
@@ -0,0 +1,345 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+#[cfg(test)]
+mod random_rounding_mode_tests {
+    use std::path::PathBuf;
+
+    use ::f256::{f256, ExceptionFlags, RoundingMode};
+    use csv::ReaderBuilder;
+    use serde::{de::DeserializeOwned, Deserialize};
+
+    type Repr = (u32, i32, u128, u128);
+
+    /// Result of an op, optionally including the exception flags.
+    trait Outcome {
+        fn split(self) -> (f256, Option<ExceptionFlags>);
+    }
+
+    impl Outcome for f256 {
+        fn split(self) -> (f256, Option<ExceptionFlags>) {
+            (self, None)
+        }
+    }
+
+    impl Outcome for (f256, ExceptionFlags) {
+        fn split(self) -> (f256, Option<ExceptionFlags>) {
+            (self.0, Some(self.1))
+        }
+    }
+
+    const MODES: [RoundingMode; 4] = [
+        RoundingMode::NearestTiesEven,
+        RoundingMode::TowardPositive,
+        RoundingMode::TowardNegative,
+        RoundingMode::TowardZero,
+    ];
+
+    #[derive(Debug, Deserialize)]
+    struct UnaryRecord {
+        x: Repr,
+        z: [Repr; 4],
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct BinaryRecord {
+        x: Repr,
+        y: Repr,
+        z: [Repr; 4],
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct TernaryRecord {
+        x: Repr,
+        y: Repr,
+        a: Repr,
+        z: [Repr; 4],
+    }
+
+    fn get_dir() -> PathBuf {
+        let mut dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        dir.push("tests");
+        dir
+    }
+
+    fn get_path(file_name: &str) -> PathBuf {
+        let mut p = get_dir();
+        p.push(file_name);
+        p
+    }
+
+    fn from_repr(r: &Repr) -> f256 {
+        f256::from_sign_exp_signif(r.0, r.1, (r.2, r.3))
+    }
+
+    fn run_tests<R: DeserializeOwned + std::fmt::Debug>(
+        file_name: &str,
+        check: impl Fn(&R),
+    ) {
+        let mut rdr = ReaderBuilder::new()
+            .has_headers(false)
+            .delimiter(b'\t')
+            .from_path(get_path(file_name))
+            .unwrap();
+        for rec in rdr.deserialize::<R>() {
+            match rec {
+                Ok(rec) => check(&rec),
+                Err(e) => panic!("{}", e),
+            }
+        }
+    }
+
+    // Check the flags against those derived from the correctly rounded
+    // results: the exact result is representable iff rounding toward +∞ and
+    // toward -∞ give the same value, and it is tiny iff its truncation is.
+    fn check_flags<R: std::fmt::Debug>(
+        rec: &R,
+        mode: RoundingMode,
+        z: f256,
+        z_tp: f256,
+        z_tn: f256,
+        z_tz: f256,
+        flags: ExceptionFlags,
+    ) {
+        let is_inexact = z_tp != z_tn;
+        assert_eq!(
+            flags.is_inexact(),
+            is_inexact,
+            "\nInexact: {mode:?}: {flags:?}\n{rec:?}"
+        );
+        assert_eq!(
+            flags.is_underflow(),
+            is_inexact && z_tz.abs() < f256::MIN_POSITIVE,
+            "\nUnderflow: {mode:?}: {flags:?}\n{rec:?}"
+        );
+        // A result of ±MAX may or may not be the result of an overflow.
+        if z.is_infinite() || z.abs() != f256::MAX {
+            assert_eq!(
+                flags.is_overflow(),
+                z.is_infinite(),
+                "\nOverflow: {mode:?}: {flags:?}\n{rec:?}"
+            );
+        }
+        assert!(
+            !flags.is_invalid() && !flags.is_div_by_zero(),
+            "\n{mode:?}: {flags:?}\n{rec:?}"
+        );
+    }
+
+    fn check_results<R: std::fmt::Debug, T: Outcome>(
+        rec: &R,
+        z: &[Repr; 4],
+        op: impl Fn(RoundingMode) -> T,
+    ) {
+        let z = z.map(|z| from_repr(&z));
+        for (mode, z_mode) in MODES.iter().zip(z) {
+            let (res, flags) = op(*mode).split();
+            // Compare the bit patterns in order to check the sign of zeros.
+            assert_eq!(
+                res.to_bits(),
+                z_mode.to_bits(),
+                "\nFailed: {mode:?}: {res:?} != {z_mode:?}\n{rec:?}"
+            );
+            if let Some(flags) = flags {
+                check_flags(rec, *mode, z_mode, z[1], z[2], z[3], flags);
+            }
+        }
+    }
+
+    fn run_unary_tests<T: Outcome>(
+        op: fn(f256, RoundingMode) -> T,
+        file_name: &str,
+    ) {
+        run_tests(file_name, |rec: &UnaryRecord| {
+            let x = from_repr(&rec.x);
+            assert!(x.is_finite(), "\nx not finite: {rec:?}");
+            check_results(rec, &rec.z, |mode| op(x, mode));
+        });
+    }
+
+    fn run_binary_tests<T: Outcome>(
+        op: fn(f256, f256, RoundingMode) -> T,
+        file_name: &str,
+    ) {
+        run_tests(file_name, |rec: &BinaryRecord| {
+            let x = from_repr(&rec.x);
+            assert!(x.is_finite(), "\nx not finite: {rec:?}");
+            let y = from_repr(&rec.y);
+            assert!(y.is_finite(), "\ny not finite: {rec:?}");
+            check_results(rec, &rec.z, |mode| op(x, y, mode));
+        });
+    }
+
+    fn run_ternary_tests<T: Outcome>(
+        op: fn(f256, f256, f256, RoundingMode) -> T,
+        file_name: &str,
+    ) {
+        run_tests(file_name, |rec: &TernaryRecord| {
+            let x = from_repr(&rec.x);
+            assert!(x.is_finite(), "\nx not finite: {rec:?}");
+            let y = from_repr(&rec.y);
+            assert!(y.is_finite(), "\ny not finite: {rec:?}");
+            let a = from_repr(&rec.a);
+            assert!(a.is_finite(), "\na not finite: {rec:?}");
+            check_results(rec, &rec.z, |mode| op(x, y, a, mode));
+        });
+    }
+
+    #[test]
+    fn test_add_rounded() {
+        run_binary_tests(f256::add_rounded, "test_add_rounded.txt");
+    }
+
+    #[test]
+    fn test_sub_rounded() {
+        run_binary_tests(
+            |x, y, mode| x.sub_rounded(-y, mode),
+            "test_add_rounded.txt",
+        );
+    }
+
+    #[test]
+    fn test_mul_rounded() {
+        run_binary_tests(f256::mul_rounded, "test_mul_rounded.txt");
+    }
+
+    #[test]
+    fn test_div_rounded() {
+        run_binary_tests(f256::div_rounded, "test_div_rounded.txt");
+    }
+
+    #[test]
+    fn test_sqrt_rounded() {
+        run_unary_tests(f256::sqrt_rounded, "test_sqrt_rounded.txt");
+    }
+
+    #[test]
+    fn test_mul_add_rounded() {
+        run_ternary_tests(f256::mul_add_rounded, "test_fma_rounded.txt");
+    }
+
+    #[test]
+    fn test_add_with_flags() {
+        run_binary_tests(f256::add_with_flags, "test_add_rounded.txt");
+    }
+
+    #[test]
+    fn test_sub_with_flags() {
+        run_binary_tests(
+            |x, y, mode| x.sub_with_flags(-y, mode),
+            "test_add_rounded.txt",
+        );
+    }
+
+    #[test]
+    fn test_mul_with_flags() {
+        run_binary_tests(f256::mul_with_flags, "test_mul_rounded.txt");
+    }
+
+    #[test]
+    fn test_div_with_flags() {
+        run_binary_tests(f256::div_with_flags, "test_div_rounded.txt");
+    }
+
+    #[test]
+    fn test_sqrt_with_flags() {
+        run_unary_tests(f256::sqrt_with_flags, "test_sqrt_rounded.txt");
+    }
+
+    #[test]
+    fn test_mul_add_with_flags() {
+        run_ternary_tests(f256::mul_add_with_flags, "test_fma_rounded.txt");
+    }
+}
+
+#[cfg(test)]
+mod rounding_mode_tests {
+    use core::cmp::Ordering;
+
+    use ::f256::{f256, RoundingMode};
+
+    const TP: RoundingMode = RoundingMode::TowardPositive;
+    const TN: RoundingMode = RoundingMode::TowardNegative;
+    const TZ: RoundingMode = RoundingMode::TowardZero;
+
+    fn assert_same(x: f256, y: f256) {
+        // Because the normal cmp treats 0 == -0, we have to use total_cmp.
+        assert_eq!(x.total_cmp(&y), Ordering::Equal, "{x:?} != {y:?}");
+    }
+
+    #[test]
+    fn test_zero_sum() {
+        let x = f256::from(7);
+        for (mode, z) in
+            [(TP, f256::ZERO), (TN, f256::NEG_ZERO), (TZ, f256::ZERO)]
+        {
+            assert_same(x.add_rounded(-x, mode), z);
+            assert_same((-x).add_rounded(x, mode), z);
+            assert_same(x.sub_rounded(x, mode), z);
+            assert_same(f256::ZERO.add_rounded(f256::NEG_ZERO, mode), z);
+            assert_same(f256::NEG_ZERO.sub_rounded(f256::NEG_ZERO, mode), z);
+            assert_same(x.mul_add_rounded(x, -x * x, mode), z);
+            assert_same(
+                f256::ZERO.mul_add_rounded(x, f256::NEG_ZERO, mode),
+                z,
+            );
+        }
+        for mode in [TP, TN, TZ] {
+            assert_same(
+                f256::NEG_ZERO.add_rounded(f256::NEG_ZERO, mode),
+                f256::NEG_ZERO,
+            );
+            assert_same(
+                f256::NEG_ZERO.mul_add_rounded(x, f256::NEG_ZERO, mode),
+                f256::NEG_ZERO,
+            );
+        }
+    }
+
+    #[test]
+    fn test_overflow() {
+        let two = f256::TWO;
+        assert_eq!(f256::MAX.mul_rounded(two, TP), f256::INFINITY);
+        assert_eq!(f256::MAX.mul_rounded(two, TN), f256::MAX);
+        assert_eq!(f256::MAX.mul_rounded(two, TZ), f256::MAX);
+        assert_eq!(f256::MIN.mul_rounded(two, TP), f256::MIN);
+        assert_eq!(f256::MIN.mul_rounded(two, TN), f256::NEG_INFINITY);
+        assert_eq!(f256::MIN.mul_rounded(two, TZ), f256::MIN);
+        assert_eq!(f256::MAX.add_rounded(f256::MAX, TZ), f256::MAX);
+        assert_eq!(f256::MAX.add_rounded(f256::MAX, TP), f256::INFINITY);
+        assert_eq!(f256::MIN.sub_rounded(f256::MAX, TP), f256::MIN);
+        assert_eq!(f256::MAX.div_rounded(f256::EPSILON, TN), f256::MAX);
+        assert_eq!(
+            f256::MAX.div_rounded(-f256::EPSILON, TN),
+            f256::NEG_INFINITY
+        );
+        assert_eq!(f256::MAX.mul_add_rounded(two, f256::MIN, TZ), f256::MAX);
+        // Infinite operands are exact.
+        assert_eq!(f256::INFINITY.mul_rounded(two, TZ), f256::INFINITY);
+        assert_eq!(f256::INFINITY.add_rounded(two, TN), f256::INFINITY);
+    }
+
+    #[test]
+    fn test_underflow() {
+        let tiny = f256::MIN_GT_ZERO;
+        let half = f256::ONE.div2();
+        assert_same(tiny.mul_rounded(half, TP), tiny);
+        assert_same(tiny.mul_rounded(half, TN), f256::ZERO);
+        assert_same(tiny.mul_rounded(-half, TN), -tiny);
+        assert_same(tiny.mul_rounded(-half, TZ), f256::NEG_ZERO);
+        assert_same(tiny.div_rounded(f256::TEN, TP), tiny);
+        assert_same((-tiny).div_rounded(f256::TEN, TP), f256::NEG_ZERO);
+        assert_same(tiny.mul_add_rounded(half, f256::ZERO, TP), tiny);
+        assert_same(
+            tiny.mul_add_rounded(-half, f256::ZERO, TZ),
+            f256::NEG_ZERO,
+        );
+        assert_same(tiny.sqrt_rounded(TZ), tiny.sqrt());
+    }
+}
@@ -0,0 +1,320 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2025 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+mod common;
+
+#[cfg(test)]
+mod sum_tests {
+    use f256::f256;
+
+    use crate::common::{Rng, MIN_EXP};
+
+    const N_SAMPLES: usize = 2000;
+
+    fn assert_same(x: f256, y: f256) {
+        assert!(x.total_cmp(&y).is_eq(), "{x:?} != {y:?}");
+    }
+
+    #[test]
+    fn test_sum_trait() {
+        let v: Vec<f256> = (1..=100).map(f256::from).collect();
+        assert_eq!(v.iter().sum::<f256>(), f256::from(5050));
+        assert_eq!(v.into_iter().sum::<f256>(), f256::from(5050));
+        let e = f256::EPSILON.div2().div2();
+        let v = [f256::ONE, e, e, f256::NEG_ONE];
+        assert_eq!(v.iter().sum::<f256>(), e.mul2());
+    }
+
+    #[test]
+    fn test_product_trait() {
+        let v: Vec<f256> = (1..=10).map(f256::from).collect();
+        assert_eq!(v.iter().product::<f256>(), f256::from(3628800));
+        assert_eq!(v.into_iter().product::<f256>(), f256::from(3628800));
+        assert_eq!([f256::ZERO; 0].iter().product::<f256>(), f256::ONE);
+    }
+
+    #[test]
+    fn test_product_single_rounding() {
+        // x³ = 1 + 3⋅2⁻¹¹⁹ + 3⋅2⁻²³⁸ + 2⁻³⁵⁷, but rounding x² to
+        // 1 + 2⁻¹¹⁸ leads to a tie, which is rounded down.
+        let d = f256::ONE.div_pow2(119);
+        let x = f256::ONE + d;
+        let p = f256::ONE + f256::from(3).div_pow2(119) + f256::EPSILON;
+        assert_same([x, x, x].iter().product::<f256>(), p);
+        assert_ne!(x * x * x, p);
+    }
+
+    #[test]
+    fn test_product_intermediate_overflow() {
+        let v = [f256::MAX, f256::from(4), f256::from(0.25)];
+        assert_same(v.iter().product::<f256>(), f256::MAX);
+        // a = MAX * MIN_POSITIVE is exact.
+        let a = f256::MAX * f256::MIN_POSITIVE;
+        let v = [
+            -f256::MAX,
+            f256::MAX,
+            f256::MIN_POSITIVE,
+            f256::MIN_POSITIVE,
+        ];
+        assert_same(v.iter().product::<f256>(), -(a * a));
+        let v = [f256::MAX, f256::MAX, f256::ZERO];
+        assert_same(v.iter().product::<f256>(), f256::ZERO);
+        let v = [f256::MAX, f256::from(2)];
+        assert_same(v.iter().product::<f256>(), f256::INFINITY);
+    }
+
+    #[test]
+    fn test_product_intermediate_underflow() {
+        let v = [f256::MIN_GT_ZERO, f256::from(0.5), f256::from(4)];
+        assert_same(
+            v.iter().product::<f256>(),
+            f256::MIN_GT_ZERO.mul_pow2(1),
+        );
+        let a = f256::MAX * f256::MIN_POSITIVE;
+        let v = [
+            f256::MIN_POSITIVE,
+            -f256::MIN_POSITIVE,
+            f256::MAX,
+            f256::MAX,
+        ];
+        assert_same(v.iter().product::<f256>(), -(a * a));
+        let v = [f256::MIN_GT_ZERO, f256::from(0.25)];
+        assert_same(v.iter().product::<f256>(), f256::ZERO);
+        let v = [f256::MIN_GT_ZERO, f256::from(-0.75)];
+        assert_same(v.iter().product::<f256>(), -f256::MIN_GT_ZERO);
+    }
+
+    #[test]
+    fn test_product_special() {
+        let two = f256::from(2);
+        let v = [two, f256::NEG_INFINITY, -two];
+        assert_same(v.iter().product::<f256>(), f256::INFINITY);
+        let v = [-two, f256::ZERO, two];
+        assert_same(v.iter().product::<f256>(), f256::NEG_ZERO);
+        let v = [f256::NEG_ZERO, f256::NEG_ZERO];
+        assert_same(v.iter().product::<f256>(), f256::ZERO);
+        let v = [f256::INFINITY, two, f256::ZERO];
+        assert!(v.iter().product::<f256>().is_nan());
+        let v = [two, f256::NAN, f256::ZERO];
+        assert!(v.iter().product::<f256>().is_nan());
+        let v = [-f256::ONE, -f256::ONE, -f256::ONE];
+        assert_same(v.iter().product::<f256>(), -f256::ONE);
+    }
+
+    #[test]
+    fn test_sum_exact_cancellation() {
+        let e = f256::EPSILON.div2().div2();
+        assert_eq!(f256::sum_exact([f256::ONE, e, f256::NEG_ONE]), e);
+        assert_eq!(f256::sum_exact([e, f256::MAX, e, f256::MIN]), e.mul2());
+        let x = f256::ONE / f256::from(3);
+        let v = [x, x, x, -x.mul2(), -x];
+        assert_same(f256::sum_exact(v.iter()), f256::ZERO);
+    }
+
+    #[test]
+    fn test_sum_exact_full_range() {
+        // Powers of 2 from MIN_GT_ZERO upto 1, 100 bits apart, so that only
+        // the upper 3 of them contribute to the rounded sum.
+        let mut v: Vec<f256> = (0..=(-MIN_EXP as u32 + 236) / 100)
+            .map(|i| f256::ONE.div_pow2(100 * i))
+            .chain([f256::MIN_GT_ZERO])
+            .collect();
+        let s = f256::ONE + f256::ONE.div_pow2(100) + f256::ONE.div_pow2(200);
+        assert_eq!(f256::sum_exact(v.iter()), s);
+        v.reverse();
+        assert_eq!(f256::sum_exact(v.iter()), s);
+        // MAX and MIN cancel out.
+        let w: Vec<f256> = [f256::MAX]
+            .iter()
+            .chain(&v)
+            .chain(&[f256::MIN])
+            .copied()
+            .collect();
+        assert_eq!(f256::sum_exact(&w), s);
+        let w: Vec<f256> = v.iter().map(|x| -*x).chain([f256::MAX]).collect();
+        assert_eq!(f256::sum_exact(&w), f256::MAX);
+        // Values far apart from each other.
+        let v = [
+            f256::MIN_GT_ZERO,
+            f256::MAX,
+            f256::ONE,
+            f256::MIN_POSITIVE,
+            f256::MIN,
+            f256::NEG_ONE,
+        ];
+        assert_eq!(
+            f256::sum_exact(v),
+            f256::MIN_POSITIVE + f256::MIN_GT_ZERO
+        );
+        let v = [f256::MAX, f256::MIN_GT_ZERO, f256::MIN, -f256::MIN_GT_ZERO];
+        assert_same(f256::sum_exact(v), f256::ZERO);
+    }
+
+    #[test]
+    fn test_sum_exact_intermediate_overflow() {
+        let v = [f256::MAX, f256::MAX, f256::MIN];
+        assert_eq!(f256::sum_exact(v.iter()), f256::MAX);
+        let v = [f256::MAX, f256::MAX.ulp().div2()];
+        assert_eq!(f256::sum_exact(v.iter()), f256::INFINITY);
+        let v = [f256::MIN, f256::MIN, f256::MAX.ulp()];
+        assert_eq!(f256::sum_exact(v.iter()), f256::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_sum_exact_single_rounding() {
+        // Summing n equal values is the same as multiplying one of them by
+        // n, which is rounded only once.
+        let n = 1_000_000;
+        for x in [f256::ONE / f256::TEN, f256::ONE / f256::from(3)] {
+            let s = f256::sum_exact(core::iter::repeat_n(x, n));
+            assert_eq!(s, x * f256::from(n as u64));
+            let s = f256::sum_exact(core::iter::repeat_n(-x, n));
+            assert_eq!(s, -x * f256::from(n as u64));
+        }
+    }
+
+    #[test]
+    fn test_sum_exact_two_values() {
+        // The sum of two values is rounded only once anyway.
+        let mut rng = Rng(0x2468_ace0_1357_9bdf);
+        for (lo_exp, hi_exp) in [
+            (-300, 300),
+            (MIN_EXP - 236, MIN_EXP + 36),
+            (f256::MAX_EXP - 260, f256::MAX_EXP - 1),
+        ] {
+            for _ in 0..N_SAMPLES {
+                let x = rng.next_f256(lo_exp, hi_exp);
+                let y = rng.next_f256(lo_exp, hi_exp);
+                assert_same(f256::sum_exact([x, y]), x + y);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sum_exact_order_independent() {
+        let mut rng = Rng(0x0bad_cafe_dead_beef);
+        let mut v: Vec<f256> =
+            (0..N_SAMPLES).map(|_| rng.next_f256(-5000, 5000)).collect();
+        let z = rng.next_f256(-6000, -5000);
+        let s = f256::sum_exact(v.iter());
+        v.reverse();
+        assert_eq!(f256::sum_exact(v.iter()), s);
+        // Adding the negated values in reverse order leaves only z.
+        let w: Vec<f256> = v
+            .iter()
+            .copied()
+            .chain([z])
+            .chain(v.iter().rev().map(|x| -*x))
+            .collect();
+        assert_eq!(f256::sum_exact(&w), z);
+    }
+
+    #[test]
+    fn test_sum_exact_subnormal() {
+        let v = [f256::MIN_GT_ZERO; 3];
+        assert_eq!(f256::sum_exact(v), f256::MIN_GT_ZERO * f256::from(3));
+        let v = [f256::MIN_POSITIVE, -f256::MIN_GT_ZERO];
+        assert_eq!(f256::sum_exact(v), f256::MIN_POSITIVE.next_down());
+    }
+
+    #[test]
+    fn test_sum_exact_special() {
+        assert!(f256::sum_exact([f256::ONE, f256::NAN]).is_nan());
+        assert!(
+            f256::sum_exact([f256::INFINITY, f256::NEG_INFINITY]).is_nan()
+        );
+        assert_eq!(
+            f256::sum_exact([f256::INFINITY, f256::MIN]),
+            f256::INFINITY
+        );
+        assert_eq!(
+            f256::sum_exact([f256::NEG_INFINITY, f256::MAX]),
+            f256::NEG_INFINITY
+        );
+        assert_same(f256::sum_exact([f256::ZERO; 0]), f256::NEG_ZERO);
+        assert_same(f256::sum_exact([f256::NEG_ZERO; 2]), f256::NEG_ZERO);
+        assert_same(
+            f256::sum_exact([f256::NEG_ZERO, f256::ZERO]),
+            f256::ZERO,
+        );
+        assert_same(f256::sum_exact([f256::ONE, f256::NEG_ONE]), f256::ZERO);
+    }
+
+    #[test]
+    fn test_dot_vs_mul_add() {
+        // dot([x, a], [y, 1]) = x * y + a, rounded only once.
+        let mut rng = Rng(0x1234_5678_9abc_def0);
+        for (lo_exp, hi_exp) in [
+            (-300, 300),
+            (MIN_EXP / 2 - 300, MIN_EXP / 2 + 300),
+            (f256::MAX_EXP / 2 - 300, f256::MAX_EXP / 2),
+        ] {
+            for _ in 0..N_SAMPLES {
+                let x = rng.next_f256(lo_exp, hi_exp);
+                let y = rng.next_f256(lo_exp, hi_exp);
+                let a = x * y * rng.next_f256(-300, 0);
+                assert_same(
+                    f256::dot(&[x, a], &[y, f256::ONE]),
+                    x.mul_add(y, a),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_dot_exact() {
+        let mut rng = Rng(0x5eed_0000_f256_0013);
+        for _ in 0..N_SAMPLES {
+            let x = rng.next_f256(-1000, 1000);
+            let y = rng.next_f256(-1000, 1000);
+            let (hi, lo) = x.two_prod(y);
+            assert_eq!(f256::dot(&[x], &[y]), hi);
+            assert_eq!(f256::dot(&[x, hi], &[y, f256::NEG_ONE]), lo);
+        }
+    }
+
+    #[test]
+    fn test_dot_special() {
+        assert!(f256::dot(&[f256::INFINITY], &[f256::ZERO]).is_nan());
+        assert!(f256::dot(&[f256::ONE, f256::NAN], &[f256::ONE; 2]).is_nan());
+        assert_eq!(
+            f256::dot(&[f256::INFINITY, f256::MAX], &[f256::NEG_ONE; 2]),
+            f256::NEG_INFINITY
+        );
+        assert_eq!(f256::dot(&[f256::MAX], &[f256::TWO]), f256::INFINITY);
+        assert_same(
+            f256::dot(&[f256::MIN_GT_ZERO], &[f256::ONE.div2()]),
+            f256::ZERO,
+        );
+        assert_same(
+            f256::dot(&[f256::ZERO], &[f256::NEG_ONE]),
+            f256::NEG_ZERO,
+        );
+    }
+
+    #[test]
+    fn test_dot_extreme_exponents() {
+        // The products span the full range of exponents of the accumulator.
+        let a = [f256::MAX, f256::MIN_GT_ZERO, f256::MAX];
+        let b = [f256::MAX, -f256::MIN_GT_ZERO, f256::MIN];
+        assert_same(f256::dot(&a, &b), f256::NEG_ZERO);
+        let b = [f256::MAX, f256::MIN_GT_ZERO, f256::MIN];
+        assert_same(f256::dot(&a, &b), f256::ZERO);
+        assert_same(f256::dot(&a[..2], &b[..2]), f256::INFINITY);
+        let a = [f256::MAX, f256::MIN_POSITIVE, f256::MAX];
+        let b = [f256::MAX, f256::ONE, f256::MIN];
+        assert_same(f256::dot(&a, &b), f256::MIN_POSITIVE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dot_unequal_len() {
+        let _ = f256::dot(&[f256::ONE; 2], &[f256::ONE; 3]);
+    }
+}